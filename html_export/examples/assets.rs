@@ -0,0 +1,47 @@
+use html_export::assets::AssetRegistry;
+use html_export::document::Document;
+use html_export::head::Head;
+use html_export::miscellaneous::ScriptLoadMode;
+use html_export::site::Site;
+use html_export::tags::*;
+use html_export::{elem, h1, img, text};
+
+fn main() {
+    let head = Head::new()
+        .with_title("Assets".to_string())
+        .with_css_file("css/style.css".to_string())
+        .with_javascript_file("app.js".to_string(), Some(ScriptLoadMode::Defer))
+        .with_ico_icon("https://example.com/favicon.ico".to_string());
+    let page = Document::new(
+        head,
+        vec![
+            img!(attributes = {"src" => Some("images/logo.svg"), "alt" => Some("Logo")}),
+            h1!() + text!("Sales"),
+        ],
+    );
+
+    let site = Site::new(
+        "Reports".to_string(),
+        "https://reports.example.com".to_string(),
+    )
+    .with_page("".to_string(), "Home".to_string(), page.clone())
+    .with_page(
+        "reports/sales".to_string(),
+        "Sales".to_string(),
+        page.clone(),
+    )
+    .without_index()
+    .with_assets(AssetRegistry::new("examples/static".to_string()));
+    site.export_to_directory("examples_output/assets".to_string())
+        .unwrap();
+
+    let mut missing = page;
+    missing.head = missing.head.with_css_file("css/print.css".to_string());
+    let mut assets = AssetRegistry::new("examples/static".to_string());
+    println!(
+        "{}",
+        assets
+            .rewrite_document(&mut missing, "index.html")
+            .unwrap_err()
+    );
+}
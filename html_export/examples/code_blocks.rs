@@ -0,0 +1,76 @@
+use html_export::component::RenderContext;
+use html_export::composed::code::{CodeBlock, CodeBlockProps, CodeTheme, Language};
+use html_export::document::Document;
+use html_export::head::Head;
+use html_export::tags::*;
+use html_export::text::to_markdown;
+use html_export::{elem, h2, text};
+
+const RUST: &str = r#"#[derive(Debug)]
+struct Report<'a> {
+    title: &'a str,
+    total: f64,
+}
+
+/* Prints the report */
+fn main() {
+    let report = Report { title: "March", total: 1_250.5 };
+    println!("{:?} {}", report, 'x');
+}
+"#;
+
+const SQL: &str = "SELECT region, SUM(amount) AS total -- monthly sales
+FROM sales
+WHERE month = 'March' AND amount > 0
+GROUP BY region;";
+
+const JSON: &str = r#"{
+    "title": "March",
+    "regions": ["North", "South"],
+    "total": 1250.5,
+    "draft": false
+}"#;
+
+const SHELL: &str = r#"# Build and export the reports
+cargo build --release
+for month in january february; do
+    ./target/release/reports "$month" > "${month}.html" 2>&1
+done"#;
+
+fn main() {
+    let mut context = RenderContext::new();
+    let blocks = [
+        CodeBlockProps::new(RUST.to_string(), Language::Rust)
+            .with_line_numbers()
+            .with_anchors("L".to_string())
+            .with_highlighted_lines(8..=10),
+        CodeBlockProps::new(SQL.to_string(), Language::Sql)
+            .with_line_numbers()
+            .with_first_line(41)
+            .with_highlighted_lines(43..=43),
+        CodeBlockProps::new(JSON.to_string(), Language::Json),
+        CodeBlockProps::new(SHELL.to_string(), Language::from_name("sh").unwrap())
+            .with_line_numbers()
+            .with_anchors("build-L".to_string()),
+    ];
+
+    let mut body = vec![];
+    for props in blocks.iter() {
+        body.push(h2!() + text!(props.language.get_name()));
+        body.push(context.render(&CodeBlock, props, vec![]).unwrap());
+    }
+    println!("{}", to_markdown(&body[..4]));
+
+    Document::new(
+        Head::new()
+            .with_charset_meta("utf-8".to_string())
+            .with_title("Code blocks".to_string())
+            .with_style(CodeTheme::dark().to_css()),
+        body,
+    )
+    .export_to_file(
+        "examples_output".to_string(),
+        "code_blocks.html".to_string(),
+    )
+    .unwrap();
+}
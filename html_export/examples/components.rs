@@ -0,0 +1,130 @@
+use html_export::component::{Component, RenderContext, Theme};
+use html_export::element::{Element, HtmlElement, HtmlElementConfig};
+use html_export::errors::HtmlResult;
+use html_export::head::Head;
+use html_export::tags::TagType;
+use html_export::{export_to_file, text};
+
+/// A card with a title and any content.
+pub struct Card;
+
+pub struct CardProps {
+    title: String,
+}
+
+impl Component for Card {
+    type Props = CardProps;
+
+    fn render(
+        &self,
+        props: &CardProps,
+        children: Vec<Element>,
+        context: &mut RenderContext,
+    ) -> HtmlResult<Element> {
+        let title = Element::Element(HtmlElement::new(
+            TagType::H2,
+            HtmlElementConfig::new_class_id(context.theme.get_classes("card.title"), None),
+        ))
+        .checked_add(text!(props.title))?;
+        let mut card = Element::Element(HtmlElement::new(
+            TagType::Article,
+            HtmlElementConfig::new_class_id(context.theme.get_classes("card"), None),
+        ));
+        card.try_push(title)?;
+        card.try_extend(children)?;
+        Ok(card)
+    }
+}
+
+/// A labeled checkbox, linked to its label with a generated id.
+pub struct Toggle;
+
+pub struct ToggleProps {
+    label: String,
+    checked: bool,
+}
+
+impl Component for Toggle {
+    type Props = ToggleProps;
+
+    fn render(
+        &self,
+        props: &ToggleProps,
+        _children: Vec<Element>,
+        context: &mut RenderContext,
+    ) -> HtmlResult<Element> {
+        let id = context.next_id("toggle");
+        let label = Element::Element(HtmlElement::new(
+            TagType::Label,
+            HtmlElementConfig::new_empty().set_attribute("for".to_string(), Some(id.clone())),
+        ))
+        .checked_add(text!(props.label))?;
+        let mut config =
+            HtmlElementConfig::new_class_id(context.theme.get_classes("toggle"), Some(id))
+                .set_attribute("type".to_string(), Some("checkbox".to_string()));
+        if props.checked {
+            config = config.set_attribute("checked".to_string(), None);
+        }
+        let div = Element::Element(HtmlElement::new(
+            TagType::Div,
+            HtmlElementConfig::new_empty(),
+        ));
+        Ok(div
+            .checked_add(label)?
+            .checked_add(Element::Element(HtmlElement::new(TagType::Input, config)))?)
+    }
+}
+
+fn main() {
+    let theme = Theme::new("dark".to_string())
+        .with_classes(
+            "card".to_string(),
+            ["card".to_string(), "card-dark".to_string()],
+        )
+        .with_classes("card.title".to_string(), ["card-title".to_string()])
+        .with_classes("toggle".to_string(), ["switch".to_string()]);
+    let mut context = RenderContext::new()
+        .with_theme(theme)
+        .with_locale("en-GB".to_string())
+        .with_id_prefix("settings-".to_string());
+
+    let toggles = vec![
+        context
+            .render(
+                &Toggle,
+                &ToggleProps {
+                    label: "Notifications".to_string(),
+                    checked: true,
+                },
+                vec![],
+            )
+            .unwrap(),
+        context
+            .render(
+                &Toggle,
+                &ToggleProps {
+                    label: "Dark mode".to_string(),
+                    checked: false,
+                },
+                vec![],
+            )
+            .unwrap(),
+    ];
+    let card = context
+        .render(
+            &Card,
+            &CardProps {
+                title: "Settings".to_string(),
+            },
+            toggles,
+        )
+        .unwrap();
+
+    export_to_file(
+        "examples_output".to_string(),
+        "components.html".to_string(),
+        Head::new().with_title("Components".to_string()),
+        vec![card],
+    )
+    .unwrap();
+}
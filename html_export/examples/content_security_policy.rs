@@ -0,0 +1,45 @@
+use html_export::csp::{ContentSecurityPolicy, CspDirective, SELF};
+use html_export::document::Document;
+use html_export::head::Head;
+use html_export::tags::*;
+use html_export::{button, elem, h1, p, text};
+
+fn main() {
+    let policy = ContentSecurityPolicy::strict()
+        .with_directive(CspDirective::ImgSrc, &[SELF, "data:"])
+        .with_directive(CspDirective::FrameAncestors, &["'none'"]);
+    let head = Head::new()
+        .with_charset_meta("utf-8".to_string())
+        .with_title("Secured report".to_string())
+        .with_style("h1 { color: #1f6feb; }".to_string())
+        .with_raw_javascript("console.log('loaded');".to_string(), None);
+    let document = Document::new(
+        head,
+        vec![
+            h1!() + text!("Secured report"),
+            p!() + text!("Sales are up."),
+        ],
+    );
+
+    // Delivered in a meta tag, with a new nonce at each export.
+    document
+        .clone()
+        .with_content_security_policy(policy.clone())
+        .export_to_file(
+            "examples_output".to_string(),
+            "content_security_policy.html".to_string(),
+        )
+        .unwrap();
+
+    // Delivered in an http header by a server.
+    let protected = policy.apply(&document).unwrap();
+    println!("Content-Security-Policy: {}", protected.header_value);
+
+    // Inline event handlers are blocked by the policy.
+    let unsafe_document = Document::new(
+        Head::new(),
+        vec![p!() + (button!(attributes = {"onclick" => Some("alert('hi')")}) + text!("Click"))],
+    );
+    let error = policy.apply(&unsafe_document).unwrap_err();
+    println!("{} {}", error, std::error::Error::source(&error).unwrap());
+}
@@ -90,12 +90,15 @@ fn main() {
         "definition_list.html".to_string(),
         head,
         vec![
-            term_definition_list_from_map(&map, HtmlElementConfig::new_empty()).unwrap(),
+            term_definition_list_from_map(&map, HtmlElementConfig::new_empty())
+                .unwrap()
+                .unwrap(),
             from_iterator(
                 &languages,
                 &ListType::TermDefinition,
                 HtmlElementConfig::new_empty(),
             )
+            .unwrap()
             .unwrap(),
         ],
     )
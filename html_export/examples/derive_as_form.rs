@@ -0,0 +1,70 @@
+use html_export::composed::forms::form::AsForm;
+use html_export::head::Head;
+use html_export::*;
+extern crate html_export;
+
+#[derive(AsForm)]
+#[form(method = "POST", action = "/cars", legend = "Car", submit = "Save")]
+pub struct Car {
+    #[form(placeholder = "Model name", pattern = "[A-Za-z0-9 ]+", max_length = 40)]
+    model: String,
+    #[form(label = "Production year", min = 1900, max = 2100)]
+    year: u16,
+    #[form(min = 0, step = 0.5)]
+    engine_size: Option<f64>,
+    electric: bool,
+    #[form(radio)]
+    color: Color,
+    gearbox: Option<Gearbox>,
+    #[form(nested)]
+    owner: Owner,
+    #[form(skip)]
+    _internal_id: u64,
+}
+
+#[derive(AsForm)]
+#[form(legend = "Owner")]
+pub struct Owner {
+    #[form(name = "owner_name", label = "Name")]
+    name: String,
+    #[form(name = "owner_email", label = "E-mail")]
+    email: Option<String>,
+}
+
+#[derive(AsForm)]
+pub enum Color {
+    Red,
+    Green,
+    #[form(label = "Dark blue", value = "dark-blue")]
+    DarkBlue,
+}
+
+#[derive(AsForm)]
+pub enum Gearbox {
+    Manual,
+    Automatic,
+}
+
+fn main() {
+    let car = Car {
+        model: "Roadster".to_string(),
+        year: 2008,
+        engine_size: None,
+        electric: true,
+        color: Color::DarkBlue,
+        gearbox: Some(Gearbox::Manual),
+        owner: Owner {
+            name: "John Doe".to_string(),
+            email: None,
+        },
+        _internal_id: 0,
+    };
+    let head = Head::new().with_title("Derive AsForm".to_string());
+    export_to_file(
+        "examples_output".to_string(),
+        "derive_as_form.html".to_string(),
+        head,
+        vec![car.try_as_form_field().unwrap()],
+    )
+    .unwrap();
+}
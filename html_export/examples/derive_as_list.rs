@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use html_export::composed::list::{from_iterator, term_definition_list_from_map, AsList, ListType};
+use html_export::element::HtmlElementConfig;
+use html_export::head::Head;
+use html_export::*;
+extern crate html_export;
+
+#[derive(AsList)]
+#[list(class = "language")]
+pub struct ProgrammingLanguage {
+    #[list(term, tag = "strong")]
+    name: String,
+    #[list(definition, label = "Published in")]
+    published_year: u32,
+    #[list(skip)]
+    _creator: String,
+}
+
+#[derive(AsList)]
+pub enum Task {
+    #[list(class = "done")]
+    Done(String),
+    #[list(class = "pending")]
+    Pending {
+        #[list(term)]
+        name: String,
+        #[list(definition, format = "format_progress")]
+        progress: f64,
+    },
+    #[list(label = "Nothing to do")]
+    Idle,
+    #[list(skip)]
+    Hidden,
+}
+
+#[derive(AsList, Eq, Hash, PartialEq)]
+#[list(term)]
+pub struct Country(String);
+
+#[derive(AsList)]
+#[list(definition)]
+pub struct Population(#[list(tag = "em")] u64);
+
+fn format_progress(progress: &f64) -> String {
+    format!("{:.0} %", progress * 100.0)
+}
+
+fn main() {
+    let languages = vec![
+        ProgrammingLanguage {
+            name: "Rust".to_string(),
+            published_year: 2015,
+            _creator: "Graydon Hoare".to_string(),
+        },
+        ProgrammingLanguage {
+            name: "C".to_string(),
+            published_year: 1972,
+            _creator: "Dennis Ritchie".to_string(),
+        },
+    ];
+    let tasks = vec![
+        Task::Done("Write the parser".to_string()),
+        Task::Pending {
+            name: "Write the docs".to_string(),
+            progress: 0.4,
+        },
+        Task::Idle,
+        Task::Hidden,
+    ];
+    let populations = HashMap::from([
+        (Country("France".to_string()), Population(68_000_000)),
+        (Country("Japan".to_string()), Population(124_000_000)),
+    ]);
+
+    let mut body = vec![];
+    for (items, mode) in [
+        (&languages, ListType::Unordered),
+        (&languages, ListType::TermDefinition),
+    ] {
+        body.push(
+            from_iterator(items, &mode, HtmlElementConfig::new_empty())
+                .unwrap()
+                .unwrap(),
+        );
+    }
+    for mode in [ListType::Ordered, ListType::TermDefinition] {
+        body.push(
+            from_iterator(&tasks, &mode, HtmlElementConfig::new_empty())
+                .unwrap()
+                .unwrap(),
+        );
+    }
+    body.push(
+        term_definition_list_from_map(&populations, HtmlElementConfig::new_empty())
+            .unwrap()
+            .unwrap(),
+    );
+
+    let head = Head::new().with_title("Derive AsList".to_string());
+    export_to_file(
+        "examples_output".to_string(),
+        "derive_as_list.html".to_string(),
+        head,
+        body,
+    )
+    .unwrap();
+}
@@ -0,0 +1,60 @@
+use html_export::composed::table::{from_iterator, AsTable};
+use html_export::element::HtmlElementConfig;
+use html_export::head::Head;
+use html_export::*;
+extern crate html_export;
+
+#[derive(AsTable)]
+#[table(foot_label = "Total")]
+pub struct Item {
+    #[table(order = 1)]
+    name: String,
+    #[table(header = "Unit price", format = "format_price", min, class = "price")]
+    unit_price: f64,
+    #[table(avg)]
+    quantity: u64,
+    #[table(format = "format_price", sum, class = "price")]
+    total: f64,
+    #[table(skip)]
+    _internal_id: u64,
+}
+
+impl Item {
+    pub fn new(name: &str, unit_price: f64, quantity: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            unit_price,
+            quantity,
+            total: unit_price * quantity as f64,
+            _internal_id: 0,
+        }
+    }
+}
+
+fn format_price(price: &f64) -> String {
+    format!("{:.2} €", price)
+}
+
+fn main() {
+    let items = vec![
+        Item::new("Item A", 10.0, 3),
+        Item::new("Item B", 11.0, 2),
+        Item::new("Item C", 20.0, 5),
+        Item::new("Item D", 5.0, 1),
+    ];
+    let table = from_iterator(
+        &items,
+        HtmlElementConfig::new_empty(),
+        HtmlElementConfig::new_empty(),
+    )
+    .unwrap()
+    .unwrap();
+    let head = Head::new().with_title("Derive AsTable".to_string());
+    export_to_file(
+        "examples_output".to_string(),
+        "derive_as_table.html".to_string(),
+        head,
+        vec![table],
+    )
+    .unwrap();
+}
@@ -0,0 +1,67 @@
+use html_export::head::Head;
+use html_export::html::ToHtml;
+use html_export::*;
+extern crate html_export;
+
+#[derive(ToHtml)]
+#[html(tag = "article", class = "person")]
+pub struct Person {
+    #[html(tag = "h2")]
+    name: String,
+    #[html(tag = "p", label = "Age", class = "age")]
+    age: u8,
+    #[html(tag = "p", label = "Height", format = "format_height")]
+    height: f64,
+    #[html(nested)]
+    status: Status,
+    #[html(skip)]
+    _password: String,
+}
+
+#[derive(ToHtml)]
+#[html(tag = "p", class = "status")]
+pub enum Status {
+    #[html(class = "active", label = "Active")]
+    Active,
+    Retired,
+    #[html(tag = "div")]
+    Away(#[html(label = "Back on")] String),
+}
+
+fn format_height(height: &f64) -> String {
+    format!("{:.2} m", height)
+}
+
+fn main() {
+    let persons = [
+        Person {
+            name: "Johns James".to_string(),
+            age: 20,
+            height: 1.8,
+            status: Status::Active,
+            _password: "secret".to_string(),
+        },
+        Person {
+            name: "Taylor Mary".to_string(),
+            age: 70,
+            height: 1.65,
+            status: Status::Retired,
+            _password: "secret".to_string(),
+        },
+        Person {
+            name: "Brown Robert".to_string(),
+            age: 40,
+            height: 1.72,
+            status: Status::Away("2025-09-01".to_string()),
+            _password: "secret".to_string(),
+        },
+    ];
+    let head = Head::new().with_title("Derive ToHtml".to_string());
+    export_to_file(
+        "examples_output".to_string(),
+        "derive_to_html.html".to_string(),
+        head,
+        persons.iter().map(|person| person.to_html()).collect(),
+    )
+    .unwrap();
+}
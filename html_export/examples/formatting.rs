@@ -0,0 +1,57 @@
+use html_export::component::RenderContext;
+use html_export::document::Document;
+use html_export::element::Element;
+use html_export::errors::HtmlResult;
+use html_export::formatting::{Date, DateStyle, Formatter, Time};
+use html_export::head::Head;
+use html_export::tags::*;
+use html_export::{elem, h2, table, td, text, th, tr};
+
+/// Renders a table of formatted values for a locale.
+fn values(formatter: &Formatter) -> HtmlResult<Element> {
+    let date = Date::new(2024, 3, 14).unwrap();
+    let time = Time::new(14, 5, 0).unwrap();
+    let rows = vec![
+        ("Number", formatter.number_element(1234567.891, 2)?),
+        ("Percentage", formatter.percent_element(0.125, 1)?),
+        ("Euros", formatter.currency_element(-1234.5, "EUR")?),
+        ("Yens", formatter.currency_element(1234.5, "JPY")?),
+        ("Francs", formatter.currency_element(1234.5, "CHF")?),
+        ("Weight", formatter.unit_element(12.5, 1, "kg")?),
+        (
+            "Short date",
+            formatter.date_element(&date, DateStyle::Short)?,
+        ),
+        (
+            "Medium date",
+            formatter.date_element(&date, DateStyle::Medium)?,
+        ),
+        ("Long date", formatter.date_element(&date, DateStyle::Long)?),
+        ("Time", formatter.time_element(&time)?),
+        (
+            "Date and time",
+            formatter.date_time_element(&date, &time, DateStyle::Medium)?,
+        ),
+        ("Past", formatter.relative_duration_element(-7200)?),
+        ("Future", formatter.relative_duration_element(3 * 86_400)?),
+        ("One day", formatter.relative_duration_element(-86_400)?),
+    ];
+    let mut table = table!() + (tr!() + (th!() + text!("Value")) + (th!() + text!("Formatted")));
+    for (name, value) in rows {
+        table += tr!() + (td!() + text!(name)) + (td!() + value);
+    }
+    Ok(table)
+}
+
+fn main() {
+    let mut body = Vec::new();
+    for locale in ["en-US", "en-GB", "fr-FR", "de", "es", "ja"] {
+        let context = RenderContext::new().with_locale(locale.to_string());
+        body.push(h2!() + text!(locale));
+        body.push(values(&context.formatter()).unwrap());
+    }
+    Document::new(Head::new().with_title("Formatting".to_string()), body)
+        .with_locale("en".to_string())
+        .export_to_file("examples_output".to_string(), "formatting.html".to_string())
+        .unwrap();
+}
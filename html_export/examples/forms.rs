@@ -92,21 +92,24 @@ impl AsForm for Car {
             ),
             TextInputConfig::new().with_place_holder("Enter model name".to_string()),
             Some(self.model_name.clone()),
-        );
+        )
+        .ok()?;
         let brand_field = self.brand.as_form_field().unwrap();
         let vehicul_fieldset = wrap_fields_in_fieldset(
             vec![model_name_field, brand_field],
             "Vehicule model".to_string(),
             HtmlElementConfig::new_empty(),
             HtmlElementConfig::new_empty(),
-        );
+        )
+        .ok()?;
 
         let motor_fieldset = wrap_fields_in_fieldset(
             vec![self.motor.as_form_field().unwrap()],
             "Motor".to_string(),
             HtmlElementConfig::new_empty(),
             HtmlElementConfig::new_empty(),
-        );
+        )
+        .ok()?;
 
         let color_input = color_input::create_labeled_color_input(
             InputFieldConfig::new(
@@ -115,7 +118,8 @@ impl AsForm for Car {
                 "color".to_string(),
             ),
             Some(self.color.clone()),
-        );
+        )
+        .ok()?;
 
         let date_input = date_input::create_date_input_with_label(
             InputFieldConfig::new(
@@ -125,7 +129,8 @@ impl AsForm for Car {
             ),
             DateInputConfig::new(false),
             Some(self.bought_the.clone()),
-        );
+        )
+        .ok()?;
         let dealership = self.dealership.as_form_field().unwrap();
         let hidden = create_hidden_input(
             InputFieldConfig::new(
@@ -144,7 +149,8 @@ impl AsForm for Car {
             ),
             NumberInputConfigs::new(),
             Some(self.power),
-        );
+        )
+        .ok()?;
 
         let size = create_labeled_range_input(
             InputFieldConfig::new(
@@ -154,7 +160,8 @@ impl AsForm for Car {
             ),
             RangeInputConfigs::new(3.0, 6.0),
             Some(self.length),
-        );
+        )
+        .ok()?;
 
         let start = create_labeled_time_input(
             InputFieldConfig::new(
@@ -164,7 +171,8 @@ impl AsForm for Car {
             ),
             TimeInputConfigs::new(),
             Some(self.last_start_time.clone()),
-        );
+        )
+        .ok()?;
 
         let benchmark = create_labeled_week_input(
             InputFieldConfig::new(
@@ -174,7 +182,8 @@ impl AsForm for Car {
             ),
             WeekInputConfigs::new(),
             Some(self.benchmark_week.clone()),
-        );
+        )
+        .ok()?;
 
         let description = create_labeled_text_area(
             InputFieldConfig::new(
@@ -184,7 +193,8 @@ impl AsForm for Car {
             ),
             TextAreaInputConfigs::new(),
             Some(self.description.clone()),
-        );
+        )
+        .ok()?;
 
         let tires = self.tyres.as_form_field().unwrap();
 
@@ -195,7 +205,8 @@ impl AsForm for Car {
                 "Reset form".to_string(),
             ),
             Some("reset".to_string()),
-        );
+        )
+        .ok()?;
 
         let submit = create_submit_input(
             InputFieldConfig::new(
@@ -226,6 +237,7 @@ impl AsForm for Car {
             FormConfig::new().with_method("POST".to_string()),
             HtmlElementConfig::new_empty(),
         )
+        .ok()?
     }
 }
 
@@ -284,7 +296,7 @@ impl AsForm for CarBrand {
 
 impl AsForm for Motor {
     fn as_form_field(&self) -> Option<Element> {
-        Some(create_labeled_checkboxes(
+        create_labeled_checkboxes(
             InputFieldConfig::new("motor".to_string(), "".to_string(), "".to_string()),
             vec![
                 (
@@ -300,7 +312,8 @@ impl AsForm for Motor {
                     self.is_thermic,
                 ),
             ],
-        ))
+        )
+        .ok()
     }
 }
 
@@ -314,11 +327,13 @@ impl AsForm for DealerShip {
             ),
             EmailInputConfig::new(),
             Some(self.email.to_string()),
-        );
+        )
+        .ok()?;
         let bill = create_labeled_file_input(
             InputFieldConfig::new("bill".to_string(), "bill".to_string(), "Bill".to_string()),
             FileInputConfig::new(),
-        );
+        )
+        .ok()?;
 
         let warranty = create_labeled_mount_input(
             InputFieldConfig::new(
@@ -328,7 +343,8 @@ impl AsForm for DealerShip {
             ),
             MonthInputConfig::new(),
             Some(self.warranty.clone()),
-        );
+        )
+        .ok()?;
 
         let secret_id = create_labeled_password_input(
             InputFieldConfig::new(
@@ -338,7 +354,8 @@ impl AsForm for DealerShip {
             ),
             PasswordInputConfig::new(),
             Some(self.secret_id.clone()),
-        );
+        )
+        .ok()?;
 
         let phone = create_labeled_tel_input(
             InputFieldConfig::new(
@@ -348,20 +365,23 @@ impl AsForm for DealerShip {
             ),
             TelInputConfigs::new(),
             Some(self.phone.clone()),
-        );
+        )
+        .ok()?;
 
         let url = create_labeled_url_input(
             InputFieldConfig::new("url".to_string(), "url".to_string(), "Url".to_string()),
             UrlInputConfigs::new(),
             Some(self.website.clone()),
-        );
+        )
+        .ok()?;
 
-        Some(wrap_fields_in_fieldset(
+        wrap_fields_in_fieldset(
             vec![email, bill, warranty, secret_id, phone, url],
             "Dealership".to_string(),
             HtmlElementConfig::new_empty(),
             HtmlElementConfig::new_empty(),
-        ))
+        )
+        .ok()
     }
 }
 
@@ -402,7 +422,7 @@ impl AsForm for Tyres {
         let group2 = SelectOptionGroup::new()
             .with_content(options2.into_iter())
             .with_label("Race".to_string());
-        Some(create_labeled_select(
+        create_labeled_select(
             InputFieldConfig::new(
                 "tyres".to_string(),
                 "tyres".to_string(),
@@ -413,7 +433,8 @@ impl AsForm for Tyres {
                 Options::OptionGroup(group1, HtmlElementConfig::new_empty()),
                 Options::OptionGroup(group2, HtmlElementConfig::new_empty()),
             ],
-        ))
+        )
+        .ok()
     }
 }
 
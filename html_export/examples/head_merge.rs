@@ -0,0 +1,39 @@
+use html_export::head::{Head, Viewport};
+use html_export::miscellaneous::ScriptLoadMode;
+use html_export::tags::*;
+use html_export::{elem, export_to_file, h1, text};
+
+/// The head entries shared by every report page.
+fn shared_head() -> Head {
+    Head::new()
+        .with_title("Reports".to_string())
+        .with_css_file("reports.css".to_string())
+        .with_javascript_file("menu.js".to_string(), Some(ScriptLoadMode::Defer))
+        .with_charset_meta("utf-8".to_string())
+}
+
+/// The head entries of the charts helper, which also loads the shared stylesheet.
+fn charts_head() -> Head {
+    Head::new()
+        .with_css_file("reports.css".to_string())
+        .with_css_file("charts.css".to_string())
+        .with_javascript_file("menu.js".to_string(), None)
+        .with_viewport(&Viewport::new())
+        .with_charset_meta("UTF-8".to_string())
+        .with_title("Reports".to_string())
+}
+
+fn main() {
+    // The charset is moved first, the second title, charset, stylesheet and script are dropped.
+    let head = shared_head().merge(charts_head()).unwrap();
+    export_to_file(
+        "examples_output".to_string(),
+        "head_merge.html".to_string(),
+        head,
+        vec![h1!() + text!("Reports")],
+    )
+    .unwrap();
+
+    let conflicting = Head::new().with_title("Sales".to_string());
+    println!("{}", shared_head().merge(conflicting).unwrap_err());
+}
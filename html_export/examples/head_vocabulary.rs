@@ -0,0 +1,106 @@
+use html_export::head::{FetchOptions, Head, Viewport};
+use html_export::manifest::{DisplayMode, ManifestIcon, WebManifest, MANIFEST_FILENAME};
+use html_export::miscellaneous::{
+    CrossOrigin, FeedFormat, HttpEquiv, PreloadDestination, ReferrerPolicy, ResourceHint,
+    ScriptLoadMode,
+};
+use html_export::tags::*;
+use html_export::{elem, export_to_file, h1, p, text};
+
+fn main() {
+    let manifest = WebManifest::new("Sales reports".to_string())
+        .with_short_name("Reports".to_string())
+        .with_display(DisplayMode::Standalone)
+        .with_colors("#ffffff".to_string(), "#1f6feb".to_string())
+        .with_locale("en-US")
+        .with_icon(ManifestIcon::new(
+            "icons/icon-192.png".to_string(),
+            "image/png".to_string(),
+            &[(192, 192)],
+        ))
+        .with_icon(
+            ManifestIcon::new(
+                "icons/icon-512.png".to_string(),
+                "image/png".to_string(),
+                &[(512, 512)],
+            )
+            .with_maskable(),
+        );
+    manifest
+        .export_to_file("examples_output".to_string(), MANIFEST_FILENAME.to_string())
+        .unwrap();
+
+    let cdn = FetchOptions::new()
+        .with_cross_origin(CrossOrigin::Anonymous)
+        .with_referrer_policy(ReferrerPolicy::NoReferrer);
+    let head = Head::new()
+        .with_charset_meta("utf-8".to_string())
+        .with_viewport(&Viewport::new())
+        .with_http_equiv(HttpEquiv::XUaCompatible, "IE=edge".to_string())
+        .with_title("Sales reports".to_string())
+        .with_theme_color("#1f6feb".to_string())
+        .with_canonical_url("https://reports.example.com/".to_string())
+        .with_resource_hint(
+            ResourceHint::Preconnect,
+            "https://cdn.example.com".to_string(),
+            None,
+            &cdn,
+        )
+        .with_resource_hint(
+            ResourceHint::Preload,
+            "https://cdn.example.com/fonts/inter.woff2".to_string(),
+            Some(PreloadDestination::Font),
+            &cdn,
+        )
+        .with_resource_hint(
+            ResourceHint::ModulePreload,
+            "js/charts.js".to_string(),
+            None,
+            &FetchOptions::new(),
+        )
+        .with_resource_hint(
+            ResourceHint::Prefetch,
+            "2024/q4.html".to_string(),
+            Some(PreloadDestination::Document),
+            &FetchOptions::new(),
+        )
+        .with_css_file_options("https://cdn.example.com/reports.css".to_string(), &cdn)
+        .with_javascript_file_options(
+            "https://cdn.example.com/analytics.js".to_string(),
+            Some(ScriptLoadMode::Async),
+            &cdn,
+        )
+        .with_module_file("js/app.js".to_string(), &FetchOptions::new())
+        .with_raw_module("import { draw } from './js/charts.js'; draw();".to_string())
+        .with_icon(
+            "favicon.png".to_string(),
+            "image/png".to_string(),
+            &[(16, 16), (32, 32)],
+        )
+        .with_icon("favicon.svg".to_string(), "image/svg+xml".to_string(), &[])
+        .with_apple_touch_icon("icons/apple-touch-icon.png".to_string(), Some(180))
+        .with_manifest(MANIFEST_FILENAME.to_string())
+        .with_alternate_feed(
+            "feed.xml".to_string(),
+            FeedFormat::Atom,
+            "Sales reports".to_string(),
+        )
+        .with_alternate_language(
+            "https://reports.example.com/fr/".to_string(),
+            "fr".to_string(),
+        )
+        .with_alternate_language(
+            "https://reports.example.com/".to_string(),
+            "x-default".to_string(),
+        );
+    export_to_file(
+        "examples_output".to_string(),
+        "head_vocabulary.html".to_string(),
+        head,
+        vec![
+            h1!() + text!("Sales reports"),
+            p!() + text!("Sales are up."),
+        ],
+    )
+    .unwrap();
+}
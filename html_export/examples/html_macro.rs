@@ -0,0 +1,72 @@
+use html_export::element::Element;
+use html_export::head::Head;
+use html_export::*;
+extern crate html_export;
+
+pub struct Book {
+    title: String,
+    author: String,
+    year: Option<u32>,
+}
+
+fn book_card(book: &Book, index: usize) -> Element {
+    html! {
+        <article class="card book" id={format!("book-{}", index)} data-index={index}>
+            <h2>{&book.title}</h2>
+            <p>"By " {&book.author}</p>
+            if let Some(year) = book.year {
+                <p class="year">"Published in " {year}</p>
+            } else {
+                <p class="year unknown">"Unknown publication date"</p>
+            }
+        </article>
+    }
+}
+
+fn main() {
+    let books = [
+        Book {
+            title: "The Rust Programming Language".to_string(),
+            author: "Steve Klabnik & Carol Nichols".to_string(),
+            year: Some(2018),
+        },
+        Book {
+            title: "Programming <Rust>".to_string(),
+            author: "Jim Blandy".to_string(),
+            year: None,
+        },
+    ];
+    let tags = ["rust", "books", "html"];
+
+    let body = html! {
+        <main>
+            <h1>"Library"</h1>
+            if books.is_empty() {
+                <p>"No book yet"</p>
+            } else {
+                <section class="books">
+                    {for books.iter().enumerate().map(|(index, book)| book_card(book, index))}
+                </section>
+            }
+            <ul class="tags">
+                for tag in tags {
+                    <li><a href={format!("/tags/{}", tag)}>{tag}</a></li>
+                }
+            </ul>
+            <form action="/search" method="get">
+                <label for="search">"Search"</label>
+                <input type="search" id="search" name="q" required />
+                <button type="submit">"Go"</button>
+            </form>
+        </main>
+    };
+
+    let head = Head::new().with_title("Html macro".to_string());
+    export_to_file(
+        "examples_output".to_string(),
+        "html_macro.html".to_string(),
+        head,
+        vec![body],
+    )
+    .unwrap();
+}
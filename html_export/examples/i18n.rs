@@ -0,0 +1,70 @@
+use html_export::component::RenderContext;
+use html_export::composed::forms::fields::field::InputFieldConfig;
+use html_export::composed::forms::fields::text_input::{create_label_text_field, TextInputConfig};
+use html_export::composed::forms::form::wrap_fields_in_fieldset;
+use html_export::document::Document;
+use html_export::element::HtmlElementConfig;
+use html_export::errors::HtmlResult;
+use html_export::head::Head;
+use html_export::i18n::{isolate, Localizer};
+use html_export::tags::*;
+use html_export::{elem, h1, p, table, td, text, th, tr};
+
+/// Renders the report in the context's locale.
+fn report(context: &RenderContext, customer: &str, orders: u64) -> HtmlResult<Document> {
+    let title = context.translate("report.title", &[])?;
+    let body = vec![
+        h1!() + text!(title),
+        p!() + text!(context.translate("report.greeting", &[("name", customer.to_string())])?),
+        p!() + text!(context.translate_plural("report.rows", orders, &[])?),
+        p!() + isolate(customer)?,
+        table!()
+            + (tr!()
+                + (th!() + text!(context.translate("table.region", &[])?))
+                + (th!() + text!(context.translate("table.sales", &[])?)))
+            + (tr!() + (td!() + text!("North")) + (td!() + text!("1 200"))),
+        wrap_fields_in_fieldset(
+            vec![create_label_text_field(
+                InputFieldConfig::new(
+                    "name".to_string(),
+                    "name".to_string(),
+                    context.translate("form.name", &[])?,
+                ),
+                TextInputConfig::new(),
+                None,
+            )?],
+            context.translate("form.legend", &[])?,
+            HtmlElementConfig::new_empty(),
+            HtmlElementConfig::new_empty(),
+        )?,
+    ];
+    Ok(Document::new(
+        Head::new()
+            .with_charset_meta("utf-8".to_string())
+            .with_title(title),
+        body,
+    )
+    .with_locale(context.locale.clone()))
+}
+
+fn main() {
+    let localizer = Localizer::new("en".to_string())
+        .load_directory("examples/locales")
+        .unwrap();
+    for (locale, orders) in [("en", 1), ("fr-CA", 0), ("ar-EG", 11)] {
+        let context = RenderContext::new()
+            .with_locale(locale.to_string())
+            .with_localizer(localizer.clone());
+        report(&context, "شركة النور", orders)
+            .unwrap()
+            .export_to_file(
+                "examples_output".to_string(),
+                format!("i18n_{}.html", locale),
+            )
+            .unwrap();
+    }
+
+    let english = localizer.for_locale("en");
+    println!("{}", english.translate("report.greeting", &[]).unwrap_err());
+    println!("{}", english.translate("report.missing", &[]).unwrap_err());
+}
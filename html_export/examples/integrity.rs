@@ -0,0 +1,56 @@
+use std::fs;
+
+use html_export::document::Document;
+use html_export::head::{FetchOptions, Head};
+use html_export::integrity::IntegrityRegistry;
+use html_export::miscellaneous::ScriptLoadMode;
+use html_export::tags::*;
+use html_export::{elem, h1, text};
+
+fn main() {
+    let head = Head::new()
+        .with_charset_meta("utf-8".to_string())
+        .with_title("Integrity".to_string())
+        .with_css_file("css/style.css".to_string())
+        .with_javascript_file("app.js".to_string(), Some(ScriptLoadMode::Defer))
+        // Remote files use precomputed hashes.
+        .with_javascript_file_options(
+            "https://cdn.example.com/charts.js".to_string(),
+            None,
+            &FetchOptions::new().with_integrity(
+                "sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC"
+                    .to_string(),
+            ),
+        );
+    let document = Document::new(head, vec![h1!() + text!("Integrity")]);
+
+    // The local files are hashed at export time, the hashes are saved for the next exports.
+    let mut registry = IntegrityRegistry::new("examples/static".to_string());
+    registry
+        .export_document(
+            &document,
+            "examples_output".to_string(),
+            "integrity.html".to_string(),
+        )
+        .unwrap();
+    registry.save("examples_output/integrity.lock").unwrap();
+
+    // A file changed since its hash was recorded fails the export.
+    fs::create_dir_all("examples_output/static").unwrap();
+    fs::write("examples_output/static/app.js", "console.log('changed');").unwrap();
+    let mut registry = IntegrityRegistry::new("examples_output/static".to_string())
+        .load("examples_output/integrity.lock")
+        .unwrap();
+    let script = Document::new(
+        Head::new().with_javascript_file("app.js".to_string(), None),
+        vec![],
+    );
+    let error = registry
+        .export_document(
+            &script,
+            "examples_output".to_string(),
+            "changed.html".to_string(),
+        )
+        .unwrap_err();
+    println!("{} {}", error, std::error::Error::source(&error).unwrap());
+}
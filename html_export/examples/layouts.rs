@@ -0,0 +1,50 @@
+use html_export::head::Head;
+use html_export::layout::Layout;
+use html_export::tags::*;
+use html_export::{div, elem, footer, h1, header, li, main, nav, p, slot, text, ul};
+
+fn main() {
+    let base = Layout::new(vec![
+        header!() + (h1!() + text!("Monthly reports")),
+        nav!() + (ul!() + (li!() + text!("Sales")) + (li!() + text!("Support"))),
+        main!() + slot!("content"),
+        footer!() + slot!("footer"),
+    ])
+    .with_head(
+        Head::new()
+            .with_charset_meta("utf-8".to_string())
+            .with_title("Reports".to_string())
+            .with_css_file("reports.css".to_string()),
+    )
+    .with_block(
+        "footer".to_string(),
+        vec![p!() + text!("Internal use only")],
+    );
+
+    let sales = Layout::extend(&base)
+        .with_head(Head::new().with_css_file("charts.css".to_string()))
+        .with_block(
+            "content".to_string(),
+            vec![div!(classes = ["chart"]) + text!("Sales chart")],
+        )
+        .extend_block(
+            "footer".to_string(),
+            vec![p!() + text!("Source : sales database")],
+        );
+
+    let march = Layout::extend(&sales)
+        .with_title("Sales - March".to_string())
+        .extend_block(
+            "content".to_string(),
+            vec![p!() + text!("March was a record month.")],
+        );
+
+    march
+        .render()
+        .unwrap()
+        .export_to_file("examples_output".to_string(), "layouts.html".to_string())
+        .unwrap();
+
+    let invalid = Layout::extend(&base).with_block("sidebar".to_string(), vec![]);
+    println!("{}", invalid.render().unwrap_err());
+}
@@ -0,0 +1,48 @@
+use html_export::document::Document;
+use html_export::head::Head;
+use html_export::markdown::{markdown_to_elements, MarkdownOptions, RawHtml};
+
+const REPORT: &str = r#"# Monthly report
+
+Sales grew by **12 %** in March[^source], see the [detailed figures](figures.md#march).
+
+## Figures
+
+| Region | Sales | Growth |
+|:-------|------:|:------:|
+| North  | 1 200 | +8 %   |
+| South  |   950 | +17 %  |
+
+## Next steps
+
+- [x] Collect the data
+- [ ] Review with the ~~sales~~ finance team
+
+> Numbers are provisional.
+
+```rust
+let total = north + south;
+```
+
+<script>alert("not trusted")</script>
+
+![Sales chart](images/chart.png "March sales")
+
+[^source]: Extracted from the sales database on *April 2nd*.
+"#;
+
+fn main() {
+    let options = MarkdownOptions::new()
+        .with_id_prefix("report-".to_string())
+        .with_raw_html(RawHtml::Escape)
+        .with_link_rewriter(|url| url.replace(".md", ".html"));
+    let body = markdown_to_elements(REPORT, &options);
+    Document::new(
+        Head::new()
+            .with_charset_meta("utf-8".to_string())
+            .with_title("Monthly report".to_string()),
+        body,
+    )
+    .export_to_file("examples_output".to_string(), "markdown.html".to_string())
+    .unwrap();
+}
@@ -0,0 +1,29 @@
+use html_export::element::*;
+use html_export::head::Head;
+use html_export::tags::*;
+use html_export::*;
+extern crate html_export;
+
+fn main() {
+    let mut list = ul!(id = "fruits", classes = ["list", "fruits"]);
+    list += li!() + text!("Apple");
+    list += li!(attributes = {"hidden" => None::<&str>}) + text!("Banana");
+    list += li!() + (a!(attributes = {"href" => Some("/cherry")}) + text!("Cherry"));
+
+    // The tree can be cached or sent to another process as JSON ...
+    let json = serde_json::to_string_pretty(&list).unwrap();
+    println!("{}", json);
+
+    // ... and rendered there once deserialized.
+    let restored: Element = serde_json::from_str(&json).unwrap();
+    assert_eq!(list, restored);
+
+    let head = Head::new().with_title("Serde round trip".to_string());
+    export_to_file(
+        "examples_output".to_string(),
+        "serde_round_trip.html".to_string(),
+        head,
+        vec![restored],
+    )
+    .unwrap();
+}
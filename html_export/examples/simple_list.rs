@@ -55,10 +55,12 @@ fn main() {
         &ListType::Unordered,
         HtmlElementConfig::new_empty(),
     )
+    .unwrap()
     .unwrap();
 
-    let ol_list =
-        from_iterator(&persons, &ListType::Ordered, HtmlElementConfig::new_empty()).unwrap();
+    let ol_list = from_iterator(&persons, &ListType::Ordered, HtmlElementConfig::new_empty())
+        .unwrap()
+        .unwrap();
     let head = Head::new().with_title("Simple list".to_string());
     export_to_file(
         "examples_output".to_string(),
@@ -56,6 +56,7 @@ fn main() {
         HtmlElementConfig::new_empty(),
         HtmlElementConfig::new_empty(),
     )
+    .unwrap()
     .unwrap();
     let head = Head::new().with_title("Simple table".to_string());
     export_to_file(
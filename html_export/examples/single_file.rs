@@ -0,0 +1,28 @@
+use html_export::document::Document;
+use html_export::head::Head;
+use html_export::miscellaneous::ScriptLoadMode;
+use html_export::tags::*;
+use html_export::{elem, h1, img, text};
+
+fn main() {
+    let head = Head::new()
+        .with_charset_meta("utf-8".to_string())
+        .with_title("Monthly report".to_string())
+        .with_css_file("css/style.css".to_string())
+        .with_javascript_file("app.js".to_string(), Some(ScriptLoadMode::Defer))
+        .with_ico_icon("images/logo.svg".to_string());
+    let report = Document::new(
+        head,
+        vec![
+            img!(attributes = {"src" => Some("images/logo.svg"), "alt" => Some("Logo")}),
+            h1!() + text!("Monthly report"),
+        ],
+    );
+    report
+        .export_to_single_file(
+            "examples/static",
+            "examples_output".to_string(),
+            "single_file.html".to_string(),
+        )
+        .unwrap();
+}
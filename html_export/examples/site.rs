@@ -0,0 +1,54 @@
+use html_export::layout::Layout;
+use html_export::site::Site;
+use html_export::tags::*;
+use html_export::{a, elem, h1, li, main, nav, p, slot, text, ul};
+
+fn main() {
+    let base = Layout::new(vec![
+        nav!()
+            + (ul!()
+                + (li!() + (a!(attributes = {"href" => Some("route:")}) + text!("Home")))
+                + (li!() + (a!(attributes = {"href" => Some("route:about")}) + text!("About")))),
+        main!() + slot!("content"),
+    ]);
+
+    let mut site = Site::new(
+        "Monthly reports".to_string(),
+        "https://reports.example.com".to_string(),
+    );
+    let months = ["january", "february", "march"];
+    for (index, month) in months.iter().enumerate() {
+        let mut content = vec![h1!() + text!(format!("Report of {}", month))];
+        if index > 0 {
+            content.push(
+                p!() + (a!(attributes = {"href" => Some(format!("route:reports/{}#total", months[index - 1]))})
+                    + text!("Previous month")),
+            );
+        }
+        let page = Layout::extend(&base)
+            .with_title(format!("Report - {}", month))
+            .with_block("content".to_string(), content)
+            .render()
+            .unwrap();
+        site = site.with_page(format!("reports/{}", month), month.to_string(), page);
+    }
+    let about = Layout::extend(&base)
+        .with_title("About".to_string())
+        .with_block(
+            "content".to_string(),
+            vec![p!() + text!("Generated nightly.")],
+        )
+        .render()
+        .unwrap();
+    site = site.with_page("about".to_string(), "About".to_string(), about.clone());
+    site.export_to_directory("examples_output/site".to_string())
+        .unwrap();
+
+    let duplicate = site.with_page("/about/".to_string(), "About again".to_string(), about);
+    println!(
+        "{}",
+        duplicate
+            .export_to_directory("examples_output/site".to_string())
+            .unwrap_err()
+    );
+}
@@ -0,0 +1,42 @@
+use html_export::head::{Head, OpenGraphType, SocialImage, SocialMetadata, TwitterCard};
+use html_export::tags::*;
+use html_export::{elem, export_to_file, h1, p, text};
+
+fn main() {
+    let metadata = SocialMetadata::new()
+        .with_title("Quarterly report \"Q3\"".to_string())
+        .with_description("Sales and orders of the third quarter.".to_string())
+        .with_url("https://reports.example.com/2024/q3".to_string())
+        .with_image(
+            SocialImage::new("https://reports.example.com/2024/q3/preview.png".to_string())
+                .with_dimensions(1200, 630)
+                .with_alt("Sales chart of the third quarter".to_string())
+                .with_mime_type("image/png".to_string()),
+        )
+        .with_type(OpenGraphType::Article)
+        .with_site_name("Reports".to_string())
+        .with_locale("en-US".to_string())
+        .with_twitter_card(TwitterCard::SummaryLargeImage, Some("@reports".to_string()));
+
+    // The url and the image are required by the Open Graph protocol.
+    let incomplete = SocialMetadata::new().with_title("Draft".to_string());
+    if let Err(error) = Head::new().with_social_metadata(&incomplete) {
+        println!("{}", error);
+    }
+
+    let head = Head::new()
+        .with_charset_meta("utf-8".to_string())
+        .with_title("Quarterly report".to_string())
+        .with_social_metadata(&metadata)
+        .unwrap();
+    export_to_file(
+        "examples_output".to_string(),
+        "social_metadata.html".to_string(),
+        head,
+        vec![
+            h1!() + text!("Quarterly report"),
+            p!() + text!("Sales are up."),
+        ],
+    )
+    .unwrap();
+}
@@ -0,0 +1,67 @@
+use html_export::formatting::{Date, Time};
+use html_export::head::Head;
+use html_export::structured_data::{
+    Article, BreadcrumbList, DataDownload, Dataset, Event, Organization, Person,
+};
+use html_export::tags::*;
+use html_export::{elem, export_to_file, h1, p, text};
+
+fn main() {
+    let organization = Organization::new("Open Statistics".to_string())
+        .with_url("https://data.example.com".to_string())
+        .with_logo("https://data.example.com/logo.png".to_string());
+
+    let dataset = Dataset::new(
+        "Regional sales 2024".to_string(),
+        "Monthly sales per region, in euros. Values </script> are escaped.".to_string(),
+    )
+    .with_url("https://data.example.com/sales-2024".to_string())
+    .with_keyword("sales".to_string())
+    .with_keyword("regions".to_string())
+    .with_license("https://creativecommons.org/licenses/by/4.0/".to_string())
+    .with_creator(organization.clone())
+    .with_date_modified(Date::new(2024, 10, 1).unwrap())
+    .with_distribution(DataDownload::new(
+        "https://data.example.com/sales-2024.csv".to_string(),
+        "text/csv".to_string(),
+    ));
+
+    let article = Article::new("Sales are up \"again\"".to_string())
+        .with_author(Person::new("Jane Doe".to_string()))
+        .with_publisher(organization.clone())
+        .with_dates(
+            Date::new(2024, 10, 2).unwrap(),
+            Some(Date::new(2024, 10, 3).unwrap()),
+        );
+
+    let breadcrumbs = BreadcrumbList::new()
+        .with_item("Home".to_string(), "https://data.example.com".to_string())
+        .with_item(
+            "Sales 2024".to_string(),
+            "https://data.example.com/sales-2024".to_string(),
+        );
+
+    let event = Event::new("Data meetup".to_string(), Date::new(2024, 11, 14).unwrap())
+        .with_start_time(Time::new(18, 30, 0).unwrap())
+        .with_location("Town hall".to_string(), "1 Main Street".to_string())
+        .with_organizer(organization);
+
+    let head = Head::new()
+        .with_charset_meta("utf-8".to_string())
+        .with_title("Regional sales 2024".to_string())
+        .with_structured_data(&dataset)
+        .and_then(|head| head.with_structured_data(&article))
+        .and_then(|head| head.with_structured_data(&breadcrumbs))
+        .and_then(|head| head.with_structured_data(&event))
+        .unwrap();
+    export_to_file(
+        "examples_output".to_string(),
+        "structured_data.html".to_string(),
+        head,
+        vec![
+            h1!() + text!("Regional sales 2024"),
+            p!() + text!("Monthly sales per region."),
+        ],
+    )
+    .unwrap();
+}
@@ -0,0 +1,38 @@
+use html_export::head::Head;
+use html_export::tags::*;
+use html_export::template::{Template, TemplateContent};
+use html_export::{
+    aside, div, elem, export_to_file, footer, h1, header, li, main, p, slot, text, ul,
+};
+
+fn main() {
+    let skeleton = Template::new(vec![
+        header!() + (h1!() + text!("Welcome {user} !")),
+        div!(classes = ["layout"]) + (aside!() + slot!("sidebar")) + (main!() + slot!("content")),
+        footer!() + (p!() + text!("{{ {site} }}, {year}")),
+    ]);
+
+    let content = TemplateContent::new()
+        .with_slot(
+            "sidebar".to_string(),
+            ul!() + (li!() + text!("Home")) + (li!() + text!("About")),
+        )
+        .with_slot("content".to_string(), p!() + text!("The page content."))
+        .with_value("user".to_string(), "<John & Jane>".to_string())
+        .with_value("site".to_string(), "html_export".to_string())
+        .with_value("year".to_string(), "2024".to_string());
+
+    let body = skeleton.fill(&content).unwrap();
+    export_to_file(
+        "examples_output".to_string(),
+        "template.html".to_string(),
+        Head::new().with_title("Template".to_string()),
+        body,
+    )
+    .unwrap();
+
+    let missing = TemplateContent::new().with_value("user".to_string(), "John".to_string());
+    println!("{}", skeleton.fill(&missing).unwrap_err());
+    let unknown = content.with_slot("footer".to_string(), text!("Unused"));
+    println!("{}", skeleton.fill(&unknown).unwrap_err());
+}
@@ -0,0 +1,57 @@
+use html_export::tags::*;
+use html_export::text::{to_markdown, to_plain_text};
+use html_export::{
+    a, blockquote, code, div, elem, em, h1, h2, li, ol, p, pre, script, strong, table, tbody, td,
+    text, th, thead, tr, ul,
+};
+
+fn main() {
+    let report = vec![
+        h1!() + text!("Release 1.4"),
+        p!() + text!("This release improves the ")
+            + (strong!() + text!("export speed"))
+            + text!(" and fixes the ")
+            + (a!(attributes = {"href" => Some("https://example.com/issues/42")})
+                + text!("layout bug"))
+            + text!(". See the ")
+            + (a!(attributes = {"href" => Some("https://example.com/changelog")})
+                + text!("changelog"))
+            + text!(
+                " for the full list of changes, which is long enough to be wrapped in plain text."
+            ),
+        h2!() + text!("Benchmarks"),
+        table!()
+            + (thead!()
+                + (tr!()
+                    + (th!() + text!("Document"))
+                    + (th!(attributes = {"style" => Some("text-align: right")})
+                        + text!("Before"))
+                    + (th!(attributes = {"style" => Some("text-align: right")}) + text!("After"))))
+            + (tbody!()
+                + (tr!()
+                    + (td!() + text!("Monthly report"))
+                    + (td!() + text!("120 ms"))
+                    + (td!() + text!("45 ms")))
+                + (tr!()
+                    + (td!() + text!("Site &amp; assets"))
+                    + (td!() + text!("2.1 s"))
+                    + (td!() + text!("0.9 s")))),
+        h2!() + text!("Upgrade"),
+        ol!()
+            + (li!() + text!("Update the dependency"))
+            + (li!()
+                + text!("Replace the deprecated calls :")
+                + (ul!()
+                    + (li!() + (code!() + text!("export_to_file")) + text!(" is unchanged"))
+                    + (li!()
+                        + (em!() + text!("Layout::render"))
+                        + text!(" now returns a document")))),
+        pre!() + (code!(classes = ["language-toml"]) + text!("html_export = \"1.4\"")),
+        blockquote!() + (p!() + text!("Thanks to all the contributors!")),
+        div!() + (script!() + text!("console.log(\"not rendered\")")),
+    ];
+
+    println!("{}", to_markdown(&report));
+    println!("----------------------------------------");
+    println!("{}", to_plain_text(&report, 60));
+}
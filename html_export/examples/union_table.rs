@@ -2,6 +2,7 @@ use html_export::composed::table::from_iterator;
 use html_export::composed::table::AsTable;
 use html_export::elem;
 use html_export::element::*;
+use html_export::formatting::Formatter;
 use html_export::head::Head;
 use html_export::tags::*;
 use html_export::thead;
@@ -36,13 +37,16 @@ impl BasicItem {
     }
 
     pub fn as_row(&self) -> Option<Element> {
+        let formatter = Formatter::new("en-US");
         let mut row = tr!();
         row += td!() + Element::Text(self.name.clone());
-        row +=
-            td!(attributes = {"colspan" => Some("2")}) + Element::Text(self.unit_price.to_string());
-        row += td!() + Element::Text(self.quantity.to_string());
+        row += td!(attributes = {"colspan" => Some("2")})
+            + formatter.currency_element(self.unit_price, "USD").ok()?;
+        row += td!() + formatter.number_element(self.quantity as f64, 0).ok()?;
         row += td!(attributes = {"colspan" => Some("3")})
-            + Element::Text((self.unit_price * (self.quantity as f64)).to_string());
+            + formatter
+                .currency_element(self.unit_price * (self.quantity as f64), "USD")
+                .ok()?;
         Some(row)
     }
 }
@@ -57,19 +61,17 @@ impl DiscountedItem {
         }
     }
     pub fn as_row(&self) -> Option<Element> {
+        let formatter = Formatter::new("en-US");
+        let price = self.unit_price * (self.quantity as f64);
+        let discount = self.unit_discount * (self.quantity as f64);
         let mut row = tr!();
         row += td!() + Element::Text(self.name.clone());
-        row += td!() + Element::Text(self.unit_price.to_string());
-        row += td!() + Element::Text(self.unit_discount.to_string());
-        row += td!() + Element::Text(self.quantity.to_string());
-        row += td!() + Element::Text((self.unit_price * (self.quantity as f64)).to_string());
-        row += td!() + Element::Text((self.unit_discount * (self.quantity as f64)).to_string());
-        row += td!()
-            + Element::Text(
-                (self.unit_price * (self.quantity as f64)
-                    - self.unit_discount * (self.quantity as f64))
-                    .to_string(),
-            );
+        row += td!() + formatter.currency_element(self.unit_price, "USD").ok()?;
+        row += td!() + formatter.currency_element(self.unit_discount, "USD").ok()?;
+        row += td!() + formatter.number_element(self.quantity as f64, 0).ok()?;
+        row += td!() + formatter.currency_element(price, "USD").ok()?;
+        row += td!() + formatter.currency_element(discount, "USD").ok()?;
+        row += td!() + formatter.currency_element(price - discount, "USD").ok()?;
         Some(row)
     }
 }
@@ -118,6 +120,7 @@ fn main() {
         HtmlElementConfig::new_empty(),
         HtmlElementConfig::new_empty(),
     )
+    .unwrap()
     .unwrap();
     let head = Head::new().with_title("Union table".to_string());
     export_to_file(
@@ -0,0 +1,916 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::{
+    document::Document,
+    element::{Element, HtmlElement, HtmlElementConfig},
+    errors::{AssetError, HtmlResult},
+    head::Head,
+    site::relative_link,
+    tags::TagType,
+};
+
+/// Copies the local files referenced by the documents (css, javascript, images, fonts...)
+/// to the export destination under content-hashed names : `css/style.css` is written to `assets/style.1a2b3c4d5e.css`.
+/// The name changes only when the content changes, which allows browsers to cache the files indefinitely.
+///
+/// The references are the `src` and `poster` attributes of all the elements, the candidates of the `srcset` attributes,
+/// the `url(...)` of the inline `style` attributes and the `href` attribute of the `<link>` elements loading a file
+/// (`stylesheet`, `icon`, `apple-touch-icon`, `preload`, `modulepreload` and `manifest`).
+/// The `url(...)` references of the css files are copied and rewritten too.
+/// External references (`https://`, `//`, `data:`...) and fragments (`#id`) are left unchanged.
+///
+/// - `source` : The folder containing the local files, the references are relative to this folder.
+/// - `folder` : The destination sub-folder of the copied files.
+/// - `assets` : The registered files, by source path.
+#[derive(Clone, Debug)]
+pub struct AssetRegistry {
+    pub source: String,
+    pub folder: String,
+    pub assets: HashMap<String, Asset>,
+    loading: HashSet<String>,
+}
+
+/// Represents a registered file.
+///
+/// - `source` : The file's path, relative to the registry's source folder.
+/// - `filename` : The content-hashed file name.
+/// - `content` : The file's content. The css files references are already rewritten.
+#[derive(Clone, Debug)]
+pub struct Asset {
+    pub source: String,
+    pub filename: String,
+    pub content: Vec<u8>,
+}
+
+impl AssetRegistry {
+    /// Creates an empty registry. The files are copied to the `assets` sub-folder.
+    ///
+    /// - `source` : The folder containing the local files.
+    pub fn new(source: String) -> Self {
+        Self {
+            source,
+            folder: "assets".to_string(),
+            assets: HashMap::new(),
+            loading: HashSet::new(),
+        }
+    }
+
+    /// Sets the destination sub-folder of the copied files.
+    ///
+    /// - `folder` : The sub-folder's path, relative to the export destination.
+    pub fn with_folder(mut self, folder: String) -> Self {
+        self.folder = folder.trim_matches('/').to_string();
+        self
+    }
+
+    /// Registers a local file and returns its path relative to the export destination (`assets/style.1a2b3c4d5e.css`).
+    /// A file is read only once, even if it is referenced several times.
+    ///
+    /// If the file can not be read, if the path leaves the source folder or if css files reference each other,
+    /// the Err variant is returned.
+    ///
+    /// - `reference` : The file's path, relative to the source folder.
+    pub fn register(&mut self, reference: &str) -> Result<String, AssetError> {
+        let path = normalize_path("", reference)
+            .ok_or_else(|| AssetError::InvalidPath(reference.to_string()))?;
+        let filename = self.load(&path, None)?;
+        Ok(format!("{}/{}", self.folder, filename))
+    }
+
+    /// Registers the files referenced by a document and rewrites the references to the copied files.
+    /// If a referenced file can not be registered, the Err variant is returned.
+    ///
+    /// - `document` : The document to update.
+    /// - `path` : The document's path, relative to the export destination (`reports/march.html`).
+    pub fn rewrite_document(
+        &mut self,
+        document: &mut Document,
+        path: &str,
+    ) -> Result<(), AssetError> {
+        for element in document
+            .head
+            .content
+            .iter_mut()
+            .chain(document.body.iter_mut())
+        {
+            self.rewrite_element(element, path)?;
+        }
+        Ok(())
+    }
+
+    /// Registers the files referenced by an element and its sub-elements and rewrites the references.
+    /// If a referenced file can not be registered, the Err variant is returned.
+    ///
+    /// - `element` : The element to update.
+    /// - `path` : The path of the element's document, relative to the export destination.
+    pub fn rewrite_element(&mut self, element: &mut Element, path: &str) -> Result<(), AssetError> {
+        if let Element::Element(html_element) = element {
+            let is_file_link = is_file_link(html_element);
+            for (name, value) in html_element.config.attributes.iter_mut() {
+                let Some(value) = value else {
+                    continue;
+                };
+                match name.as_str() {
+                    "srcset" => {
+                        *value = replace_srcset_urls(value, |reference| {
+                            self.rewrite_reference(reference, path)
+                        })?;
+                    }
+                    "style" => {
+                        *value = replace_css_urls(value, |reference| {
+                            self.rewrite_reference(reference, path)
+                        })?;
+                    }
+                    "src" | "poster" if is_local(value) => {
+                        *value = self.rewrite_reference(value, path)?;
+                    }
+                    "href" if is_file_link && is_local(value) => {
+                        *value = self.rewrite_reference(value, path)?;
+                    }
+                    _ => {}
+                }
+            }
+            for child in html_element.children.iter_mut() {
+                self.rewrite_element(child, path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the registered files to the registry's sub-folder of the destination.
+    /// If a file can not be written, the Err variant is returned.
+    ///
+    /// - `destination` : The export destination folder's path.
+    pub fn export_to_directory(&self, destination: String) -> HtmlResult<()> {
+        let folder = format!("{}/{}", destination, self.folder);
+        fs::create_dir_all(&folder)?;
+        for asset in self.assets.values() {
+            fs::write(format!("{}/{}", folder, asset.filename), &asset.content)?;
+        }
+        Ok(())
+    }
+
+    /// Internal function. Registers a local reference of a document and returns the relative link to the copied file.
+    ///
+    /// - `reference` : The reference, with its optional query or fragment.
+    /// - `path` : The document's path, relative to the export destination.
+    fn rewrite_reference(&mut self, reference: &str, path: &str) -> Result<String, AssetError> {
+        let (reference, suffix) = split_suffix(reference);
+        let output = self.register(reference).map_err(|error| match error {
+            AssetError::MissingFile(file, None) => {
+                AssetError::MissingFile(file, Some(path.to_string()))
+            }
+            error => error,
+        })?;
+        Ok(format!("{}{}", relative_link(path, &output), suffix))
+    }
+
+    /// Internal function. Loads a file, rewrites its references if it is a css file and returns its hashed name.
+    ///
+    /// - `path` : The normalized file's path, relative to the source folder.
+    /// - `referrer` : The css file referencing this file, used for errors.
+    fn load(&mut self, path: &str, referrer: Option<&str>) -> Result<String, AssetError> {
+        if let Some(asset) = self.assets.get(path) {
+            return Ok(asset.filename.clone());
+        }
+        if !self.loading.insert(path.to_string()) {
+            return Err(AssetError::Cycle(path.to_string()));
+        }
+        let content = match fs::read(format!("{}/{}", self.source, path)) {
+            Ok(content) if path.ends_with(".css") => {
+                self.rewrite_css(&String::from_utf8_lossy(&content), path)
+            }
+            Ok(content) => Ok(content),
+            Err(_) => Err(AssetError::MissingFile(
+                format!("{}/{}", self.source, path),
+                referrer.map(|referrer| format!("{}/{}", self.source, referrer)),
+            )),
+        };
+        self.loading.remove(path);
+        let content = content?;
+
+        let hash = Sha256::digest(&content)
+            .iter()
+            .take(5)
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        let name = path.rsplit('/').next().unwrap_or(path);
+        let filename = match name.rsplit_once('.') {
+            Some((stem, extension)) if !stem.is_empty() => {
+                format!("{}.{}.{}", stem, hash, extension)
+            }
+            _ => format!("{}.{}", name, hash),
+        };
+        self.assets.insert(
+            path.to_string(),
+            Asset {
+                source: path.to_string(),
+                filename: filename.clone(),
+                content,
+            },
+        );
+        Ok(filename)
+    }
+
+    /// Internal function. Registers the `url(...)` references of a css file and returns the rewritten css.
+    /// The copied files are in the same folder, the references are replaced by their hashed names.
+    ///
+    /// - `css` : The css code.
+    /// - `path` : The normalized css file's path, relative to the source folder.
+    fn rewrite_css(&mut self, css: &str, path: &str) -> Result<Vec<u8>, AssetError> {
+        let folder = path
+            .rsplit_once('/')
+            .map(|(folder, _)| folder)
+            .unwrap_or("");
+        let css = replace_css_urls(css, |value| {
+            let (reference, suffix) = split_suffix(value);
+            let source = normalize_path(folder, reference)
+                .ok_or_else(|| AssetError::InvalidPath(reference.to_string()))?;
+            Ok(format!("{}{}", self.load(&source, Some(path))?, suffix))
+        })?;
+        Ok(css.into_bytes())
+    }
+}
+
+/// Returns a copy of the document in which the local files are embedded, the document can be shared as a single file.
+/// - The `<link rel="stylesheet">` elements are replaced by `<style>` elements, keeping their `media`, `nonce`, `title` and id.
+/// - The `<script src="...">` elements are replaced by `<script>` elements containing the code.
+///   The `defer`, `async` and module scripts are moved to the end of the `<body>`, as they can not be delayed once embedded.
+/// - The other references (`src`, `poster` and `srcset` attributes, `href` of the `<link>` elements loading a file, css `url(...)`
+///   of the stylesheets and of the `style` attributes) are replaced by base64 `data:` urls,
+///   their mime type is detected from the file's content and extension.
+///
+/// External references (`https://`, `//`, `data:`...) are left unchanged.
+/// If a referenced file can not be read or if a reference leaves the source folder, the Err variant is returned.
+///
+/// - `document` : The document to embed the files in.
+/// - `source` : The folder containing the local files, the references are relative to this folder.
+pub fn inline_document(document: &Document, source: &str) -> Result<Document, AssetError> {
+    let mut deferred = vec![];
+    let mut head = Head::new();
+    for element in document.head.content.iter() {
+        head.content
+            .extend(inline_element(element, source, &mut deferred)?);
+    }
+    let mut body = vec![];
+    for element in document.body.iter() {
+        body.extend(inline_element(element, source, &mut deferred)?);
+    }
+    body.extend(deferred);
+    Ok(Document {
+        head,
+        body,
+        locale: document.locale.clone(),
+        content_security_policy: document.content_security_policy.clone(),
+    })
+}
+
+/// Internal function. Returns a copy of the element in which the local files are embedded.
+/// The function returns None if the element is moved to the deferred scripts.
+///
+/// - `element` : The element to copy.
+/// - `source` : The folder containing the local files.
+/// - `deferred` : The `defer`, `async` and module scripts, moved to the end of the `<body>`.
+fn inline_element(
+    element: &Element,
+    source: &str,
+    deferred: &mut Vec<Element>,
+) -> Result<Option<Element>, AssetError> {
+    let Element::Element(html_element) = element else {
+        return Ok(Some(element.clone()));
+    };
+    let mut config = html_element.config.clone();
+    let local = |name: &str| match config.attributes.get(name) {
+        Some(Some(value)) if is_local(value) => Some(split_suffix(value).0.to_string()),
+        _ => None,
+    };
+
+    let is_stylesheet = matches!(
+        config.attributes.get("rel"),
+        Some(Some(rel)) if rel.split_whitespace().any(|rel| rel == "stylesheet")
+    );
+    match (&html_element.tag, local("href"), local("src")) {
+        (TagType::Link, Some(reference), _) if is_stylesheet => {
+            let path = normalize_path("", &reference).ok_or(AssetError::InvalidPath(reference))?;
+            let folder = path
+                .rsplit_once('/')
+                .map(|(folder, _)| folder)
+                .unwrap_or("");
+            let css = String::from_utf8_lossy(&read_file(source, &path, None)?).to_string();
+            let css = replace_css_urls(&css, |value| {
+                let (reference, _) = split_suffix(value);
+                let file = normalize_path(folder, reference)
+                    .ok_or_else(|| AssetError::InvalidPath(reference.to_string()))?;
+                Ok(data_url(&file, &read_file(source, &file, Some(&path))?))
+            })?;
+            let mut style_config = HtmlElementConfig::new_empty();
+            style_config.id = config.id.clone();
+            for name in ["media", "nonce", "title"] {
+                if let Some(value) = config.attributes.get(name) {
+                    style_config = style_config.set_attribute(name.to_string(), value.clone());
+                }
+            }
+            let mut style = HtmlElement::new(TagType::Style, style_config);
+            style
+                .children
+                .push(Box::new(Element::Text(css.replace("</style", "<\\/style"))));
+            return Ok(Some(Element::Element(style)));
+        }
+        (TagType::Script, _, Some(reference)) => {
+            let path = normalize_path("", &reference).ok_or(AssetError::InvalidPath(reference))?;
+            let code = String::from_utf8_lossy(&read_file(source, &path, None)?).to_string();
+            config = config.remove_attribute("src".to_string());
+            let is_deferred = config.has_attribute("defer".to_string())
+                || config.has_attribute("async".to_string())
+                || matches!(config.attributes.get("type"), Some(Some(kind)) if kind == "module");
+            config = config
+                .remove_attribute("defer".to_string())
+                .remove_attribute("async".to_string());
+            let mut script = HtmlElement::new(TagType::Script, config);
+            script.children.push(Box::new(Element::Text(
+                code.replace("</script", "<\\/script"),
+            )));
+            if is_deferred {
+                deferred.push(Element::Element(script));
+                return Ok(None);
+            }
+            return Ok(Some(Element::Element(script)));
+        }
+        _ => {}
+    }
+
+    let is_file_link = is_file_link(html_element);
+    let mut link_type = None;
+    for (name, value) in config.attributes.iter_mut() {
+        let Some(value) = value else {
+            continue;
+        };
+        match name.as_str() {
+            "srcset" => {
+                *value =
+                    replace_srcset_urls(value, |reference| inline_reference(source, reference))?;
+            }
+            "style" => {
+                *value = replace_css_urls(value, |reference| inline_reference(source, reference))?;
+            }
+            "src" | "poster" if is_local(value) => {
+                *value = inline_reference(source, value)?;
+            }
+            "href" if is_file_link && is_local(value) => {
+                let (reference, _) = split_suffix(value);
+                let path = normalize_path("", reference)
+                    .ok_or_else(|| AssetError::InvalidPath(reference.to_string()))?;
+                let content = read_file(source, &path, None)?;
+                link_type = Some(mime_type(&path, &content));
+                *value = data_url(&path, &content);
+            }
+            _ => {}
+        }
+    }
+    if let Some(link_type) = link_type.filter(|_| config.has_attribute("type".to_string())) {
+        config = config.set_attribute("type".to_string(), Some(link_type.to_string()));
+    }
+    let mut inlined = HtmlElement::new(html_element.tag.clone(), config);
+    for child in html_element.children.iter() {
+        if let Some(child) = inline_element(child, source, deferred)? {
+            inlined.children.push(Box::new(child));
+        }
+    }
+    Ok(Some(Element::Element(inlined)))
+}
+
+/// Internal function. Returns the base64 `data:` url of a local reference of a document.
+/// If the file can not be read or if the reference leaves the source folder, the Err variant is returned.
+///
+/// - `source` : The folder containing the local files.
+/// - `reference` : The reference, its query or fragment is dropped.
+fn inline_reference(source: &str, reference: &str) -> Result<String, AssetError> {
+    let (reference, _) = split_suffix(reference);
+    let path = normalize_path("", reference)
+        .ok_or_else(|| AssetError::InvalidPath(reference.to_string()))?;
+    Ok(data_url(&path, &read_file(source, &path, None)?))
+}
+
+/// Internal function. Reads a local file.
+/// If the file can not be read, the Err variant is returned.
+///
+/// - `source` : The folder containing the local files.
+/// - `path` : The normalized file's path, relative to the source folder.
+/// - `referrer` : The css file referencing this file, used for errors.
+fn read_file(source: &str, path: &str, referrer: Option<&str>) -> Result<Vec<u8>, AssetError> {
+    fs::read(format!("{}/{}", source, path)).map_err(|_| {
+        AssetError::MissingFile(
+            format!("{}/{}", source, path),
+            referrer.map(|referrer| format!("{}/{}", source, referrer)),
+        )
+    })
+}
+
+/// Internal function. Replaces the local `url(...)` references of a css code.
+/// The quotes around the references are kept, including the escaped quotes of the `style` attributes (`&quot;`).
+///
+/// - `css` : The css code.
+/// - `replace` : Returns the replacement of a reference.
+fn replace_css_urls<F>(css: &str, mut replace: F) -> Result<String, AssetError>
+where
+    F: FnMut(&str) -> Result<String, AssetError>,
+{
+    let mut result = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("url(") {
+        let (before, after) = rest.split_at(start + 4);
+        result.push_str(before);
+        let Some(end) = after.find(')') else {
+            rest = after;
+            break;
+        };
+        let raw = after[..end].trim();
+        let quote = ["\"", "'", "&quot;", "&#39;"]
+            .into_iter()
+            .find(|quote| {
+                raw.len() >= 2 * quote.len() && raw.starts_with(quote) && raw.ends_with(quote)
+            })
+            .unwrap_or("");
+        let value = &raw[quote.len()..raw.len() - quote.len()];
+        if is_local(value) {
+            result.push_str(&format!("{}{}{}", quote, replace(value)?, quote));
+        } else {
+            result.push_str(&after[..end]);
+        }
+        rest = &after[end..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// Internal function. Replaces the local urls of a `srcset` attribute.
+/// The candidates are written back separated by `, `, with their width or density descriptors.
+///
+/// - `srcset` : The attribute's value (`photo.jpg 1x, photo@2x.jpg 2x`).
+/// - `replace` : Returns the replacement of a reference.
+fn replace_srcset_urls<F>(srcset: &str, mut replace: F) -> Result<String, AssetError>
+where
+    F: FnMut(&str) -> Result<String, AssetError>,
+{
+    let mut candidates = vec![];
+    let mut rest = srcset;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        if rest.is_empty() {
+            break;
+        }
+        // The url ends at the first whitespace, a url ending with commas has no descriptor.
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (url, after) = rest.split_at(end);
+        let (url, descriptor, after) = if url.ends_with(',') {
+            (url.trim_end_matches(','), "", after)
+        } else {
+            let end = after.find(',').unwrap_or(after.len());
+            (url, after[..end].trim(), &after[end..])
+        };
+        rest = after;
+        let url = if is_local(url) {
+            replace(url)?
+        } else {
+            url.to_string()
+        };
+        candidates.push(if descriptor.is_empty() {
+            url
+        } else {
+            format!("{} {}", url, descriptor)
+        });
+    }
+    Ok(candidates.join(", "))
+}
+
+/// Returns the base64 `data:` url of a file's content.
+///
+/// - `path` : The file's path, its extension is used when the content's type is not detected.
+/// - `content` : The file's content.
+pub fn data_url(path: &str, content: &[u8]) -> String {
+    format!(
+        "data:{};base64,{}",
+        mime_type(path, content),
+        base64_encode(content)
+    )
+}
+
+/// Returns the mime type of a file, detected from its first bytes, then from its extension.
+/// If the type is unknown, `application/octet-stream` is returned.
+///
+/// - `path` : The file's path.
+/// - `content` : The file's content.
+pub fn mime_type(path: &str, content: &[u8]) -> &'static str {
+    const SIGNATURES: [(&[u8], &str); 9] = [
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"\x00\x00\x01\x00", "image/x-icon"),
+        (b"%PDF-", "application/pdf"),
+        (b"wOFF", "font/woff"),
+        (b"wOF2", "font/woff2"),
+        (b"OggS", "audio/ogg"),
+    ];
+    if let Some((_, mime)) = SIGNATURES
+        .iter()
+        .find(|(signature, _)| content.starts_with(signature))
+    {
+        return mime;
+    }
+    if content.len() >= 12 && &content[..4] == b"RIFF" && &content[8..12] == b"WEBP" {
+        return "image/webp";
+    }
+    let extension = path
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "ico" => "image/x-icon",
+        "css" => "text/css",
+        "js" | "mjs" => "text/javascript",
+        "json" => "application/json",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "mp3" => "audio/mpeg",
+        "pdf" => "application/pdf",
+        "txt" => "text/plain",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Internal function. Encodes the bytes in base64, with padding.
+///
+/// - `bytes` : The bytes to encode.
+pub(crate) fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (index, byte)| {
+            n | (*byte as u32) << (16 - 8 * index)
+        });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * index) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Internal function. Returns true if the element is a `<link>` loading the file of its `href` attribute :
+/// its `rel` attribute contains `stylesheet`, `icon`, `apple-touch-icon`, `preload`, `modulepreload` or `manifest`.
+/// The other links (`canonical`, `alternate`, `next`...) reference pages and are left unchanged.
+///
+/// - `element` : The element to check.
+fn is_file_link(element: &HtmlElement) -> bool {
+    const FILE_RELS: [&str; 6] = [
+        "stylesheet",
+        "icon",
+        "apple-touch-icon",
+        "preload",
+        "modulepreload",
+        "manifest",
+    ];
+    matches!(element.tag, TagType::Link)
+        && matches!(
+            element.config.attributes.get("rel"),
+            Some(Some(rel)) if rel
+                .split_whitespace()
+                .any(|rel| FILE_RELS.iter().any(|file_rel| rel.eq_ignore_ascii_case(file_rel)))
+        )
+}
+
+/// Internal function. Returns true if the reference is a local file.
+///
+/// - `reference` : The reference to check.
+pub(crate) fn is_local(reference: &str) -> bool {
+    !(reference.is_empty()
+        || reference.starts_with('#')
+        || reference.starts_with("//")
+        || reference.contains(':'))
+}
+
+/// Internal function. Splits the reference into the file's path and the query or fragment (`?v=2#icon`).
+///
+/// - `reference` : The reference to split.
+pub(crate) fn split_suffix(reference: &str) -> (&str, &str) {
+    match reference.find(['?', '#']) {
+        Some(index) => reference.split_at(index),
+        None => (reference, ""),
+    }
+}
+
+/// Internal function. Returns the normalized path of a reference, relative to the source folder.
+/// If the path leaves the source folder, the function returns None.
+///
+/// - `folder` : The folder of the file containing the reference.
+/// - `reference` : The reference.
+pub(crate) fn normalize_path(folder: &str, reference: &str) -> Option<String> {
+    let mut segments = vec![];
+    let base = if reference.starts_with('/') {
+        ""
+    } else {
+        folder
+    };
+    for segment in base.split('/').chain(reference.split('/')) {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+    if segments.is_empty() {
+        None
+    } else {
+        Some(segments.join("/"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{head::FetchOptions, miscellaneous::ScriptLoadMode};
+
+    fn mark(reference: &str) -> Result<String, AssetError> {
+        Ok(format!("[{}]", reference))
+    }
+
+    #[test]
+    fn srcset_candidates_are_replaced() {
+        assert_eq!(
+            replace_srcset_urls("photo.jpg 1x,photo@2x.jpg   2x", mark).unwrap(),
+            "[photo.jpg] 1x, [photo@2x.jpg] 2x"
+        );
+        assert_eq!(
+            replace_srcset_urls(
+                "small.jpg 480w, https://cdn.example.com/large.jpg 1080w",
+                mark
+            )
+            .unwrap(),
+            "[small.jpg] 480w, https://cdn.example.com/large.jpg 1080w"
+        );
+        assert_eq!(
+            replace_srcset_urls("a.png, b.png 2x", mark).unwrap(),
+            "[a.png], [b.png] 2x"
+        );
+        assert_eq!(
+            replace_srcset_urls("data:image/png;base64,AAAA 1x", mark).unwrap(),
+            "data:image/png;base64,AAAA 1x"
+        );
+    }
+
+    #[test]
+    fn css_urls_keep_their_quotes() {
+        assert_eq!(
+            replace_css_urls("background: url('bg.png') no-repeat", mark).unwrap(),
+            "background: url('[bg.png]') no-repeat"
+        );
+        assert_eq!(
+            replace_css_urls("background-image: url(&quot;img/bg.png&quot;)", mark).unwrap(),
+            "background-image: url(&quot;[img/bg.png]&quot;)"
+        );
+        assert_eq!(
+            replace_css_urls("background: url(#gradient), url(//cdn/a.png)", mark).unwrap(),
+            "background: url(#gradient), url(//cdn/a.png)"
+        );
+    }
+
+    #[test]
+    fn style_and_srcset_attributes_are_registered() {
+        let source =
+            std::env::temp_dir().join(format!("html_export_assets_{}", std::process::id()));
+        fs::create_dir_all(&source).unwrap();
+        fs::write(source.join("bg.png"), b"background").unwrap();
+        fs::write(source.join("photo.jpg"), b"photo").unwrap();
+        let mut registry = AssetRegistry::new(source.to_string_lossy().to_string());
+        let mut element = Element::Element(HtmlElement::new(
+            TagType::Img,
+            HtmlElementConfig::new_empty()
+                .set_attribute("srcset".to_string(), Some("photo.jpg 2x".to_string()))
+                .set_attribute(
+                    "style".to_string(),
+                    Some("background: url(&#39;bg.png?v=2&#39;)".to_string()),
+                ),
+        ));
+        registry
+            .rewrite_element(&mut element, "reports/march.html")
+            .unwrap();
+        fs::remove_dir_all(&source).unwrap();
+
+        let Element::Element(element) = element else {
+            panic!("the element is not an html element");
+        };
+        let srcset = element
+            .config
+            .get_attribute("srcset".to_string())
+            .flatten()
+            .unwrap();
+        let style = element
+            .config
+            .get_attribute("style".to_string())
+            .flatten()
+            .unwrap();
+        assert!(srcset.starts_with("../assets/photo.") && srcset.ends_with(".jpg 2x"));
+        assert!(style.starts_with("background: url(&#39;../assets/bg."));
+        assert!(style.ends_with(".png?v=2&#39;)"));
+        assert_eq!(registry.assets.len(), 2);
+    }
+
+    #[test]
+    fn only_file_links_are_registered() {
+        let source = std::env::temp_dir().join(format!("html_export_links_{}", std::process::id()));
+        fs::create_dir_all(&source).unwrap();
+        fs::write(source.join("style.css"), b"body { color: red; }").unwrap();
+        let mut document = Document::new(
+            Head::new()
+                .with_canonical_url("style.css".to_string())
+                .with_css_file("style.css".to_string()),
+            vec![],
+        );
+        let mut registry = AssetRegistry::new(source.to_string_lossy().to_string());
+        registry
+            .rewrite_document(&mut document, "index.html")
+            .unwrap();
+        fs::remove_dir_all(&source).unwrap();
+
+        let hrefs = document
+            .head
+            .content
+            .iter()
+            .map(|element| match element {
+                Element::Element(element) => element
+                    .config
+                    .get_attribute("href".to_string())
+                    .flatten()
+                    .unwrap(),
+                _ => panic!("the head entry is not an html element"),
+            })
+            .collect::<Vec<String>>();
+        assert_eq!(hrefs[0], "style.css");
+        assert!(hrefs[1].starts_with("assets/style.") && hrefs[1].ends_with(".css"));
+        assert_eq!(registry.assets.len(), 1);
+    }
+
+    fn inlined(head: Head, body: Vec<Element>, files: &[(&str, &[u8])], name: &str) -> Document {
+        let source = std::env::temp_dir().join(format!(
+            "html_export_inline_{}_{}",
+            name,
+            std::process::id()
+        ));
+        fs::create_dir_all(&source).unwrap();
+        for (file, content) in files {
+            fs::write(source.join(file), content).unwrap();
+        }
+        let document = inline_document(&Document::new(head, body), &source.to_string_lossy());
+        fs::remove_dir_all(&source).unwrap();
+        document.unwrap()
+    }
+
+    fn html(element: &Element) -> &HtmlElement {
+        match element {
+            Element::Element(element) => element,
+            _ => panic!("the element is not an html element"),
+        }
+    }
+
+    fn text(element: &HtmlElement) -> &str {
+        match element.children[0].as_ref() {
+            Element::Text(text) => text,
+            _ => panic!("the element does not contain raw text"),
+        }
+    }
+
+    #[test]
+    fn inlined_stylesheets_keep_their_media() {
+        let link = HtmlElement::new(
+            TagType::Link,
+            HtmlElementConfig::new_empty()
+                .with_id("print-style".to_string())
+                .set_attribute("rel".to_string(), Some("stylesheet".to_string()))
+                .set_attribute("href".to_string(), Some("print.css".to_string()))
+                .set_attribute("media".to_string(), Some("print".to_string()))
+                .set_attribute("title".to_string(), Some("Print".to_string()))
+                .set_attribute("nonce".to_string(), Some("r4nd0m".to_string())),
+        );
+        let mut head = Head::new();
+        head.content.push(Element::Element(link));
+        let document = inlined(
+            head,
+            vec![],
+            &[("print.css", b"body { color: black; }")],
+            "media",
+        );
+
+        let style = html(&document.head.content[0]);
+        assert_eq!(style.tag, TagType::Style);
+        assert_eq!(style.config.id.as_deref(), Some("print-style"));
+        for (name, value) in [("media", "print"), ("title", "Print"), ("nonce", "r4nd0m")] {
+            assert_eq!(
+                style.config.get_attribute(name.to_string()),
+                Some(Some(value.to_string()))
+            );
+        }
+        assert!(!style.config.has_attribute("href".to_string()));
+        assert_eq!(text(style), "body { color: black; }");
+    }
+
+    #[test]
+    fn delayed_and_module_scripts_are_moved_to_the_end_of_the_body() {
+        let head = Head::new()
+            .with_javascript_file("first.js".to_string(), Some(ScriptLoadMode::Defer))
+            .with_module_file("module.js".to_string(), &FetchOptions::new())
+            .with_javascript_file("blocking.js".to_string(), None)
+            .with_javascript_file("last.js".to_string(), Some(ScriptLoadMode::Async));
+        let paragraph =
+            Element::Element(HtmlElement::new(TagType::P, HtmlElementConfig::new_empty()));
+        let document = inlined(
+            head,
+            vec![paragraph.clone()],
+            &[
+                ("first.js", b"first();"),
+                ("module.js", b"module();"),
+                ("blocking.js", b"blocking();"),
+                ("last.js", b"last();"),
+            ],
+            "scripts",
+        );
+
+        let head = document
+            .head
+            .content
+            .iter()
+            .map(html)
+            .map(text)
+            .collect::<Vec<&str>>();
+        assert_eq!(head, ["blocking();"]);
+        assert_eq!(document.body[0], paragraph);
+        let scripts = document.body[1..]
+            .iter()
+            .map(html)
+            .collect::<Vec<&HtmlElement>>();
+        assert_eq!(
+            scripts
+                .iter()
+                .map(|script| text(script))
+                .collect::<Vec<&str>>(),
+            ["first();", "module();", "last();"]
+        );
+        assert!(scripts
+            .iter()
+            .all(|script| !script.config.has_attribute("src".to_string())
+                && !script.config.has_attribute("defer".to_string())
+                && !script.config.has_attribute("async".to_string())));
+        assert_eq!(
+            scripts[1].config.get_attribute("type".to_string()),
+            Some(Some("module".to_string()))
+        );
+    }
+
+    #[test]
+    fn images_are_replaced_by_data_urls() {
+        let png: &[u8] = b"\x89PNG\r\n\x1a\nimage";
+        let image = Element::Element(HtmlElement::new(
+            TagType::Img,
+            HtmlElementConfig::new_empty()
+                .set_attribute("src".to_string(), Some("logo.png?v=2".to_string()))
+                .set_attribute(
+                    "srcset".to_string(),
+                    Some("https://cdn.example.com/logo.png 2x".to_string()),
+                ),
+        ));
+        let document = inlined(Head::new(), vec![image], &[("logo.png", png)], "images");
+
+        let image = html(&document.body[0]);
+        let src = image
+            .config
+            .get_attribute("src".to_string())
+            .flatten()
+            .unwrap();
+        assert!(src.starts_with("data:image/png;base64,"));
+        assert_eq!(src, data_url("logo.png", png));
+        assert_eq!(
+            image.config.get_attribute("srcset".to_string()),
+            Some(Some("https://cdn.example.com/logo.png 2x".to_string()))
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use std::collections::HashMap;
+
+use crate::{
+    element::Element,
+    errors::{HtmlResult, I18nError},
+    formatting::Formatter,
+    i18n::{Localizer, TextDirection},
+};
+
+/// Defines a reusable piece of Html configured by its parent.
+/// The rendered element can be inserted anywhere in an Html tree, including in other components.
+pub trait Component {
+    /// The component's configuration, given by its parent.
+    type Props;
+
+    /// Converts the component to an element.
+    /// If the html structure can not be assembled, the Err variant is returned.
+    ///
+    /// - `props` : The component's configuration.
+    /// - `children` : The elements placed inside the component by its parent.
+    /// - `context` : The context shared by all the components of the document.
+    fn render(
+        &self,
+        props: &Self::Props,
+        children: Vec<Element>,
+        context: &mut RenderContext,
+    ) -> HtmlResult<Element>;
+}
+
+/// Represents the context shared by all the components of a document.
+///
+/// - `theme` : The components theme.
+/// - `locale` : The document's locale (`en`, `fr-FR`...).
+/// - `ids` : The generator of the document's unique ids.
+/// - `localizer` : The message catalogs, translated in the context's locale.
+#[derive(Clone, Debug)]
+pub struct RenderContext {
+    pub theme: Theme,
+    pub locale: String,
+    pub ids: IdGenerator,
+    pub localizer: Option<Localizer>,
+}
+
+/// Defines the css classes used by the components.
+///
+/// - `name` : The theme's name.
+/// - `classes` : The css classes of each component part, by key (`button.primary`, `card.title`...).
+#[derive(Clone, Debug)]
+pub struct Theme {
+    pub name: String,
+    pub classes: HashMap<String, Vec<String>>,
+}
+
+/// Generates unique and predictable ids : `{prefix}{name}-{count}`.
+///
+/// - `prefix` : The prefix of all the generated ids.
+/// - `counters` : The amount of ids generated for each name.
+#[derive(Clone, Debug)]
+pub struct IdGenerator {
+    pub prefix: String,
+    pub counters: HashMap<String, usize>,
+}
+
+impl RenderContext {
+    /// Creates a default context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the components theme.
+    ///
+    /// - `theme` : The new theme.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets the document's locale.
+    ///
+    /// - `locale` : The new locale.
+    pub fn with_locale(mut self, locale: String) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the message catalogs used by `translate`.
+    ///
+    /// - `localizer` : The message catalogs.
+    pub fn with_localizer(mut self, localizer: Localizer) -> Self {
+        self.localizer = Some(localizer);
+        self
+    }
+
+    /// Returns a message in the context's locale, shortcut for `Translator::translate`.
+    /// If the context has no localizer, if the message is not defined or if an argument is missing, the Err variant is returned.
+    ///
+    /// - `key` : The message's key.
+    /// - `arguments` : The arguments, by name.
+    pub fn translate(&self, key: &str, arguments: &[(&str, String)]) -> Result<String, I18nError> {
+        match &self.localizer {
+            Some(localizer) => localizer.for_locale(&self.locale).translate(key, arguments),
+            None => Err(I18nError::MissingMessage(
+                self.locale.clone(),
+                key.to_string(),
+            )),
+        }
+    }
+
+    /// Returns the plural form of a message in the context's locale, shortcut for `Translator::translate_plural`.
+    /// If the context has no localizer, if the message is not defined or if an argument is missing, the Err variant is returned.
+    ///
+    /// - `key` : The message's key.
+    /// - `count` : The counted amount.
+    /// - `arguments` : The other arguments, by name.
+    pub fn translate_plural(
+        &self,
+        key: &str,
+        count: u64,
+        arguments: &[(&str, String)],
+    ) -> Result<String, I18nError> {
+        match &self.localizer {
+            Some(localizer) => localizer
+                .for_locale(&self.locale)
+                .translate_plural(key, count, arguments),
+            None => Err(I18nError::MissingMessage(
+                self.locale.clone(),
+                key.to_string(),
+            )),
+        }
+    }
+
+    /// Returns the writing direction of the context's locale.
+    pub fn direction(&self) -> TextDirection {
+        TextDirection::for_locale(&self.locale)
+    }
+
+    /// Returns a formatter for the context's locale.
+    pub fn formatter(&self) -> Formatter {
+        Formatter::new(&self.locale)
+    }
+
+    /// Sets the prefix of the generated ids.
+    ///
+    /// - `prefix` : The new prefix.
+    pub fn with_id_prefix(mut self, prefix: String) -> Self {
+        self.ids.prefix = prefix;
+        self
+    }
+
+    /// Returns a new unique id, shortcut for `IdGenerator::next_id`.
+    ///
+    /// - `name` : The id's base name.
+    pub fn next_id(&mut self, name: &str) -> String {
+        self.ids.next_id(name)
+    }
+
+    /// Renders a component with this context.
+    ///
+    /// - `component` : The component to render.
+    /// - `props` : The component's configuration.
+    /// - `children` : The elements placed inside the component.
+    pub fn render<T>(
+        &mut self,
+        component: &T,
+        props: &T::Props,
+        children: Vec<Element>,
+    ) -> HtmlResult<Element>
+    where
+        T: Component,
+    {
+        component.render(props, children, self)
+    }
+}
+
+impl Theme {
+    /// Creates a theme without css classes.
+    ///
+    /// - `name` : The theme's name.
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            classes: HashMap::new(),
+        }
+    }
+
+    /// Sets the css classes of a component part.
+    ///
+    /// - `key` : The component part's key.
+    /// - `classes` : The part's css classes.
+    pub fn with_classes<T>(mut self, key: String, classes: T) -> Self
+    where
+        T: IntoIterator<Item = String>,
+    {
+        self.classes.insert(key, classes.into_iter().collect());
+        self
+    }
+
+    /// Returns the css classes of a component part. If the theme does not define the part, no class is returned.
+    ///
+    /// - `key` : The component part's key.
+    pub fn get_classes(&self, key: &str) -> Vec<String> {
+        self.classes.get(key).cloned().unwrap_or_default()
+    }
+}
+
+impl IdGenerator {
+    /// Creates a generator.
+    ///
+    /// - `prefix` : The prefix of all the generated ids.
+    pub fn new(prefix: String) -> Self {
+        Self {
+            prefix,
+            counters: HashMap::new(),
+        }
+    }
+
+    /// Returns a new unique id : the first id generated for `field` is `{prefix}field-1`, then `{prefix}field-2`...
+    ///
+    /// - `name` : The id's base name.
+    pub fn next_id(&mut self, name: &str) -> String {
+        let counter = self.counters.entry(name.to_string()).or_insert(0);
+        *counter += 1;
+        format!("{}{}-{}", self.prefix, name, counter)
+    }
+}
+
+impl Default for RenderContext {
+    fn default() -> Self {
+        Self {
+            theme: Theme::default(),
+            locale: "en".to_string(),
+            ids: IdGenerator::default(),
+            localizer: None,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new("default".to_string())
+    }
+}
+
+impl Default for IdGenerator {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        element::{HtmlElement, HtmlElementConfig},
+        i18n::Catalog,
+        tags::TagType,
+    };
+
+    /// A card wrapping its children, with a themed and identified `<section>`.
+    struct Card;
+
+    /// A translated label with its own id.
+    struct Caption;
+
+    impl Component for Card {
+        type Props = String;
+
+        fn render(
+            &self,
+            title: &String,
+            children: Vec<Element>,
+            context: &mut RenderContext,
+        ) -> HtmlResult<Element> {
+            let mut card = Element::Element(HtmlElement::new(
+                TagType::Section,
+                HtmlElementConfig::new_empty()
+                    .with_id(context.next_id("card"))
+                    .with_classes(context.theme.get_classes("card").into_iter()),
+            ));
+            card.try_push(context.render(&Caption, title, vec![])?)?;
+            card.try_extend(children)?;
+            Ok(card)
+        }
+    }
+
+    impl Component for Caption {
+        type Props = String;
+
+        fn render(
+            &self,
+            key: &String,
+            _children: Vec<Element>,
+            context: &mut RenderContext,
+        ) -> HtmlResult<Element> {
+            let mut config = HtmlElementConfig::new_empty()
+                .with_id(context.next_id("caption"))
+                .set_attribute(
+                    "dir".to_string(),
+                    Some(context.direction().get_name().to_string()),
+                );
+            config = config.with_classes(context.theme.get_classes("card.title").into_iter());
+            Element::Element(HtmlElement::new(TagType::H2, config))
+                .checked_add(Element::Text(context.translate(key, &[])?))
+                .map_err(Into::into)
+        }
+    }
+
+    fn context(locale: &str) -> RenderContext {
+        let localizer = Localizer::new("en".to_string())
+            .with_catalog(
+                Catalog::new("en".to_string())
+                    .with_message("sales".to_string(), "Sales".to_string()),
+            )
+            .with_catalog(
+                Catalog::new("ar".to_string())
+                    .with_message("sales".to_string(), "المبيعات".to_string()),
+            );
+        RenderContext::new()
+            .with_locale(locale.to_string())
+            .with_localizer(localizer)
+            .with_theme(
+                Theme::new("dark".to_string())
+                    .with_classes("card".to_string(), ["card".to_string(), "dark".to_string()])
+                    .with_classes("card.title".to_string(), ["title".to_string()]),
+            )
+    }
+
+    fn html(element: &Element) -> &HtmlElement {
+        match element {
+            Element::Element(element) => element,
+            _ => panic!("the element is not an html element"),
+        }
+    }
+
+    #[test]
+    fn nested_components_share_the_context() {
+        let mut context = context("ar");
+        let card = context.render(&Card, &"sales".to_string(), vec![]).unwrap();
+        let card = html(&card);
+        assert_eq!(card.config.id.as_deref(), Some("card-1"));
+        assert!(card.config.classes.contains("dark"));
+        let caption = html(&card.children[0]);
+        assert!(caption.config.classes.contains("title"));
+        assert_eq!(
+            caption.config.get_attribute("dir".to_string()),
+            Some(Some("rtl".to_string()))
+        );
+        assert!(matches!(caption.children[0].as_ref(), Element::Text(text) if text == "المبيعات"));
+    }
+
+    #[test]
+    fn children_are_placed_inside_the_component() {
+        let mut context = context("en");
+        let children = vec![
+            Element::Text("First".to_string()),
+            Element::Element(HtmlElement::new(TagType::P, HtmlElementConfig::new_empty())),
+        ];
+        let card = context
+            .render(&Card, &"sales".to_string(), children)
+            .unwrap();
+        let card = html(&card);
+        assert_eq!(card.children.len(), 3);
+        assert!(matches!(card.children[1].as_ref(), Element::Text(text) if text == "First"));
+        assert_eq!(html(&card.children[2]).tag, TagType::P);
+    }
+
+    #[test]
+    fn ids_are_unique_across_nested_renders() {
+        let mut context = context("en").with_id_prefix("report-".to_string());
+        let inner = context.render(&Card, &"sales".to_string(), vec![]).unwrap();
+        let outer = context
+            .render(&Card, &"sales".to_string(), vec![inner])
+            .unwrap();
+        let outer = html(&outer);
+        let inner = html(&outer.children[1]);
+        let ids = [
+            inner.config.id.clone(),
+            html(&inner.children[0]).config.id.clone(),
+            outer.config.id.clone(),
+            html(&outer.children[0]).config.id.clone(),
+        ];
+        assert_eq!(
+            ids.map(Option::unwrap),
+            [
+                "report-card-1",
+                "report-caption-1",
+                "report-card-2",
+                "report-caption-2"
+            ]
+        );
+    }
+
+    #[test]
+    fn render_errors_are_propagated() {
+        let mut context = RenderContext::new();
+        let error = context
+            .render(&Card, &"sales".to_string(), vec![])
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            crate::errors::Error::I18n(I18nError::MissingMessage(_, key)) if key == "sales"
+        ));
+    }
+}
@@ -0,0 +1,1190 @@
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use crate::{
+    component::{Component, RenderContext},
+    element::{Element, HtmlElement, HtmlElementConfig},
+    errors::{ElementResult, HtmlResult},
+    miscellaneous::escape_html,
+    tags::TagType,
+};
+
+/// The languages supported by the highlighter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Language {
+    Rust,
+    Sql,
+    Json,
+    Shell,
+    JavaScript,
+    Python,
+    Toml,
+    PlainText,
+}
+
+/// The kinds of tokens, each one rendered with its own css class.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Keyword,
+    Type,
+    Literal,
+    String,
+    Number,
+    Comment,
+    Function,
+    Variable,
+    Property,
+    Attribute,
+    Operator,
+    Punctuation,
+    Plain,
+}
+
+/// The code block component : a `<pre><code>` element with highlighted tokens.
+/// Each line is a `<span class="line">`, the highlighted lines also have the `highlighted` class.
+/// The `<pre>` element has the `code-block` class, followed by the theme's `code_block` classes.
+pub struct CodeBlock;
+
+/// The code block's configuration.
+///
+/// - `code` : The source code.
+/// - `language` : The source code's language.
+/// - `line_numbers` : If true, each line starts with its number. The numbers are hidden from the text renderers.
+/// - `anchor_prefix` : If set, each line gets an id made of this prefix and its number (`L12`), and the line numbers link to it.
+/// - `highlighted_lines` : The ranges of highlighted line numbers.
+/// - `first_line` : The number of the first line.
+#[derive(Clone, Debug)]
+pub struct CodeBlockProps {
+    pub code: String,
+    pub language: Language,
+    pub line_numbers: bool,
+    pub anchor_prefix: Option<String>,
+    pub highlighted_lines: Vec<RangeInclusive<usize>>,
+    pub first_line: usize,
+}
+
+/// The colors of a code block stylesheet.
+///
+/// - `name` : The theme's name.
+/// - `background` : The block's background color.
+/// - `foreground` : The plain text's color.
+/// - `line_number` : The line numbers color.
+/// - `highlighted_line` : The background color of the highlighted and targeted lines.
+/// - `colors` : The color of each token kind. The kinds without color use the foreground color.
+#[derive(Clone, Debug)]
+pub struct CodeTheme {
+    pub name: String,
+    pub background: String,
+    pub foreground: String,
+    pub line_number: String,
+    pub highlighted_line: String,
+    pub colors: HashMap<TokenKind, String>,
+}
+
+impl Language {
+    /// Returns the language matching a name or a file extension (`rust`, `rs`, `sh`...).
+    /// If the language is not supported, None is returned.
+    ///
+    /// - `name` : The language's name, case insensitive.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name.to_lowercase().as_str() {
+            "rust" | "rs" => Self::Rust,
+            "sql" => Self::Sql,
+            "json" => Self::Json,
+            "shell" | "sh" | "bash" | "zsh" | "console" => Self::Shell,
+            "javascript" | "js" | "typescript" | "ts" => Self::JavaScript,
+            "python" | "py" => Self::Python,
+            "toml" => Self::Toml,
+            "text" | "txt" | "plaintext" => Self::PlainText,
+            _ => return None,
+        })
+    }
+
+    /// Returns the language's name, used in the `language-*` class.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Rust => "rust",
+            Self::Sql => "sql",
+            Self::Json => "json",
+            Self::Shell => "shell",
+            Self::JavaScript => "javascript",
+            Self::Python => "python",
+            Self::Toml => "toml",
+            Self::PlainText => "text",
+        }
+    }
+
+    /// Internal function. Returns the language's lexical rules.
+    fn definition(&self) -> Definition {
+        match self {
+            Self::Rust => RUST,
+            Self::Sql => SQL,
+            Self::Json => JSON,
+            Self::Shell => SHELL,
+            Self::JavaScript => JAVASCRIPT,
+            Self::Python => PYTHON,
+            Self::Toml => TOML,
+            Self::PlainText => PLAIN_TEXT,
+        }
+    }
+}
+
+impl TokenKind {
+    /// All the token kinds, in stylesheet order.
+    pub const ALL: [TokenKind; 13] = [
+        Self::Keyword,
+        Self::Type,
+        Self::Literal,
+        Self::String,
+        Self::Number,
+        Self::Comment,
+        Self::Function,
+        Self::Variable,
+        Self::Property,
+        Self::Attribute,
+        Self::Operator,
+        Self::Punctuation,
+        Self::Plain,
+    ];
+
+    /// Returns the css class of the token kind. The plain tokens have no class.
+    pub fn get_class(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Keyword => "tok-keyword",
+            Self::Type => "tok-type",
+            Self::Literal => "tok-literal",
+            Self::String => "tok-string",
+            Self::Number => "tok-number",
+            Self::Comment => "tok-comment",
+            Self::Function => "tok-function",
+            Self::Variable => "tok-variable",
+            Self::Property => "tok-property",
+            Self::Attribute => "tok-attribute",
+            Self::Operator => "tok-operator",
+            Self::Punctuation => "tok-punctuation",
+            Self::Plain => return None,
+        })
+    }
+}
+
+impl CodeBlockProps {
+    /// Creates the configuration of a code block without line numbers, anchors nor highlighted lines.
+    ///
+    /// - `code` : The source code.
+    /// - `language` : The source code's language.
+    pub fn new(code: String, language: Language) -> Self {
+        Self {
+            code,
+            language,
+            line_numbers: false,
+            anchor_prefix: None,
+            highlighted_lines: vec![],
+            first_line: 1,
+        }
+    }
+
+    /// Displays the line numbers.
+    pub fn with_line_numbers(mut self) -> Self {
+        self.line_numbers = true;
+        self
+    }
+
+    /// Adds an anchor to each line : `{prefix}{number}`.
+    /// Use a different prefix for each code block of a document.
+    ///
+    /// - `prefix` : The anchors prefix (`L`).
+    pub fn with_anchors(mut self, prefix: String) -> Self {
+        self.anchor_prefix = Some(prefix);
+        self
+    }
+
+    /// Highlights a range of lines. The range uses the displayed line numbers.
+    ///
+    /// - `lines` : The highlighted line numbers (`3..=5`).
+    pub fn with_highlighted_lines(mut self, lines: RangeInclusive<usize>) -> Self {
+        self.highlighted_lines.push(lines);
+        self
+    }
+
+    /// Sets the number of the first line, for an extract of a larger file.
+    ///
+    /// - `first_line` : The first line's number.
+    pub fn with_first_line(mut self, first_line: usize) -> Self {
+        self.first_line = first_line;
+        self
+    }
+}
+
+impl Component for CodeBlock {
+    type Props = CodeBlockProps;
+
+    /// Renders the code block.
+    /// If an element of the block can not be built, the Err variant is returned.
+    ///
+    /// - `props` : The code block's configuration.
+    /// - `_children` : Unused.
+    /// - `context` : The render context, providing the theme's `code_block` classes.
+    fn render(
+        &self,
+        props: &CodeBlockProps,
+        _children: Vec<Element>,
+        context: &mut RenderContext,
+    ) -> HtmlResult<Element> {
+        let lines = split_lines(tokenize(
+            props.code.trim_end_matches(['\n', '\r']),
+            props.language,
+        ));
+        let last_number = props.first_line + lines.len().saturating_sub(1);
+        let mut code = HtmlElement::new(
+            TagType::Code,
+            HtmlElementConfig::new_empty()
+                .with_class(format!("language-{}", props.language.get_name())),
+        );
+        for (index, tokens) in lines.into_iter().enumerate() {
+            let number = props.first_line + index;
+            let mut line = HtmlElement::new(
+                TagType::Span,
+                HtmlElementConfig::new_empty().with_class("line".to_string()),
+            );
+            if props
+                .highlighted_lines
+                .iter()
+                .any(|range| range.contains(&number))
+            {
+                line.config = line.config.with_class("highlighted".to_string());
+            }
+            if let Some(prefix) = &props.anchor_prefix {
+                line.config = line
+                    .config
+                    .with_id(escape_html(&format!("{}{}", prefix, number)));
+            }
+            if props.line_numbers {
+                line.try_push(line_number(number, last_number, props)?)?;
+            }
+            for (kind, text) in tokens {
+                let text = Element::Text(escape_html(&text));
+                match kind.get_class() {
+                    Some(class) => line.try_push(
+                        Element::Element(HtmlElement::new(
+                            TagType::Span,
+                            HtmlElementConfig::new_empty().with_class(class.to_string()),
+                        ))
+                        .checked_add(text)?,
+                    )?,
+                    None => line.try_push(text)?,
+                }
+            }
+            if index > 0 {
+                code.try_push(Element::Text("\n".to_string()))?;
+            }
+            code.try_push(Element::Element(line))?;
+        }
+        let pre = HtmlElement::new(
+            TagType::Pre,
+            HtmlElementConfig::new_empty()
+                .with_class("code-block".to_string())
+                .with_classes(context.theme.get_classes("code_block").into_iter()),
+        );
+        Ok(Element::Element(pre).checked_add(Element::Element(code))?)
+    }
+}
+
+impl CodeTheme {
+    /// Creates a theme without token colors.
+    ///
+    /// - `name` : The theme's name.
+    /// - `background` : The block's background color.
+    /// - `foreground` : The plain text's color.
+    pub fn new(name: String, background: String, foreground: String) -> Self {
+        Self {
+            name,
+            background,
+            line_number: foreground.clone(),
+            highlighted_line: "rgba(255, 200, 0, 0.2)".to_string(),
+            foreground,
+            colors: HashMap::new(),
+        }
+    }
+
+    /// The default light theme.
+    pub fn light() -> Self {
+        Self::new(
+            "light".to_string(),
+            "#fafafa".to_string(),
+            "#383a42".to_string(),
+        )
+        .with_line_colors("#9d9d9f".to_string(), "#fff3c4".to_string())
+        .with_color(TokenKind::Keyword, "#a626a4".to_string())
+        .with_color(TokenKind::Type, "#c18401".to_string())
+        .with_color(TokenKind::Literal, "#986801".to_string())
+        .with_color(TokenKind::String, "#50a14f".to_string())
+        .with_color(TokenKind::Number, "#986801".to_string())
+        .with_color(TokenKind::Comment, "#a0a1a7".to_string())
+        .with_color(TokenKind::Function, "#4078f2".to_string())
+        .with_color(TokenKind::Variable, "#e45649".to_string())
+        .with_color(TokenKind::Property, "#e45649".to_string())
+        .with_color(TokenKind::Attribute, "#0184bc".to_string())
+        .with_color(TokenKind::Operator, "#0184bc".to_string())
+    }
+
+    /// The default dark theme.
+    pub fn dark() -> Self {
+        Self::new(
+            "dark".to_string(),
+            "#282c34".to_string(),
+            "#abb2bf".to_string(),
+        )
+        .with_line_colors("#636d83".to_string(), "#3e4451".to_string())
+        .with_color(TokenKind::Keyword, "#c678dd".to_string())
+        .with_color(TokenKind::Type, "#e5c07b".to_string())
+        .with_color(TokenKind::Literal, "#d19a66".to_string())
+        .with_color(TokenKind::String, "#98c379".to_string())
+        .with_color(TokenKind::Number, "#d19a66".to_string())
+        .with_color(TokenKind::Comment, "#7f848e".to_string())
+        .with_color(TokenKind::Function, "#61afef".to_string())
+        .with_color(TokenKind::Variable, "#e06c75".to_string())
+        .with_color(TokenKind::Property, "#e06c75".to_string())
+        .with_color(TokenKind::Attribute, "#56b6c2".to_string())
+        .with_color(TokenKind::Operator, "#56b6c2".to_string())
+    }
+
+    /// Sets the color of a token kind.
+    ///
+    /// - `kind` : The token kind.
+    /// - `color` : The css color.
+    pub fn with_color(mut self, kind: TokenKind, color: String) -> Self {
+        self.colors.insert(kind, color);
+        self
+    }
+
+    /// Sets the line numbers color and the highlighted lines background color.
+    ///
+    /// - `line_number` : The line numbers color.
+    /// - `highlighted_line` : The highlighted lines background color.
+    pub fn with_line_colors(mut self, line_number: String, highlighted_line: String) -> Self {
+        self.line_number = line_number;
+        self.highlighted_line = highlighted_line;
+        self
+    }
+
+    /// Returns the stylesheet of the code blocks, to add to the document's `<head>` with `Head::with_style`.
+    pub fn to_css(&self) -> String {
+        let mut css = format!(
+            ".code-block {{ background: {}; color: {}; padding: 0.75em 0; overflow-x: auto; }}\n\
+             .code-block .line {{ display: inline-block; box-sizing: border-box; min-width: 100%; padding: 0 1em; }}\n\
+             .code-block .line.highlighted, .code-block .line:target {{ background: {}; }}\n\
+             .code-block .line-number {{ display: inline-block; margin-right: 1em; text-align: right; color: {}; text-decoration: none; user-select: none; }}\n",
+            self.background, self.foreground, self.highlighted_line, self.line_number
+        );
+        for kind in TokenKind::ALL {
+            if let (Some(class), Some(color)) = (kind.get_class(), self.colors.get(&kind)) {
+                css.push_str(&format!(".code-block .{} {{ color: {}; }}\n", class, color));
+            }
+        }
+        css
+    }
+}
+
+impl Default for CodeTheme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+/// Internal function. Returns the line number element, padded to the width of the last number.
+/// It links to the line when the anchors are enabled.
+/// If the element can not be built, the Err variant is returned.
+///
+/// - `number` : The line's number.
+/// - `last_number` : The number of the block's last line.
+/// - `props` : The code block's configuration.
+fn line_number(number: usize, last_number: usize, props: &CodeBlockProps) -> ElementResult {
+    let text = format!("{:>width$}", number, width = last_number.to_string().len());
+    let mut config = HtmlElementConfig::new_empty()
+        .with_class("line-number".to_string())
+        .set_attribute("aria-hidden".to_string(), Some("true".to_string()));
+    let tag = match &props.anchor_prefix {
+        Some(prefix) => {
+            config = config.set_attribute(
+                "href".to_string(),
+                Some(escape_html(&format!("#{}{}", prefix, number))),
+            );
+            TagType::A
+        }
+        None => TagType::Span,
+    };
+    Element::Element(HtmlElement::new(tag, config)).checked_add(Element::Text(text))
+}
+
+/// Internal struct. The lexical rules of a language.
+///
+/// - `keywords` : The reserved words.
+/// - `types` : The builtin types, or the builtin commands for the shell.
+/// - `literals` : The literal words (`true`, `null`...).
+/// - `case_insensitive` : If true, the words are matched regardless of their case.
+/// - `capitalized_types` : If true, the capitalized words are types.
+/// - `line_comments` : The line comment markers.
+/// - `block_comment` : The block comment start and end markers.
+/// - `quotes` : The string delimiters.
+/// - `property_separator` : The character following a property name (`:` in json).
+struct Definition {
+    keywords: &'static [&'static str],
+    types: &'static [&'static str],
+    literals: &'static [&'static str],
+    case_insensitive: bool,
+    capitalized_types: bool,
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    property_separator: Option<char>,
+}
+
+const RUST: Definition = Definition {
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+        "pub", "ref", "return", "self", "static", "struct", "super", "trait", "type", "unsafe",
+        "use", "where", "while",
+    ],
+    types: &[
+        "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32",
+        "i64", "i128", "isize", "f32", "f64",
+    ],
+    literals: &["true", "false"],
+    case_insensitive: false,
+    capitalized_types: true,
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"'],
+    property_separator: None,
+};
+
+const SQL: Definition = Definition {
+    keywords: &[
+        "add",
+        "all",
+        "alter",
+        "and",
+        "as",
+        "asc",
+        "between",
+        "by",
+        "case",
+        "check",
+        "column",
+        "constraint",
+        "create",
+        "cross",
+        "default",
+        "delete",
+        "desc",
+        "distinct",
+        "drop",
+        "else",
+        "end",
+        "exists",
+        "foreign",
+        "from",
+        "full",
+        "group",
+        "having",
+        "if",
+        "in",
+        "index",
+        "inner",
+        "insert",
+        "into",
+        "is",
+        "join",
+        "key",
+        "left",
+        "like",
+        "limit",
+        "not",
+        "offset",
+        "on",
+        "or",
+        "order",
+        "outer",
+        "primary",
+        "references",
+        "returning",
+        "right",
+        "select",
+        "set",
+        "table",
+        "then",
+        "union",
+        "unique",
+        "update",
+        "values",
+        "view",
+        "when",
+        "where",
+        "with",
+    ],
+    types: &[
+        "bigint",
+        "blob",
+        "boolean",
+        "char",
+        "date",
+        "decimal",
+        "double",
+        "float",
+        "int",
+        "integer",
+        "numeric",
+        "real",
+        "serial",
+        "smallint",
+        "text",
+        "time",
+        "timestamp",
+        "varchar",
+    ],
+    literals: &["null", "true", "false"],
+    case_insensitive: true,
+    capitalized_types: false,
+    line_comments: &["--"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['\'', '"'],
+    property_separator: None,
+};
+
+const JSON: Definition = Definition {
+    keywords: &[],
+    types: &[],
+    literals: &["true", "false", "null"],
+    case_insensitive: false,
+    capitalized_types: false,
+    line_comments: &[],
+    block_comment: None,
+    quotes: &['"'],
+    property_separator: Some(':'),
+};
+
+const SHELL: Definition = Definition {
+    keywords: &[
+        "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if",
+        "in", "local", "return", "then", "until", "while",
+    ],
+    types: &[
+        "alias", "cd", "echo", "eval", "exec", "exit", "printf", "read", "set", "shift", "source",
+        "test", "unset",
+    ],
+    literals: &[],
+    case_insensitive: false,
+    capitalized_types: false,
+    line_comments: &["#"],
+    block_comment: None,
+    quotes: &['"', '\''],
+    property_separator: None,
+};
+
+const JAVASCRIPT: Definition = Definition {
+    keywords: &[
+        "async",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "export",
+        "extends",
+        "finally",
+        "for",
+        "from",
+        "function",
+        "if",
+        "import",
+        "in",
+        "instanceof",
+        "let",
+        "new",
+        "of",
+        "return",
+        "static",
+        "switch",
+        "this",
+        "throw",
+        "try",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "yield",
+    ],
+    types: &[
+        "Array", "Boolean", "Date", "Error", "Map", "Number", "Object", "Promise", "Set", "String",
+    ],
+    literals: &["true", "false", "null", "undefined", "NaN"],
+    case_insensitive: false,
+    capitalized_types: false,
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\'', '`'],
+    property_separator: None,
+};
+
+const PYTHON: Definition = Definition {
+    keywords: &[
+        "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del",
+        "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in", "is",
+        "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while", "with",
+        "yield",
+    ],
+    types: &[
+        "bool", "bytes", "dict", "float", "int", "list", "object", "set", "str", "tuple",
+    ],
+    literals: &["True", "False", "None"],
+    case_insensitive: false,
+    capitalized_types: false,
+    line_comments: &["#"],
+    block_comment: None,
+    quotes: &['"', '\''],
+    property_separator: None,
+};
+
+const TOML: Definition = Definition {
+    keywords: &[],
+    types: &[],
+    literals: &["true", "false"],
+    case_insensitive: false,
+    capitalized_types: false,
+    line_comments: &["#"],
+    block_comment: None,
+    quotes: &['"', '\''],
+    property_separator: Some('='),
+};
+
+const PLAIN_TEXT: Definition = Definition {
+    keywords: &[],
+    types: &[],
+    literals: &[],
+    case_insensitive: false,
+    capitalized_types: false,
+    line_comments: &[],
+    block_comment: None,
+    quotes: &[],
+    property_separator: None,
+};
+
+/// Splits the source code into tokens. Concatenating the tokens text gives back the source code.
+///
+/// - `code` : The source code.
+/// - `language` : The source code's language.
+pub fn tokenize(code: &str, language: Language) -> Vec<(TokenKind, String)> {
+    let definition = language.definition();
+    let chars = code.chars().collect::<Vec<char>>();
+    let mut tokens: Vec<(TokenKind, String)> = vec![];
+    let mut position = 0;
+    let mut command_position = true;
+    while position < chars.len() {
+        let start = position;
+        let c = chars[position];
+        let kind = if language == Language::PlainText {
+            position = chars.len();
+            TokenKind::Plain
+        } else if c.is_whitespace() {
+            while position < chars.len() && chars[position].is_whitespace() {
+                if chars[position] == '\n' {
+                    command_position = true;
+                }
+                position += 1;
+            }
+            TokenKind::Plain
+        } else if let Some(marker) = definition
+            .line_comments
+            .iter()
+            .find(|marker| starts_with(&chars, position, marker))
+        {
+            position += marker.chars().count();
+            while position < chars.len() && chars[position] != '\n' {
+                position += 1;
+            }
+            TokenKind::Comment
+        } else if let Some((opening, closing)) = definition
+            .block_comment
+            .filter(|(opening, _)| starts_with(&chars, position, opening))
+        {
+            position += opening.chars().count();
+            while position < chars.len() && !starts_with(&chars, position, closing) {
+                position += 1;
+            }
+            position = (position + closing.chars().count()).min(chars.len());
+            TokenKind::Comment
+        } else if language == Language::Rust && c == '\'' {
+            position = rust_quote_end(&chars, position);
+            if chars[position - 1] == '\'' && position - start > 2 {
+                TokenKind::String
+            } else {
+                TokenKind::Type
+            }
+        } else if definition.quotes.contains(&c) {
+            position = string_end(&chars, position, language);
+            if is_property(&chars, position, &definition) {
+                TokenKind::Property
+            } else {
+                TokenKind::String
+            }
+        } else if (language == Language::Rust
+            && c == '#'
+            && (starts_with(&chars, position + 1, "[") || starts_with(&chars, position + 1, "![")))
+            || (language == Language::Toml && c == '[' && at_line_start(&chars, position))
+        {
+            position = bracket_end(&chars, position);
+            TokenKind::Attribute
+        } else if language == Language::Python && c == '@' && at_line_start(&chars, position) {
+            position += 1;
+            while position < chars.len()
+                && (is_word_char(chars[position]) || chars[position] == '.')
+            {
+                position += 1;
+            }
+            TokenKind::Attribute
+        } else if language == Language::Shell && c == '$' {
+            position = variable_end(&chars, position);
+            TokenKind::Variable
+        } else if language == Language::Shell && is_shell_word_char(c) {
+            while position < chars.len() && is_shell_word_char(chars[position]) {
+                position += 1;
+            }
+            let word = chars[start..position].iter().collect::<String>();
+            let kind = if definition.keywords.contains(&word.as_str()) {
+                TokenKind::Keyword
+            } else if command_position && definition.types.contains(&word.as_str()) {
+                TokenKind::Type
+            } else if command_position {
+                TokenKind::Function
+            } else if word.chars().all(|c| c.is_ascii_digit()) {
+                TokenKind::Number
+            } else {
+                TokenKind::Plain
+            };
+            command_position = matches!(
+                word.as_str(),
+                "if" | "then" | "else" | "elif" | "do" | "while" | "until"
+            );
+            kind
+        } else if c.is_ascii_digit() {
+            while position < chars.len()
+                && (is_word_char(chars[position])
+                    || (chars[position] == '.'
+                        && chars.get(position + 1).is_some_and(char::is_ascii_digit)))
+            {
+                position += 1;
+            }
+            TokenKind::Number
+        } else if is_word_char(c) || (language == Language::JavaScript && c == '$') {
+            while position < chars.len()
+                && (is_word_char(chars[position])
+                    || (language == Language::JavaScript && chars[position] == '$')
+                    || (language == Language::Toml && chars[position] == '-'))
+            {
+                position += 1;
+            }
+            let word = chars[start..position].iter().collect::<String>();
+            classify_word(&word, &chars, &mut position, &definition)
+        } else {
+            position += 1;
+            if language == Language::Shell
+                && (matches!(c, '|' | ';' | '(')
+                    || (c == '&' && start > 0 && chars[start - 1] == '&'))
+            {
+                command_position = true;
+            }
+            if "+-*/%=<>!&|^~?:".contains(c) {
+                TokenKind::Operator
+            } else {
+                TokenKind::Punctuation
+            }
+        };
+        let text = chars[start..position].iter().collect::<String>();
+        match tokens.last_mut() {
+            Some((last_kind, last_text)) if *last_kind == kind => last_text.push_str(&text),
+            _ => tokens.push((kind, text)),
+        }
+    }
+    tokens
+}
+
+/// Internal function. Returns the kind of a word, and includes the `!` of the Rust macros in the word.
+///
+/// - `word` : The word.
+/// - `chars` : The source code.
+/// - `position` : The position following the word.
+/// - `definition` : The language's lexical rules.
+fn classify_word(
+    word: &str,
+    chars: &[char],
+    position: &mut usize,
+    definition: &Definition,
+) -> TokenKind {
+    let normalized = if definition.case_insensitive {
+        word.to_lowercase()
+    } else {
+        word.to_string()
+    };
+    if definition.keywords.contains(&normalized.as_str()) {
+        TokenKind::Keyword
+    } else if definition.literals.contains(&normalized.as_str()) {
+        TokenKind::Literal
+    } else if is_property(chars, *position, definition) {
+        TokenKind::Property
+    } else if definition.types.contains(&normalized.as_str())
+        || (definition.capitalized_types && word.starts_with(|c: char| c.is_uppercase()))
+    {
+        TokenKind::Type
+    } else if definition.capitalized_types
+        && chars.get(*position) == Some(&'!')
+        && chars.get(*position + 1) != Some(&'=')
+    {
+        *position += 1;
+        TokenKind::Function
+    } else if next_non_blank(chars, *position) == Some('(') {
+        TokenKind::Function
+    } else {
+        TokenKind::Plain
+    }
+}
+
+/// Internal function. Indicates if the text at the given position starts with the pattern.
+///
+/// - `chars` : The source code.
+/// - `position` : The position to check.
+/// - `pattern` : The searched pattern.
+fn starts_with(chars: &[char], position: usize, pattern: &str) -> bool {
+    pattern
+        .chars()
+        .enumerate()
+        .all(|(index, c)| chars.get(position + index) == Some(&c))
+}
+
+/// Internal function. Returns the next character which is not a space nor a tab, on the same line.
+///
+/// - `chars` : The source code.
+/// - `position` : The position to start from.
+fn next_non_blank(chars: &[char], position: usize) -> Option<char> {
+    chars[position..]
+        .iter()
+        .find(|c| **c != ' ' && **c != '\t')
+        .copied()
+}
+
+/// Internal function. Indicates if only spaces and tabs precede the position on its line.
+///
+/// - `chars` : The source code.
+/// - `position` : The position to check.
+fn at_line_start(chars: &[char], position: usize) -> bool {
+    chars[..position]
+        .iter()
+        .rev()
+        .take_while(|c| **c != '\n')
+        .all(|c| *c == ' ' || *c == '\t')
+}
+
+/// Internal function. Indicates if the token ending at the position is a property name.
+///
+/// - `chars` : The source code.
+/// - `position` : The position following the token.
+/// - `definition` : The language's lexical rules.
+fn is_property(chars: &[char], position: usize, definition: &Definition) -> bool {
+    definition.property_separator.is_some()
+        && next_non_blank(chars, position) == definition.property_separator
+}
+
+/// Internal function. Indicates if the character can be part of a word.
+///
+/// - `c` : The character.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Internal function. Indicates if the character can be part of a shell word (`--release`, `./build.sh`).
+///
+/// - `c` : The character.
+fn is_shell_word_char(c: char) -> bool {
+    !c.is_whitespace() && !"|&;<>()$\"'`#=".contains(c)
+}
+
+/// Internal function. Returns the position following a string. Triple-quoted python strings are supported.
+/// An unterminated string ends with the source code.
+///
+/// - `chars` : The source code.
+/// - `position` : The position of the opening quote.
+/// - `language` : The source code's language.
+fn string_end(chars: &[char], position: usize, language: Language) -> usize {
+    let quote = chars[position];
+    let triple = quote.to_string().repeat(3);
+    if language == Language::Python && starts_with(chars, position, &triple) {
+        let mut end = position + 3;
+        while end < chars.len() && !starts_with(chars, end, &triple) {
+            end += if chars[end] == '\\' { 2 } else { 1 };
+        }
+        return (end + 3).min(chars.len());
+    }
+    let escapes = !(language == Language::Shell && quote == '\'');
+    let mut end = position + 1;
+    while end < chars.len() && chars[end] != quote {
+        end += if escapes && chars[end] == '\\' { 2 } else { 1 };
+    }
+    (end + 1).min(chars.len())
+}
+
+/// Internal function. Returns the position following a Rust char literal (`'a'`, `'\n'`) or lifetime (`'a`).
+///
+/// - `chars` : The source code.
+/// - `position` : The position of the quote.
+fn rust_quote_end(chars: &[char], position: usize) -> usize {
+    if chars.get(position + 1) == Some(&'\\') {
+        let mut end = position + 2;
+        while end < chars.len() && chars[end] != '\'' && chars[end] != '\n' {
+            end += 1;
+        }
+        return (end + 1).min(chars.len());
+    }
+    if chars.get(position + 2) == Some(&'\'') {
+        return position + 3;
+    }
+    let mut end = position + 1;
+    while end < chars.len() && is_word_char(chars[end]) {
+        end += 1;
+    }
+    end
+}
+
+/// Internal function. Returns the position following a bracketed group on the same line (`#[derive(Debug)]`, `[package]`).
+///
+/// - `chars` : The source code.
+/// - `position` : The position of the group's first character.
+fn bracket_end(chars: &[char], position: usize) -> usize {
+    let mut depth = 0;
+    let mut end = position;
+    while end < chars.len() && chars[end] != '\n' {
+        match chars[end] {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return end + 1;
+                }
+            }
+            _ => {}
+        }
+        end += 1;
+    }
+    end
+}
+
+/// Internal function. Returns the position following a shell variable (`$HOME`, `${name}`, `$1`, `$?`).
+///
+/// - `chars` : The source code.
+/// - `position` : The position of the `$`.
+fn variable_end(chars: &[char], position: usize) -> usize {
+    match chars.get(position + 1) {
+        Some('{') => {
+            let mut end = position + 2;
+            while end < chars.len() && chars[end] != '}' && chars[end] != '\n' {
+                end += 1;
+            }
+            (end + 1).min(chars.len())
+        }
+        Some(c) if is_word_char(*c) => {
+            let mut end = position + 1;
+            while end < chars.len() && is_word_char(chars[end]) {
+                end += 1;
+            }
+            end
+        }
+        Some('?' | '#' | '@' | '*' | '!' | '$' | '-') => position + 2,
+        _ => position + 1,
+    }
+}
+
+/// Internal function. Splits the tokens into lines, the tokens spanning several lines are split too.
+///
+/// - `tokens` : The tokens.
+fn split_lines(tokens: Vec<(TokenKind, String)>) -> Vec<Vec<(TokenKind, String)>> {
+    let mut lines = vec![vec![]];
+    for (kind, text) in tokens {
+        for (index, part) in text.split('\n').enumerate() {
+            if index > 0 {
+                lines.push(vec![]);
+            }
+            let part = part.trim_end_matches('\r');
+            if !part.is_empty() {
+                lines.last_mut().unwrap().push((kind, part.to_string()));
+            }
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the texts of the tokens of the given kind.
+    fn tokens_of(code: &str, language: Language, kind: TokenKind) -> Vec<String> {
+        tokenize(code, language)
+            .into_iter()
+            .filter(|(token_kind, _)| *token_kind == kind)
+            .map(|(_, text)| text)
+            .collect()
+    }
+
+    #[test]
+    fn tokens_cover_the_whole_code() {
+        for (code, language) in [
+            (
+                "fn main() {\n    let s = \"a\\\"b\"; // done\n}\n",
+                Language::Rust,
+            ),
+            (
+                "SELECT name FROM users -- all\r\nWHERE id = 'x';",
+                Language::Sql,
+            ),
+            ("echo \"$HOME\" ${name} $1 # comment", Language::Shell),
+            ("unterminated \"string", Language::JavaScript),
+            ("", Language::PlainText),
+        ] {
+            let tokens = tokenize(code, language);
+            assert_eq!(
+                tokens
+                    .iter()
+                    .map(|(_, text)| text.as_str())
+                    .collect::<String>(),
+                code
+            );
+            assert!(tokens.iter().all(|(_, text)| !text.is_empty()));
+        }
+    }
+
+    #[test]
+    fn rust_tokens() {
+        let code = "#[derive(Debug)]\nfn main() { let s = \"a\\\"b\"; let c = 'x'; // done\n}";
+        assert_eq!(
+            tokens_of(code, Language::Rust, TokenKind::Keyword),
+            ["fn", "let", "let"]
+        );
+        assert_eq!(
+            tokens_of(code, Language::Rust, TokenKind::Function),
+            ["main"]
+        );
+        assert_eq!(
+            tokens_of(code, Language::Rust, TokenKind::String),
+            ["\"a\\\"b\"", "'x'"]
+        );
+        assert_eq!(
+            tokens_of(code, Language::Rust, TokenKind::Comment),
+            ["// done"]
+        );
+        assert_eq!(
+            tokens_of(code, Language::Rust, TokenKind::Attribute),
+            ["#[derive(Debug)]"]
+        );
+        assert_eq!(
+            tokens_of("x: Option<&'a str>", Language::Rust, TokenKind::Type),
+            ["Option", "'a", "str"]
+        );
+    }
+
+    #[test]
+    fn other_languages_tokens() {
+        let sql = "SELECT name, 42 FROM users -- all\nWHERE id = 'x';";
+        assert_eq!(
+            tokens_of(sql, Language::Sql, TokenKind::Keyword),
+            ["SELECT", "FROM", "WHERE"]
+        );
+        assert_eq!(tokens_of(sql, Language::Sql, TokenKind::Number), ["42"]);
+        assert_eq!(
+            tokens_of(sql, Language::Sql, TokenKind::Comment),
+            ["-- all"]
+        );
+
+        let json = "{\"key\": [1.5e3, true, null]}";
+        assert_eq!(
+            tokens_of(json, Language::Json, TokenKind::Property),
+            ["\"key\""]
+        );
+        assert_eq!(
+            tokens_of(json, Language::Json, TokenKind::Number),
+            ["1.5e3"]
+        );
+        assert_eq!(
+            tokens_of(json, Language::Json, TokenKind::Literal),
+            ["true", "null"]
+        );
+
+        let shell = "echo \"$HOME\" ${name} $1 # comment";
+        assert_eq!(
+            tokens_of(shell, Language::Shell, TokenKind::Variable),
+            ["${name}", "$1"]
+        );
+        assert_eq!(
+            tokens_of(shell, Language::Shell, TokenKind::Comment),
+            ["# comment"]
+        );
+
+        let toml = "[package]\nname = \"x\" # c";
+        assert_eq!(
+            tokens_of(toml, Language::Toml, TokenKind::Attribute),
+            ["[package]"]
+        );
+        assert_eq!(
+            tokens_of(toml, Language::Toml, TokenKind::Property),
+            ["name"]
+        );
+
+        let python = "def f(x):\n    \"\"\"doc\nstring\"\"\"\n    return None";
+        assert_eq!(
+            tokens_of(python, Language::Python, TokenKind::String),
+            ["\"\"\"doc\nstring\"\"\""]
+        );
+        assert_eq!(
+            tokens_of(python, Language::Python, TokenKind::Literal),
+            ["None"]
+        );
+
+        assert_eq!(
+            tokenize("let x = 1;", Language::PlainText),
+            [(TokenKind::Plain, "let x = 1;".to_string())]
+        );
+    }
+
+    #[test]
+    fn multiline_tokens_are_split_into_lines() {
+        let lines = split_lines(tokenize("a /* b\r\nc */ d\n\ne", Language::JavaScript));
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0],
+            [
+                (TokenKind::Plain, "a ".to_string()),
+                (TokenKind::Comment, "/* b".to_string())
+            ]
+        );
+        assert_eq!(
+            lines[1],
+            [
+                (TokenKind::Comment, "c */".to_string()),
+                (TokenKind::Plain, " d".to_string())
+            ]
+        );
+        assert!(lines[2].is_empty());
+        assert_eq!(lines[3], [(TokenKind::Plain, "e".to_string())]);
+    }
+
+    #[test]
+    fn languages_from_names() {
+        assert_eq!(Language::from_name("RS"), Some(Language::Rust));
+        assert_eq!(Language::from_name("bash"), Some(Language::Shell));
+        assert_eq!(Language::from_name("ts"), Some(Language::JavaScript));
+        assert_eq!(Language::from_name("cobol"), None);
+    }
+}
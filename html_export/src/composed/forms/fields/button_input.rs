@@ -5,6 +5,7 @@ use super::field::{create_input, AsHtmlConfig, InputFieldConfig};
 /// Represents an `<input type="button">` configs.
 ///
 /// - `value` : The input's value. If the value is None, this parameter is ignored.
+#[derive(Default)]
 pub struct ButtonInputConfig {
     pub value: Option<String>,
 }
@@ -28,9 +29,3 @@ impl AsHtmlConfig for ButtonInputConfig {
         configs
     }
 }
-
-impl Default for ButtonInputConfig {
-    fn default() -> Self {
-        Self { value: None }
-    }
-}
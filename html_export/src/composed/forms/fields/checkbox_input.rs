@@ -1,5 +1,6 @@
 use crate::{
     element::{Element, HtmlElement, HtmlElementConfig},
+    errors::ElementResult,
     tags::TagType,
 };
 
@@ -22,11 +23,12 @@ fn _create_labeled_checkbox(
     label_config: HtmlElementConfig,
     field_config: HtmlElementConfig,
     checked: bool,
-) -> Element {
+) -> ElementResult {
     let label = Element::Element(HtmlElement::new(
         TagType::Label,
         label_config.set_attribute("for".to_string(), Some(id.clone())),
-    )) + Element::Text(label);
+    ))
+    .checked_add(Element::Text(label))?;
 
     let mut cfg = field_config
         .clone()
@@ -38,14 +40,15 @@ fn _create_labeled_checkbox(
     if checked {
         cfg = cfg.set_attribute("checked".to_string(), None);
     }
-    Element::Element(HtmlElement::new(
+    Ok(Element::Element(HtmlElement::new(
         TagType::Div,
         HtmlElementConfig::new_empty(),
-    )) + label
+    ))) + label
         + Element::Element(HtmlElement::new(TagType::Input, cfg))
 }
 
 /// Creates a `<label>` + `<input type="checkbox">`
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `field_config` : The label + input html configs.
 /// - `value` : The checkbox's value.
@@ -54,7 +57,7 @@ pub fn create_labeled_checkbox(
     field_config: &InputFieldConfig,
     value: String,
     checked: bool,
-) -> Element {
+) -> ElementResult {
     _create_labeled_checkbox(
         field_config.id.clone(),
         value,
@@ -67,6 +70,7 @@ pub fn create_labeled_checkbox(
 }
 
 /// Creates `<input type="checkbox">`s with associated `<label>`s.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `field_config` : The label + input html configs.
 /// - `checkboxes` : A vector of tuples containing all label + checkbox details:
@@ -77,14 +81,14 @@ pub fn create_labeled_checkbox(
 pub fn create_labeled_checkboxes(
     field_config: InputFieldConfig,
     checkboxes: Vec<(String, String, String, bool)>,
-) -> Element {
+) -> ElementResult {
     let mut div = Element::Element(HtmlElement::new(
         TagType::Div,
         HtmlElementConfig::new_empty(),
     ));
 
     for (id, label, value, checked) in checkboxes.into_iter() {
-        div += _create_labeled_checkbox(
+        div.try_push(_create_labeled_checkbox(
             id,
             value,
             field_config.name.clone(),
@@ -92,8 +96,8 @@ pub fn create_labeled_checkboxes(
             field_config.label_config.clone(),
             field_config.field_config.clone(),
             checked,
-        )
+        )?)?;
     }
 
-    div
+    Ok(div)
 }
@@ -0,0 +1,78 @@
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::{ElementResult, FieldCreationError},
+};
+
+use super::{
+    field::InputFieldConfig,
+    radio_input::create_labeled_radios,
+    select_input::{create_labeled_select, Options, SelectInputConfigs, SelectOption},
+};
+
+/// Represents a type whose values can be picked from a fixed list of choices,
+/// such as a fieldless enum.
+pub trait FormChoice {
+    /// Returns the available choices as `(value, label)` pairs.
+    fn choices() -> Vec<(String, String)>;
+
+    /// Returns the value of the current choice, as given by `FormChoice::choices`.
+    fn choice_value(&self) -> String;
+}
+
+/// Creates a `<label>` + `<select>` html structure containing all the type's choices.
+/// If the select is not required, an empty choice is added first.
+/// If the html structure can not be assembled, the Err variant is returned.
+///
+/// - `html_configs` : The label and select html configs.
+/// - `select_configs` : The select's configs.
+/// - `value` : The selected choice. If the value is None, no choice is selected.
+pub fn create_labeled_choice_select<T>(
+    html_configs: InputFieldConfig,
+    select_configs: SelectInputConfigs,
+    value: Option<&T>,
+) -> ElementResult
+where
+    T: FormChoice,
+{
+    let selected = value.map(|value| value.choice_value());
+    let mut options = vec![];
+    if select_configs.required != Some(true) {
+        options.push(Options::Option(
+            SelectOption::new(String::new(), String::new()),
+            HtmlElementConfig::new_empty(),
+        ));
+    }
+    for (value, label) in T::choices() {
+        let is_selected = selected.as_ref() == Some(&value);
+        options.push(Options::Option(
+            SelectOption::new(value, label).with_selected(is_selected),
+            HtmlElementConfig::new_empty(),
+        ));
+    }
+    create_labeled_select(html_configs, select_configs, options)
+}
+
+/// Creates `<label>`s and `<input type="radio">`s for all the type's choices.
+/// Each radio's id is the field's id followed by the choice's value (`color-Red`).
+/// If the html structure can not be assembled, the Err variant is returned.
+///
+/// - `html_configs` : The labels and radios html configs.
+/// - `value` : The checked choice. If the value is None, no radio is checked.
+pub fn create_labeled_choice_radios<T>(
+    html_configs: InputFieldConfig,
+    value: Option<&T>,
+) -> Result<Element, FieldCreationError>
+where
+    T: FormChoice,
+{
+    let selected = value.map(|value| value.choice_value());
+    let choices = T::choices();
+    let checked = choices
+        .iter()
+        .position(|(value, _)| selected.as_ref() == Some(value));
+    let radios = choices
+        .into_iter()
+        .map(|(value, label)| (format!("{}-{}", html_configs.id, value), label, value))
+        .collect();
+    create_labeled_radios(html_configs, checked, radios)
+}
@@ -1,15 +1,16 @@
-use crate::element::Element;
+use crate::{element::Element, errors::ElementResult};
 
 use super::field::{create_input, create_labeled_input, InputFieldConfig, NoConfigs};
 
 /// Creates a `<label>` + `<input type="color">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The label + input html configs.
 /// - `value` : The input's optional value.
 pub fn create_labeled_color_input(
     html_configs: InputFieldConfig,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "color".to_string(), NoConfigs::new(), value)
 }
 
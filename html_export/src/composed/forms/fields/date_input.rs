@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -7,6 +10,7 @@ use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldC
 /// - `max` : The optional maximum date.
 /// - `min` : The optional minimum date.
 /// - `step` : The optional counter step.
+#[derive(Default)]
 pub struct DateInputConfig {
     pub is_local_datetime: bool,
     pub max: Option<String>,
@@ -23,6 +27,7 @@ pub enum DateInputStep {
 }
 
 /// Creates a `<label>` + `<input type="date">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The label + input html configs
 /// - `input_configs` : The date input's config.
@@ -31,7 +36,7 @@ pub fn create_date_input_with_label(
     html_configs: InputFieldConfig,
     input_configs: DateInputConfig,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(
         html_configs,
         if input_configs.is_local_datetime {
@@ -140,14 +145,3 @@ impl AsHtmlConfig for DateInputConfig {
         configs
     }
 }
-
-impl Default for DateInputConfig {
-    fn default() -> Self {
-        Self {
-            is_local_datetime: false,
-            max: None,
-            min: None,
-            step: None,
-        }
-    }
-}
@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -12,6 +15,7 @@ use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldC
 /// - `placeholder` : The input's placeholder text. If the value is None, this attribute is ignored.
 /// - `readonly` : Indicates if the input is in readonly mode. If the value is None, this attribute is ignored.
 /// - `size` : The input's content display size. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct EmailInputConfig {
     pub list: Option<String>,
     pub max_length: Option<usize>,
@@ -24,6 +28,7 @@ pub struct EmailInputConfig {
 }
 
 /// Creates a `<label>` + `<input type="email">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The label + input html configs.
 /// - `input_configs` : The input's configs.
@@ -32,7 +37,7 @@ pub fn create_labeled_email_input(
     html_configs: InputFieldConfig,
     input_configs: EmailInputConfig,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "email".to_string(), input_configs, value)
 }
 
@@ -202,18 +207,3 @@ impl AsHtmlConfig for EmailInputConfig {
         configs
     }
 }
-
-impl Default for EmailInputConfig {
-    fn default() -> Self {
-        Self {
-            list: None,
-            max_length: None,
-            min_length: None,
-            multiple: None,
-            pattern: None,
-            placeholder: None,
-            readonly: None,
-            size: None,
-        }
-    }
-}
@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElement, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElement, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 /// Defines the form input configs.
 ///
@@ -49,6 +52,12 @@ impl NoConfigs {
     }
 }
 
+impl Default for NoConfigs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AsHtmlConfig for NoConfigs {
     fn set_html_configs(&self, configs: HtmlElementConfig) -> HtmlElementConfig {
         configs
@@ -61,6 +70,7 @@ pub trait AsHtmlConfig {
 }
 
 /// Creates an `<input type="?">` and a `label` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The label and input html configs.
 /// - `input_type` : The input's type.
@@ -71,7 +81,7 @@ pub fn create_labeled_input<T>(
     input_type: String,
     input_configs: T,
     value: Option<String>,
-) -> Element
+) -> ElementResult
 where
     T: AsHtmlConfig,
 {
@@ -81,7 +91,8 @@ where
             .label_config
             .clone()
             .set_attribute("for".to_string(), Some(html_configs.id.clone())),
-    )) + Element::Text(html_configs.label.clone());
+    ))
+    .checked_add(Element::Text(html_configs.label.clone()))?;
 
     let mut cfg = input_configs.set_html_configs(
         html_configs
@@ -91,10 +102,10 @@ where
     if value.is_some() {
         cfg = cfg.set_attribute("value".to_string(), value);
     }
-    Element::Element(HtmlElement::new(
+    Ok(Element::Element(HtmlElement::new(
         crate::tags::TagType::Div,
         HtmlElementConfig::new_empty(),
-    )) + label
+    ))) + label
         + Element::Element(HtmlElement::new(crate::tags::TagType::Input, cfg))
 }
 
@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -7,6 +10,7 @@ use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldC
 /// - `accept` : Lists the authorized file extensions. If the value is None, this attribute is ignored.
 /// - `capture` : Defines the capture tool. If the value is None, this attribute is ignored.
 /// - `mutltiple` : Indicates if the input can accept multiple files. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct FileInputConfig {
     pub accept: Option<String>,
     pub capture: Option<String>,
@@ -14,13 +18,14 @@ pub struct FileInputConfig {
 }
 
 /// Creates a `<label>` and `<input type="file">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The label + input html configs.
 /// - `input_configs` : The email input's configs.
 pub fn create_labeled_file_input(
     html_configs: InputFieldConfig,
     input_configs: FileInputConfig,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "file".to_string(), input_configs, None)
 }
 
@@ -100,13 +105,3 @@ impl AsHtmlConfig for FileInputConfig {
         configs
     }
 }
-
-impl Default for FileInputConfig {
-    fn default() -> Self {
-        Self {
-            accept: None,
-            capture: None,
-            multiple: None,
-        }
-    }
-}
@@ -12,6 +12,7 @@ use super::field::{create_input, AsHtmlConfig, InputFieldConfig};
 /// - `formmethod` : The form's submit method. If the value is None this attribute is ignored.
 /// - `formnovalidate` : Indicates if the form must not be validated before submission.
 /// - `formtarget` : Indicates how to display the server's response.
+#[derive(Default)]
 pub struct ImageInputConfigs {
     pub alt: Option<String>,
     pub width: Option<usize>,
@@ -185,18 +186,3 @@ impl AsHtmlConfig for ImageInputConfigs {
         configs
     }
 }
-
-impl Default for ImageInputConfigs {
-    fn default() -> Self {
-        Self {
-            alt: None,
-            width: None,
-            height: None,
-            formaction: None,
-            formenctype: None,
-            formmethod: None,
-            formnovalidate: None,
-            formtarget: None,
-        }
-    }
-}
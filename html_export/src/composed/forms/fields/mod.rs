@@ -1,5 +1,6 @@
 pub mod button_input;
 pub mod checkbox_input;
+pub mod choice_input;
 pub mod color_input;
 pub mod date_input;
 pub mod email_field;
@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -9,6 +12,7 @@ use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldC
 /// - `step` : The input counter step value. If the value is None, this attribute is ignored.
 /// - `min` : The input minimum value. If the value is None, this attribute is ignored.
 /// - `max` : The input maximum value. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct MonthInputConfig {
     pub read_only: Option<bool>,
     pub list: Option<String>,
@@ -18,6 +22,7 @@ pub struct MonthInputConfig {
 }
 
 /// Creates a `<label>` + `<input type="month">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The input and label html configs.
 /// - `input_configs` : The input's configs.
@@ -26,7 +31,7 @@ pub fn create_labeled_mount_input(
     html_configs: InputFieldConfig,
     input_configs: MonthInputConfig,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "month".to_string(), input_configs, value)
 }
 
@@ -143,15 +148,3 @@ impl AsHtmlConfig for MonthInputConfig {
         configs
     }
 }
-
-impl Default for MonthInputConfig {
-    fn default() -> Self {
-        Self {
-            read_only: None,
-            list: None,
-            step: None,
-            min: None,
-            max: None,
-        }
-    }
-}
@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -10,6 +13,8 @@ use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldC
 /// - `place_holder` : The input's place holder text. If the value is None, this attribute is ignored.
 /// - `read_only` : Indicates if the input is in read only mode. If the value is None, this attribute is ignored.
 /// - `step` : The step value for the input's counter. If the value is None, this attribute is ignored.
+/// - `required` : Indicates if the input is required. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct NumberInputConfigs {
     pub list: Option<String>,
     pub min: Option<f64>,
@@ -17,9 +22,11 @@ pub struct NumberInputConfigs {
     pub place_holder: Option<String>,
     pub read_only: Option<bool>,
     pub step: Option<f64>,
+    pub required: Option<bool>,
 }
 
 /// Creates a `<label>` + `<input type="number">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The input and label html configs.
 /// - `input_configs` : The input's configs.
@@ -28,11 +35,8 @@ pub fn create_labeled_number_input(
     html_configs: InputFieldConfig,
     input_configs: NumberInputConfigs,
     value: Option<f64>,
-) -> Element {
-    let value = match value {
-        Some(val) => Some(val.to_string()),
-        None => None,
-    };
+) -> ElementResult {
+    let value = value.map(|val| val.to_string());
     create_labeled_input(html_configs, "number".to_string(), input_configs, value)
 }
 
@@ -46,10 +50,7 @@ pub fn create_number_input(
     input_configs: NumberInputConfigs,
     value: Option<f64>,
 ) -> Element {
-    let value = match value {
-        Some(val) => Some(val.to_string()),
-        None => None,
-    };
+    let value = value.map(|val| val.to_string());
     create_input(html_configs, "number".to_string(), input_configs, value)
 }
 
@@ -142,6 +143,20 @@ impl NumberInputConfigs {
         self.step = None;
         self
     }
+
+    /// Sets the new required parameter state.
+    ///
+    /// - `required` : The new required parameter state.
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    /// Removes the required parameter.
+    pub fn without_required(mut self) -> Self {
+        self.required = None;
+        self
+    }
 }
 
 impl AsHtmlConfig for NumberInputConfigs {
@@ -167,19 +182,11 @@ impl AsHtmlConfig for NumberInputConfigs {
                     configs.set_attribute("readonly".to_string(), Some(read_only.to_string()));
             }
         }
-        configs
-    }
-}
-
-impl Default for NumberInputConfigs {
-    fn default() -> Self {
-        Self {
-            list: None,
-            min: None,
-            max: None,
-            place_holder: None,
-            read_only: None,
-            step: None,
+        if let Some(required) = self.required {
+            if required {
+                configs = configs.set_attribute("required".to_string(), None);
+            }
         }
+        configs
     }
 }
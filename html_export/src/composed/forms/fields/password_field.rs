@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -10,6 +13,7 @@ use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldC
 /// - `place_holder` : The input's place holder text. If the value is None, this attribute is ignored.
 /// - `read_only` : Indicates if the input is in read only mode. If the value is None, this attribute is ignored.
 /// - `size` : The input's content display size. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct PasswordInputConfig {
     pub max_length: Option<usize>,
     pub min_length: Option<usize>,
@@ -20,6 +24,7 @@ pub struct PasswordInputConfig {
 }
 
 /// Creates a `<label>` + `<input type="password">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The input and label html configs.
 /// - `input_configs` : The input's configs.
@@ -28,7 +33,7 @@ pub fn create_labeled_password_input(
     html_configs: InputFieldConfig,
     input_configs: PasswordInputConfig,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "password".to_string(), input_configs, value)
 }
 
@@ -161,16 +166,3 @@ impl AsHtmlConfig for PasswordInputConfig {
         configs
     }
 }
-
-impl Default for PasswordInputConfig {
-    fn default() -> Self {
-        Self {
-            max_length: None,
-            min_length: None,
-            pattern: None,
-            place_holder: None,
-            read_only: None,
-            size: None,
-        }
-    }
-}
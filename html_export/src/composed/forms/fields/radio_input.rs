@@ -1,12 +1,13 @@
 use crate::{
     element::{Element, HtmlElement, HtmlElementConfig},
-    errors::FieldCreationError,
+    errors::{ElementResult, FieldCreationError},
     tags::TagType,
 };
 
 use super::field::InputFieldConfig;
 
 /// Creates a `<label>` and `<input type="radio">`.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `field_config` : The label and radio input html configs.
 ///     - sub filed `field_config.id` is ignored .
@@ -21,14 +22,15 @@ pub fn create_labeled_radio(
     label: String,
     value: String,
     checked: bool,
-) -> Element {
+) -> ElementResult {
     let label = Element::Element(HtmlElement::new(
         TagType::Label,
         field_config
             .label_config
             .clone()
             .set_attribute("for".to_string(), Some(id.clone())),
-    )) + Element::Text(label);
+    ))
+    .checked_add(Element::Text(label))?;
 
     let mut cfg = field_config
         .field_config
@@ -42,10 +44,10 @@ pub fn create_labeled_radio(
         cfg = cfg.set_attribute("checked".to_string(), None);
     }
 
-    Element::Element(HtmlElement::new(
+    Ok(Element::Element(HtmlElement::new(
         TagType::Div,
         HtmlElementConfig::new_empty(),
-    )) + label
+    ))) + label
         + Element::Element(HtmlElement::new(TagType::Input, cfg))
 }
 
@@ -76,9 +78,21 @@ pub fn create_labeled_radios(
     ));
     for (index, (id, label, value)) in radios.into_iter().enumerate() {
         if let Some(check) = checked {
-            div += create_labeled_radio(&field_config, id, label, value, check == index);
+            div.try_push(create_labeled_radio(
+                &field_config,
+                id,
+                label,
+                value,
+                check == index,
+            )?)?;
         } else {
-            div += create_labeled_radio(&field_config, id, label, value, false);
+            div.try_push(create_labeled_radio(
+                &field_config,
+                id,
+                label,
+                value,
+                false,
+            )?)?;
         }
     }
     Ok(div)
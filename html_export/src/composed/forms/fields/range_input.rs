@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -14,6 +17,7 @@ pub struct RangeInputConfigs {
 }
 
 /// Creates a `<label>` + `<input type="range">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The input and label html configs.
 /// - `input_configs` : The input's configs.
@@ -22,11 +26,8 @@ pub fn create_labeled_range_input(
     html_configs: InputFieldConfig,
     input_configs: RangeInputConfigs,
     value: Option<f64>,
-) -> Element {
-    let value = match value {
-        Some(val) => Some(val.to_string()),
-        None => None,
-    };
+) -> ElementResult {
+    let value = value.map(|val| val.to_string());
     create_labeled_input(html_configs, "range".to_string(), input_configs, value)
 }
 
@@ -40,10 +41,7 @@ pub fn create_range_input(
     input_configs: RangeInputConfigs,
     value: Option<f64>,
 ) -> Element {
-    let value = match value {
-        Some(val) => Some(val.to_string()),
-        None => None,
-    };
+    let value = value.map(|val| val.to_string());
     create_input(html_configs, "range".to_string(), input_configs, value)
 }
 
@@ -1,15 +1,16 @@
-use crate::element::Element;
+use crate::{element::Element, errors::ElementResult};
 
 use super::field::{create_input, create_labeled_input, InputFieldConfig, NoConfigs};
 
 /// Creates a `<label>` + `<input type="reset">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The input and label html configs.
 /// - `value` : The input's optional initial value.
 pub fn create_labeled_reset_input(
     html_configs: InputFieldConfig,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "reset".to_string(), NoConfigs::new(), value)
 }
 
@@ -1,5 +1,6 @@
 use crate::{
     element::{Element, HtmlElement, HtmlElementConfig},
+    errors::ElementResult,
     tags::TagType,
 };
 
@@ -14,6 +15,7 @@ use super::field::{AsHtmlConfig, InputFieldConfig};
 /// - `auto_focus` : Indicates if the select's auto focus is active. If the value is None, this attribute is ignored.
 /// - `disabled` : Indicates if the select is disabled. If the value is None, this attribute is ignored.
 /// - `required` : Indicates if the select is required. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct SelectInputConfigs {
     pub auto_complete: Option<String>,
     pub form: Option<String>,
@@ -35,6 +37,7 @@ pub enum Options {
 /// - `content` : The option group's options.
 /// - `label` : The option group's label. If the value is None, this attribute is ignored.
 /// - `disabled` : Indicates if the option group is disabled. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct SelectOptionGroup {
     pub content: Vec<(SelectOption, HtmlElementConfig)>,
     pub label: Option<String>,
@@ -47,6 +50,7 @@ pub struct SelectOptionGroup {
 /// - `label` : The option's displayed value.
 /// - `disabled` : Indicates if the option is disabled. If the value is None, this attribute is ignored.
 /// - `selected` : Indicates if the option is selected. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct SelectOption {
     pub value: String,
     pub label: String,
@@ -55,6 +59,7 @@ pub struct SelectOption {
 }
 
 /// Creates a `<label>` + `<select>` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The label and selecct html configs.
 /// - `select_configs` : The select's configs.
@@ -63,13 +68,14 @@ pub fn create_labeled_select(
     html_configs: InputFieldConfig,
     select_configs: SelectInputConfigs,
     values: Vec<Options>,
-) -> Element {
+) -> ElementResult {
     let label = Element::Element(HtmlElement::new(
         TagType::Label,
         html_configs
             .label_config
             .set_attribute("for".to_string(), Some(html_configs.id.clone())),
-    )) + Element::Text(html_configs.label);
+    ))
+    .checked_add(Element::Text(html_configs.label))?;
 
     let configs = select_configs.set_html_configs(
         html_configs
@@ -79,23 +85,24 @@ pub fn create_labeled_select(
     );
     let mut select = Element::Element(HtmlElement::new(TagType::Select, configs));
     for option in values {
-        select += match option {
+        select.try_push(match option {
             Options::OptionGroup(select_option_group, html_element_config) => {
-                create_option_group(select_option_group, html_element_config)
+                create_option_group(select_option_group, html_element_config)?
             }
             Options::Option(select_option, html_element_config) => {
-                create_option(select_option, html_element_config)
+                create_option(select_option, html_element_config)?
             }
-        };
+        })?;
     }
-    Element::Element(HtmlElement::new(
+    Ok(Element::Element(HtmlElement::new(
         TagType::Div,
         HtmlElementConfig::new_empty(),
-    )) + label
+    ))) + label
         + select
 }
 
 /// Creates a `<select>` html element.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The select's html configs.
 /// - `select_configs` : The select's configs.
@@ -104,7 +111,7 @@ pub fn create_select(
     html_configs: InputFieldConfig,
     select_configs: SelectInputConfigs,
     values: Vec<Options>,
-) -> Element {
+) -> ElementResult {
     let configs = select_configs.set_html_configs(
         html_configs
             .field_config
@@ -113,26 +120,31 @@ pub fn create_select(
     );
     let mut select = Element::Element(HtmlElement::new(TagType::Select, configs));
     for option in values {
-        select += match option {
+        select.try_push(match option {
             Options::OptionGroup(select_option_group, html_element_config) => {
-                create_option_group(select_option_group, html_element_config)
+                create_option_group(select_option_group, html_element_config)?
             }
             Options::Option(select_option, html_element_config) => {
-                create_option(select_option, html_element_config)
+                create_option(select_option, html_element_config)?
             }
-        };
+        })?;
     }
     Element::Element(HtmlElement::new(
         TagType::Div,
         HtmlElementConfig::new_empty(),
-    )) + select
+    ))
+    .checked_add(select)
 }
 
 /// Creates an `<optgroup>` with its `<option>` content.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `option_group` : The option group's content and configs.
 /// - `configs` : The option group's html configs.
-pub fn create_option_group(option_group: SelectOptionGroup, configs: HtmlElementConfig) -> Element {
+pub fn create_option_group(
+    option_group: SelectOptionGroup,
+    configs: HtmlElementConfig,
+) -> ElementResult {
     let mut configs = configs;
     if option_group.label.is_some() {
         configs = configs.set_attribute("label".to_string(), option_group.label);
@@ -144,16 +156,17 @@ pub fn create_option_group(option_group: SelectOptionGroup, configs: HtmlElement
     }
     let mut optgroup = Element::Element(HtmlElement::new(TagType::OptGroup, configs));
     for (option, option_configs) in option_group.content {
-        optgroup += create_option(option, option_configs);
+        optgroup.try_push(create_option(option, option_configs)?)?;
     }
-    optgroup
+    Ok(optgroup)
 }
 
 /// Creates an `<option>` html element.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `option` : The option's content and configs.
 /// - `configs` : The option's html configs.
-pub fn create_option(option: SelectOption, configs: HtmlElementConfig) -> Element {
+pub fn create_option(option: SelectOption, configs: HtmlElementConfig) -> ElementResult {
     let mut configs = configs.set_attribute("value".to_string(), Some(option.value));
     if let Some(disabled) = option.disabled {
         if disabled {
@@ -165,7 +178,8 @@ pub fn create_option(option: SelectOption, configs: HtmlElementConfig) -> Elemen
             configs = configs.set_attribute("selected".to_string(), None);
         }
     }
-    Element::Element(HtmlElement::new(TagType::Option, configs)) + Element::Text(option.label)
+    Element::Element(HtmlElement::new(TagType::Option, configs))
+        .checked_add(Element::Text(option.label))
 }
 
 impl SelectInputConfigs {
@@ -419,38 +433,3 @@ impl AsHtmlConfig for SelectInputConfigs {
         configs
     }
 }
-
-impl Default for SelectInputConfigs {
-    fn default() -> Self {
-        Self {
-            auto_complete: None,
-            form: None,
-            size: None,
-            multiple: None,
-            auto_focus: None,
-            disabled: None,
-            required: None,
-        }
-    }
-}
-
-impl Default for SelectOptionGroup {
-    fn default() -> Self {
-        Self {
-            content: Default::default(),
-            label: Default::default(),
-            disabled: Default::default(),
-        }
-    }
-}
-
-impl Default for SelectOption {
-    fn default() -> Self {
-        Self {
-            value: String::new(),
-            label: String::new(),
-            disabled: None,
-            selected: None,
-        }
-    }
-}
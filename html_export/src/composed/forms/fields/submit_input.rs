@@ -9,6 +9,7 @@ use super::field::{create_input, AsHtmlConfig, InputFieldConfig};
 /// - `formmethod` : The form's submit method. If the value is None this attribute is ignored.
 /// - `formnovalidate` : Indicates if the form must not be validated before submission.
 /// - `formtarget` : Indicates how to display the server's response.
+#[derive(Default)]
 pub struct SubmitInputConfigs {
     pub formaction: Option<String>,
     pub formenctype: Option<String>,
@@ -127,15 +128,3 @@ impl AsHtmlConfig for SubmitInputConfigs {
         configs
     }
 }
-
-impl Default for SubmitInputConfigs {
-    fn default() -> Self {
-        Self {
-            formaction: None,
-            formenctype: None,
-            formmethod: None,
-            formnovalidate: None,
-            formtarget: None,
-        }
-    }
-}
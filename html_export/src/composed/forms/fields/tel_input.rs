@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -11,6 +14,7 @@ use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldC
 /// - `max_length` : The input's content maximum length. If the value is None, this attribute is ignored.
 /// - `size` : The input's content display size. If the value is None, this attribute is ignored.
 /// - `read_only` : Indicates if the input is in read only mode. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct TelInputConfigs {
     pub list: Option<String>,
     pub pattern: Option<String>,
@@ -22,6 +26,7 @@ pub struct TelInputConfigs {
 }
 
 /// Creates a `<label>` + `<input type="tel">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The input and label html configs.
 /// - `input_configs` : The input's configs.
@@ -30,7 +35,7 @@ pub fn create_labeled_tel_input(
     html_configs: InputFieldConfig,
     input_configs: TelInputConfigs,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "tel".to_string(), input_configs, value)
 }
 
@@ -180,17 +185,3 @@ impl AsHtmlConfig for TelInputConfigs {
         configs
     }
 }
-
-impl Default for TelInputConfigs {
-    fn default() -> Self {
-        Self {
-            list: None,
-            pattern: None,
-            place_holder: None,
-            min_length: None,
-            max_length: None,
-            size: None,
-            read_only: None,
-        }
-    }
-}
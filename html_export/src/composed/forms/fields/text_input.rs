@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -13,6 +16,7 @@ use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldC
 /// - `required` : Indicates if the input is required.
 /// - `read_only` : Indicates if the input is read only.
 /// - `spell_check` : Indicates if spell checking is activated.
+#[derive(Default)]
 pub struct TextInputConfig {
     pub max_length: Option<usize>,
     pub min_length: Option<usize>,
@@ -26,6 +30,7 @@ pub struct TextInputConfig {
 }
 
 /// Creates a `<label>` + `<input type="text">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The input and label html configs.
 /// - `input_configs` : The input's configs.
@@ -34,7 +39,7 @@ pub fn create_label_text_field(
     html_configs: InputFieldConfig,
     input_configs: TextInputConfig,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "text".to_string(), input_configs, value)
 }
 
@@ -52,6 +57,7 @@ pub fn create_text_field(
 }
 
 /// Creates a `<label>` + `<input type="search">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The input and label html configs.
 /// - `input_configs` : The input's configs.
@@ -60,7 +66,7 @@ pub fn create_label_search_field(
     html_configs: InputFieldConfig,
     input_configs: TextInputConfig,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "search".to_string(), input_configs, value)
 }
 
@@ -237,19 +243,3 @@ impl AsHtmlConfig for TextInputConfig {
         configs
     }
 }
-
-impl Default for TextInputConfig {
-    fn default() -> Self {
-        Self {
-            max_length: None,
-            min_length: None,
-            list: None,
-            place_holder: None,
-            pattern: None,
-            size: None,
-            required: false,
-            read_only: false,
-            spell_check: false,
-        }
-    }
-}
@@ -1,5 +1,6 @@
 use crate::{
     element::{Element, HtmlElement, HtmlElementConfig},
+    errors::ElementResult,
     tags::TagType,
 };
 
@@ -20,6 +21,7 @@ use super::field::{AsHtmlConfig, InputFieldConfig};
 /// - `disabled` : Indicates if the text area is disabled. If the value is None this parameter is ignored.
 /// - `read_only` : Indicates if the text area is in read only mode. If the value is None this parameter is ignored.
 /// - `spell_check` : Indicates if the text area spell check mode is active. If the value is None this parameter is ignored.
+#[derive(Default)]
 pub struct TextAreaInputConfigs {
     pub auto_capitalize: Option<AutoCapitalize>,
     pub cols: Option<usize>,
@@ -65,6 +67,7 @@ pub enum WrapMode {
 }
 
 /// Creates a `<label>` + `<textarea>` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The label and text area html configs.
 /// - `text_area_configs` : The text area's configs.
@@ -73,13 +76,14 @@ pub fn create_labeled_text_area(
     html_configs: InputFieldConfig,
     text_area_configs: TextAreaInputConfigs,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     let label = Element::Element(HtmlElement::new(
         TagType::Label,
         html_configs
             .label_config
             .set_attribute("for".to_string(), Some(html_configs.id.clone())),
-    )) + Element::Text(html_configs.label.to_string());
+    ))
+    .checked_add(Element::Text(html_configs.label.to_string()))?;
 
     let configs = text_area_configs.set_html_configs(
         html_configs
@@ -90,17 +94,18 @@ pub fn create_labeled_text_area(
 
     let mut text_area = Element::Element(HtmlElement::new(TagType::TextArea, configs));
     if let Some(value) = &value {
-        text_area += Element::Text(value.clone());
+        text_area.try_push(Element::Text(value.clone()))?;
     }
 
-    Element::Element(HtmlElement::new(
+    Ok(Element::Element(HtmlElement::new(
         TagType::Div,
         HtmlElementConfig::new_empty(),
-    )) + label
+    ))) + label
         + text_area
 }
 
 /// Creates a `<textarea>` html element.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The text area's html configs.
 /// - `text_area_configs` : The text area's configs.
@@ -109,7 +114,7 @@ pub fn create_text_area(
     html_configs: InputFieldConfig,
     text_area_configs: TextAreaInputConfigs,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     let configs = text_area_configs.set_html_configs(
         html_configs
             .field_config
@@ -119,13 +124,14 @@ pub fn create_text_area(
 
     let mut text_area = Element::Element(HtmlElement::new(TagType::TextArea, configs));
     if let Some(value) = &value {
-        text_area += Element::Text(value.clone());
+        text_area.try_push(Element::Text(value.clone()))?;
     }
 
     Element::Element(HtmlElement::new(
         TagType::Div,
         HtmlElementConfig::new_empty(),
-    )) + text_area
+    ))
+    .checked_add(text_area)
 }
 
 impl TextAreaInputConfigs {
@@ -398,23 +404,3 @@ impl AsHtmlConfig for WrapMode {
         )
     }
 }
-
-impl Default for TextAreaInputConfigs {
-    fn default() -> Self {
-        Self {
-            auto_capitalize: None,
-            auto_complete: None,
-            auto_focus: None,
-            cols: None,
-            rows: None,
-            disabled: None,
-            form: None,
-            max_length: None,
-            min_length: None,
-            place_holder: None,
-            read_only: None,
-            spell_check: None,
-            wrap: None,
-        }
-    }
-}
@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -8,6 +11,7 @@ use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldC
 /// - `max` : The input's maximum value. If the value is None, this attribute is ignored.
 /// - `min` : The input's minimum value. If the value is None, this attribute is ignored.
 /// - `read_only` : Indicates if the input is in read only mode. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct TimeInputConfigs {
     pub list: Option<String>,
     pub max: Option<String>,
@@ -16,6 +20,7 @@ pub struct TimeInputConfigs {
 }
 
 /// Creates a `<label>` + `<input type="time">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The input and label html configs.
 /// - `input_configs` : The input's configs.
@@ -24,7 +29,7 @@ pub fn create_labeled_time_input(
     html_configs: InputFieldConfig,
     input_configs: TimeInputConfigs,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "time".to_string(), input_configs, value)
 }
 
@@ -124,14 +129,3 @@ impl AsHtmlConfig for TimeInputConfigs {
         configs
     }
 }
-
-impl Default for TimeInputConfigs {
-    fn default() -> Self {
-        Self {
-            list: None,
-            max: None,
-            min: None,
-            read_only: None,
-        }
-    }
-}
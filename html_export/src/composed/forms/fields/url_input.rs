@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -11,6 +14,7 @@ use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldC
 /// - `size` : The input's content display size. If the value is None, this attribute is ignored.
 /// - `read_only` : Indicates if the input is in read only mode. If the value is None, this attribute is ignored.
 /// - `spell_check` : Indicates if the input spell check is active. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct UrlInputConfigs {
     pub list: Option<String>,
     pub pattern: Option<String>,
@@ -22,6 +26,7 @@ pub struct UrlInputConfigs {
 }
 
 /// Creates a `<label>` + `<input type="url">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The label + input html configs.
 /// - `input_configs` : The input's configs.
@@ -30,7 +35,7 @@ pub fn create_labeled_url_input(
     html_configs: InputFieldConfig,
     input_configs: UrlInputConfigs,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "url".to_string(), input_configs, value)
 }
 
@@ -182,17 +187,3 @@ impl AsHtmlConfig for UrlInputConfigs {
         configs
     }
 }
-
-impl Default for UrlInputConfigs {
-    fn default() -> Self {
-        Self {
-            list: None,
-            pattern: None,
-            min_length: None,
-            max_length: None,
-            size: None,
-            read_only: None,
-            spell_check: None,
-        }
-    }
-}
@@ -1,4 +1,7 @@
-use crate::element::{Element, HtmlElementConfig};
+use crate::{
+    element::{Element, HtmlElementConfig},
+    errors::ElementResult,
+};
 
 use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldConfig};
 
@@ -8,6 +11,7 @@ use super::field::{create_input, create_labeled_input, AsHtmlConfig, InputFieldC
 /// - `min` : The input's minimum value. If the value is None, this attribute is ignored.
 /// - `step` : The input's counter step value. If the value is None, this attribute is ignored.
 /// - `read_only` : Indicates if the input is in read only mode. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct WeekInputConfigs {
     pub max: Option<String>,
     pub min: Option<String>,
@@ -16,6 +20,7 @@ pub struct WeekInputConfigs {
 }
 
 /// Creates a `<label>` + `<input type="week">` html structure.
+/// If the html structure can not be assembled, the Err variant is returned.
 ///
 /// - `html_configs` : The label + input html configs.
 /// - `input_configs` : The input's configs.
@@ -24,7 +29,7 @@ pub fn create_labeled_week_input(
     html_configs: InputFieldConfig,
     input_configs: WeekInputConfigs,
     value: Option<String>,
-) -> Element {
+) -> ElementResult {
     create_labeled_input(html_configs, "week".to_string(), input_configs, value)
 }
 
@@ -124,14 +129,3 @@ impl AsHtmlConfig for WeekInputConfigs {
         configs
     }
 }
-
-impl Default for WeekInputConfigs {
-    fn default() -> Self {
-        Self {
-            max: None,
-            min: None,
-            step: None,
-            read_only: None,
-        }
-    }
-}
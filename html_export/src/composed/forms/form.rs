@@ -1,22 +1,38 @@
 use crate::{
     element::{Element, HtmlElement, HtmlElementConfig},
+    errors::{ElementError, ElementResult, FieldCreationError, HtmlResult},
     tags::TagType,
 };
 
+#[cfg(feature = "derive")]
+pub use html_export_derive::AsForm;
+
 /// Defines the form's configs.
 ///
 /// - `method` : The form's optional method. If the value is None, this attribute is ignored.
 /// - `action` : The form's action url. If the value is None, this attribute is ignored.
+#[derive(Default)]
 pub struct FormConfig {
     pub method: Option<String>,
     pub action: Option<String>,
 }
 
+/// Defines the behavior to convert the item to a form field.
 pub trait AsForm {
     fn as_form_field(&self) -> Option<Element>;
+
+    /// Converts the item to a form field.
+    /// If the field can not be built, the Err variant is returned.
+    /// By default, the Err variant is returned if `as_form_field` returns None.
+    fn try_as_form_field(&self) -> HtmlResult<Element> {
+        self.as_form_field().ok_or_else(|| {
+            FieldCreationError::MissingField(std::any::type_name::<Self>().to_string()).into()
+        })
+    }
 }
 
 /// Wraps the given fields in a `<fieldset>`.
+/// If a field can not be added to the fieldset, the Err variant is returned.
 ///
 /// - `fields` : The fields to wrap.
 /// - `legend` : The fieldset's legend content.
@@ -27,18 +43,14 @@ pub fn wrap_fields_in_fieldset(
     legend: String,
     fieldset_config: HtmlElementConfig,
     legend_config: HtmlElementConfig,
-) -> Element {
-    let mut fieldset = Element::Element(HtmlElement::new(TagType::FieldSet, fieldset_config));
-    let legend =
-        Element::Element(HtmlElement::new(TagType::Legend, legend_config)) + Element::Text(legend);
-    fieldset += legend;
-    for field in fields {
-        fieldset += field;
-    }
-    fieldset
+) -> ElementResult {
+    let mut fieldset = create_fieldset(legend, fieldset_config, legend_config)?;
+    fieldset.try_extend(fields)?;
+    Ok(fieldset)
 }
 
 /// Wraps an entity in a `<fieldset>`.
+/// If the entity's field can not be added to the fieldset, the Err variant is returned.
 ///
 /// - `entity` : The entity to wrap. The entity must implement the `AsForm` trait.
 /// - `legend` : The fieldset's legend content.
@@ -49,56 +61,48 @@ pub fn wrap_entity_in_fieldsets<T>(
     legend: String,
     fieldset_config: HtmlElementConfig,
     legend_config: HtmlElementConfig,
-) -> Option<Element>
+) -> Result<Option<Element>, ElementError>
 where
     T: AsForm,
 {
     if let Some(field) = entity.as_form_field() {
-        let mut fieldset = Element::Element(HtmlElement::new(TagType::FieldSet, fieldset_config));
-        let legend = Element::Element(HtmlElement::new(TagType::Legend, legend_config))
-            + Element::Text(legend);
-        fieldset += legend;
-        fieldset += field;
-        Some(fieldset)
+        let fieldset = create_fieldset(legend, fieldset_config, legend_config)?;
+        Ok(Some(fieldset.checked_add(field)?))
     } else {
-        None
+        Ok(None)
     }
 }
 
 /// Wraps entities in a `<fieldset>`.
+/// If an entity's field can not be added to the fieldset, the Err variant is returned.
 ///
 /// - `entities` : The entities to wrap. The entities must implement the `AsForm` trait.
 /// - `legend` : The fieldset's legend content.
 /// - `fieldset_config` : The fieldset's html configs.
 /// - `legend_config` : The fieldset's legend html configs.
 pub fn wrap_entities_in_fieldset<T>(
-    entities: &Vec<T>,
+    entities: &[T],
     legend: String,
     fieldset_config: HtmlElementConfig,
     legend_config: HtmlElementConfig,
-) -> Option<Element>
+) -> Result<Option<Element>, ElementError>
 where
     T: AsForm,
 {
-    let mut has_element = false;
-    let mut fieldset = Element::Element(HtmlElement::new(TagType::FieldSet, fieldset_config));
-    let legend =
-        Element::Element(HtmlElement::new(TagType::Legend, legend_config)) + Element::Text(legend);
-    fieldset += legend;
-    for entity in entities {
-        if let Some(field) = entity.as_form_field() {
-            has_element = true;
-            fieldset += field;
-        }
-    }
-    if has_element {
-        Some(fieldset)
-    } else {
-        None
+    let fields = entities
+        .iter()
+        .filter_map(|entity| entity.as_form_field())
+        .collect::<Vec<Element>>();
+    if fields.is_empty() {
+        return Ok(None);
     }
+    let mut fieldset = create_fieldset(legend, fieldset_config, legend_config)?;
+    fieldset.try_extend(fields)?;
+    Ok(Some(fieldset))
 }
 
 /// Creates a `<form>` with the given elements.
+/// If a field can not be added to the form, the Err variant is returned.
 ///
 /// - `fields` : The form's fields.
 /// - `form_config` : The form's configs.
@@ -107,9 +111,9 @@ pub fn create_form(
     fields: Vec<Element>,
     form_config: FormConfig,
     html_config: HtmlElementConfig,
-) -> Option<Element> {
+) -> Result<Option<Element>, ElementError> {
     if fields.is_empty() {
-        return None;
+        return Ok(None);
     }
     let mut cfg = html_config;
     if let Some(method) = form_config.method {
@@ -119,10 +123,23 @@ pub fn create_form(
         cfg = cfg.set_attribute("action".to_string(), Some(action));
     }
     let mut form = Element::Element(HtmlElement::new(TagType::Form, cfg));
-    for field in fields {
-        form += field;
-    }
-    Some(form)
+    form.try_extend(fields)?;
+    Ok(Some(form))
+}
+
+/// Internal function. Creates a `<fieldset>` containing only its `<legend>`.
+///
+/// - `legend` : The fieldset's legend content.
+/// - `fieldset_config` : The fieldset's html configs.
+/// - `legend_config` : The fieldset's legend html configs.
+fn create_fieldset(
+    legend: String,
+    fieldset_config: HtmlElementConfig,
+    legend_config: HtmlElementConfig,
+) -> ElementResult {
+    let legend = Element::Element(HtmlElement::new(TagType::Legend, legend_config))
+        .checked_add(Element::Text(legend))?;
+    Element::Element(HtmlElement::new(TagType::FieldSet, fieldset_config)).checked_add(legend)
 }
 
 impl FormConfig {
@@ -147,12 +164,3 @@ impl FormConfig {
         self
     }
 }
-
-impl Default for FormConfig {
-    fn default() -> Self {
-        Self {
-            method: None,
-            action: None,
-        }
-    }
-}
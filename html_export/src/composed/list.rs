@@ -2,9 +2,13 @@ use std::collections::HashMap;
 
 use crate::{
     element::{Element, HtmlElement, HtmlElementConfig},
+    errors::ElementError,
     tags::TagType,
 };
 
+#[cfg(feature = "derive")]
+pub use html_export_derive::AsList;
+
 /// The different list types.
 pub enum ListType {
     Ordered,
@@ -30,6 +34,8 @@ pub trait AsList {
 }
 
 /// Converts a vector to a list.
+/// If the vector is empty, the function returns None.
+/// If an item can not be added to the list, the Err variant is returned.
 ///
 /// `collection` : Vector of element to be converted into a list.
 /// `mode` : The list type
@@ -38,12 +44,12 @@ pub fn from_iterator<T>(
     collection: &Vec<T>,
     mode: &ListType,
     list_config: HtmlElementConfig,
-) -> Option<Element>
+) -> Result<Option<Element>, ElementError>
 where
     T: AsList,
 {
     if collection.is_empty() {
-        return None;
+        return Ok(None);
     }
     let mut list = match mode {
         ListType::Ordered => Element::Element(HtmlElement::new(TagType::Ol, list_config)),
@@ -54,27 +60,34 @@ where
         match mode {
             ListType::Ordered | ListType::Unordered => {
                 if let Some(list_item) = item.to_list_item(mode) {
-                    list += list_item;
+                    list.try_push(list_item)?;
                 }
             }
             ListType::TermDefinition => {
                 if let Some((key, value)) = item.to_definition_list_item() {
-                    list += Element::Element(HtmlElement::new(
-                        TagType::Dt,
-                        HtmlElementConfig::new_empty(),
-                    )) + key;
-                    list += Element::Element(HtmlElement::new(
-                        TagType::Dd,
-                        HtmlElementConfig::new_empty(),
-                    )) + value;
+                    list.try_push(
+                        Element::Element(HtmlElement::new(
+                            TagType::Dt,
+                            HtmlElementConfig::new_empty(),
+                        ))
+                        .checked_add(key)?,
+                    )?;
+                    list.try_push(
+                        Element::Element(HtmlElement::new(
+                            TagType::Dd,
+                            HtmlElementConfig::new_empty(),
+                        ))
+                        .checked_add(value)?,
+                    )?;
                 }
             }
         }
     }
-    Some(list)
+    Ok(Some(list))
 }
 
 /// Converts a hashmap into a definition list.
+/// If an item can not be added to the list, the Err variant is returned.
 ///
 /// `items` : The hashmap to be converted.
 /// `list_config` : The list tag html configs.
@@ -83,23 +96,19 @@ where
 pub fn term_definition_list_from_map<T, U>(
     items: &HashMap<T, U>,
     list_config: HtmlElementConfig,
-) -> Option<Element>
+) -> Result<Option<Element>, ElementError>
 where
     T: AsList,
     U: AsList,
 {
     let mut list = Element::Element(HtmlElement::new(TagType::Dl, list_config));
     for (key, value) in items {
-        match (
+        if let (Some(k), Some(v)) = (
             key.to_list_item(&ListType::TermDefinition),
             value.to_list_item(&ListType::TermDefinition),
         ) {
-            (Some(k), Some(v)) => {
-                list += k;
-                list += v;
-            }
-            _ => {}
+            list.try_extend([k, v])?;
         }
     }
-    Some(list)
+    Ok(Some(list))
 }
@@ -1,3 +1,4 @@
+pub mod code;
 pub mod forms;
 pub mod list;
 pub mod table;
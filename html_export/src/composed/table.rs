@@ -1,8 +1,12 @@
 use crate::{
     element::{Element, HtmlElement, HtmlElementConfig},
+    errors::ElementError,
     tags::TagType,
 };
 
+#[cfg(feature = "derive")]
+pub use html_export_derive::AsTable;
+
 /// Defines the behavior to convert the item to a table.
 pub trait AsTable {
     /// Defines how to convert the item to the table header.
@@ -18,38 +22,49 @@ pub trait AsTable {
     /// If the function returns None, the generated table has no footer.
     /// It is recommanded to return an `<tfoot>` element.
     fn as_table_foot(&self) -> Option<Element>;
+
+    /// Defines how to convert the whole collection to the table footer, for example to display totals.
+    /// By default, the footer is generated using the collection's first element.
+    /// If the function returns None, the generated table has no footer.
+    ///
+    /// `items` : The collection converted to a table.
+    fn as_table_foot_of(items: &[Self]) -> Option<Element>
+    where
+        Self: Sized,
+    {
+        items.first().and_then(|item| item.as_table_foot())
+    }
 }
 
 /// Converts the given vector to a html table.
 /// If the vector is empty, the function returns None.
-/// The table's headear and footer are generated using the vector's first element.
+/// If a header, row or footer can not be added to the table, the Err variant is returned.
+/// The table's headear is generated using the vector's first element and its footer using `AsTable::as_table_foot_of`.
 /// The table's body is wrapped in a `<tbody>` html element.
 pub fn from_iterator<T>(
     collection: &Vec<T>,
     table_config: HtmlElementConfig,
     table_body_config: HtmlElementConfig,
-) -> Option<Element>
+) -> Result<Option<Element>, ElementError>
 where
     T: AsTable,
 {
-    if collection.is_empty() {
-        return None;
-    }
+    let Some(first) = collection.first() else {
+        return Ok(None);
+    };
     let mut table = Element::Element(HtmlElement::new(TagType::Table, table_config));
     let mut body = Element::Element(HtmlElement::new(TagType::Tbody, table_body_config));
-    let head = collection.get(0).unwrap().as_table_head();
-    let foot = collection.get(0).unwrap().as_table_foot();
-    if let Some(head) = head {
-        table += head;
+    if let Some(head) = first.as_table_head() {
+        table.try_push(head)?;
     };
     for item in collection {
         if let Some(row) = item.as_table_row() {
-            body += row;
+            body.try_push(row)?;
         }
     }
-    table += body;
-    if let Some(foot) = foot {
-        table += foot;
+    table.try_push(body)?;
+    if let Some(foot) = T::as_table_foot_of(collection) {
+        table.try_push(foot)?;
     };
-    Some(table)
+    Ok(Some(table))
 }
@@ -0,0 +1,415 @@
+use std::fmt::Display;
+
+use crate::{
+    assets::base64_encode,
+    document::Document,
+    element::{Element, HtmlElement, HtmlElementConfig},
+    errors::{CspError, HtmlResult},
+    miscellaneous::{escape_html, HttpEquiv},
+    tags::TagType,
+};
+
+/// The `'self'` source, allowing the document's origin.
+pub const SELF: &str = "'self'";
+
+/// The `'none'` source, allowing nothing.
+pub const NONE: &str = "'none'";
+
+/// The `'unsafe-inline'` source, allowing every inline script or style. No nonce is added to a directive allowing it.
+pub const UNSAFE_INLINE: &str = "'unsafe-inline'";
+
+/// The `'strict-dynamic'` source, trusting the scripts loaded by an already trusted script.
+pub const STRICT_DYNAMIC: &str = "'strict-dynamic'";
+
+/// The Content-Security-Policy directives.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CspDirective {
+    DefaultSrc,
+    ScriptSrc,
+    StyleSrc,
+    ImgSrc,
+    FontSrc,
+    ConnectSrc,
+    MediaSrc,
+    ObjectSrc,
+    FrameSrc,
+    WorkerSrc,
+    ManifestSrc,
+    BaseUri,
+    FormAction,
+    FrameAncestors,
+    UpgradeInsecureRequests,
+    ReportUri,
+}
+
+/// A Content-Security-Policy, restricting the resources the document can load and execute.
+/// When a document is rendered with a policy, a new nonce is stamped on every `<script>` and `<style>` tag,
+/// and added to the `script-src` and `style-src` directives restricting inline content.
+///
+/// - `directives` : The directives and their sources, in order.
+#[derive(Clone, Debug, Default)]
+pub struct ContentSecurityPolicy {
+    pub directives: Vec<(CspDirective, Vec<String>)>,
+}
+
+/// A document secured by a Content-Security-Policy, see `ContentSecurityPolicy::apply`.
+///
+/// - `document` : The document, with the nonce stamped on its scripts and styles.
+/// - `nonce` : The nonce of this render.
+/// - `header_value` : The policy including the nonce, to send in the `Content-Security-Policy` http header.
+#[derive(Clone, Debug)]
+pub struct ProtectedDocument {
+    pub document: Document,
+    pub nonce: String,
+    pub header_value: String,
+}
+
+impl CspDirective {
+    /// Returns true if the directive is ignored by the browsers when the policy is delivered in a `<meta>` tag.
+    pub fn requires_header(&self) -> bool {
+        matches!(self, CspDirective::FrameAncestors | CspDirective::ReportUri)
+    }
+}
+
+impl Display for CspDirective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CspDirective::DefaultSrc => "default-src",
+            CspDirective::ScriptSrc => "script-src",
+            CspDirective::StyleSrc => "style-src",
+            CspDirective::ImgSrc => "img-src",
+            CspDirective::FontSrc => "font-src",
+            CspDirective::ConnectSrc => "connect-src",
+            CspDirective::MediaSrc => "media-src",
+            CspDirective::ObjectSrc => "object-src",
+            CspDirective::FrameSrc => "frame-src",
+            CspDirective::WorkerSrc => "worker-src",
+            CspDirective::ManifestSrc => "manifest-src",
+            CspDirective::BaseUri => "base-uri",
+            CspDirective::FormAction => "form-action",
+            CspDirective::FrameAncestors => "frame-ancestors",
+            CspDirective::UpgradeInsecureRequests => "upgrade-insecure-requests",
+            CspDirective::ReportUri => "report-uri",
+        })
+    }
+}
+
+impl ContentSecurityPolicy {
+    /// Creates an empty policy, allowing everything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a strict policy : only the document's origin is allowed, plugins are blocked,
+    /// and the inline scripts and styles must carry the render's nonce.
+    pub fn strict() -> Self {
+        Self::new()
+            .with_directive(CspDirective::DefaultSrc, &[SELF])
+            .with_directive(CspDirective::ObjectSrc, &[NONE])
+            .with_directive(CspDirective::BaseUri, &[SELF])
+    }
+
+    /// Adds sources to a directive, the directive is created if it is not part of the policy.
+    ///
+    /// - `directive` : The directive.
+    /// - `sources` : The sources (`SELF`, `https://cdn.example.com`, `data:`...). Empty for `UpgradeInsecureRequests`.
+    pub fn with_directive(mut self, directive: CspDirective, sources: &[&str]) -> Self {
+        let sources = sources.iter().map(|source| source.to_string());
+        match self.directives.iter_mut().find(|(d, _)| *d == directive) {
+            Some((_, existing)) => {
+                for source in sources {
+                    if !existing.contains(&source) {
+                        existing.push(source);
+                    }
+                }
+            }
+            None => self.directives.push((directive, sources.collect())),
+        }
+        self
+    }
+
+    /// Returns the sources of a directive, or None if the directive is not part of the policy.
+    ///
+    /// - `directive` : The directive.
+    pub fn get_sources(&self, directive: &CspDirective) -> Option<&Vec<String>> {
+        self.directives
+            .iter()
+            .find(|(d, _)| d == directive)
+            .map(|(_, sources)| sources)
+    }
+
+    /// Returns true if the policy blocks the inline content of a directive without nonce,
+    /// looking at the `default-src` directive if the directive is not part of the policy.
+    ///
+    /// - `directive` : `ScriptSrc` or `StyleSrc`.
+    pub fn restricts_inline(&self, directive: &CspDirective) -> bool {
+        match self
+            .get_sources(directive)
+            .or(self.get_sources(&CspDirective::DefaultSrc))
+        {
+            Some(sources) => !sources.iter().any(|source| source == UNSAFE_INLINE),
+            None => false,
+        }
+    }
+
+    /// Returns the policy as an http header value (`default-src 'self'; script-src 'self' 'nonce-...'`).
+    ///
+    /// - `nonce` : The render's nonce, added to the `script-src` and `style-src` directives restricting inline content.
+    ///   If these directives are not part of the policy, they are created from `default-src`.
+    pub fn to_header_value(&self, nonce: Option<&str>) -> String {
+        self.with_nonce(nonce)
+            .directives
+            .iter()
+            .map(|(directive, sources)| {
+                let mut directive = directive.to_string();
+                for source in sources {
+                    directive.push(' ');
+                    directive.push_str(source);
+                }
+                directive
+            })
+            .collect::<Vec<String>>()
+            .join("; ")
+    }
+
+    /// Returns the policy as a `<meta http-equiv="content-security-policy">` element.
+    /// The directives requiring an http header are left out, see `CspDirective::requires_header`.
+    ///
+    /// - `nonce` : The render's nonce, see `ContentSecurityPolicy::to_header_value`.
+    pub fn to_meta_element(&self, nonce: Option<&str>) -> Element {
+        let policy = Self {
+            directives: self
+                .directives
+                .iter()
+                .filter(|(directive, _)| !directive.requires_header())
+                .cloned()
+                .collect(),
+        };
+        Element::Element(HtmlElement::new(
+            TagType::Meta,
+            HtmlElementConfig::new_empty()
+                .set_attribute(
+                    "http-equiv".to_string(),
+                    Some(HttpEquiv::ContentSecurityPolicy.to_string()),
+                )
+                .set_attribute(
+                    "content".to_string(),
+                    Some(escape_html(&policy.to_header_value(nonce))),
+                ),
+        ))
+    }
+
+    /// Secures a document with a new nonce : the nonce is stamped on every `<script>` and `<style>` tag.
+    /// The policy is not added to the document, send `header_value` or add the element of `to_meta_element`.
+    /// If an element has an inline event handler (`onclick`...) or a `javascript:` url blocked by the policy,
+    /// the Err variant is returned with the path of the element.
+    ///
+    /// - `document` : The document to secure.
+    pub fn apply(&self, document: &Document) -> HtmlResult<ProtectedDocument> {
+        let nonce = generate_nonce()?;
+        let mut document = document.clone();
+        document.content_security_policy = None;
+        let scripts = self.restricts_inline(&CspDirective::ScriptSrc);
+        for (index, element) in document.head.content.iter_mut().enumerate() {
+            secure_element(element, &nonce, scripts)
+                .map_err(|error| error.inside("head".to_string(), index))?;
+        }
+        for (index, element) in document.body.iter_mut().enumerate() {
+            secure_element(element, &nonce, scripts)
+                .map_err(|error| error.inside("body".to_string(), index))?;
+        }
+        Ok(ProtectedDocument {
+            header_value: self.to_header_value(Some(&nonce)),
+            document,
+            nonce,
+        })
+    }
+
+    /// Internal function. Returns the policy with the nonce added to the directives restricting inline content.
+    ///
+    /// - `nonce` : The optional nonce.
+    fn with_nonce(&self, nonce: Option<&str>) -> Self {
+        let Some(nonce) = nonce else {
+            return self.clone();
+        };
+        let mut policy = self.clone();
+        for directive in [CspDirective::ScriptSrc, CspDirective::StyleSrc] {
+            if !self.restricts_inline(&directive) {
+                continue;
+            }
+            if self.get_sources(&directive).is_none() {
+                let defaults = self
+                    .get_sources(&CspDirective::DefaultSrc)
+                    .cloned()
+                    .unwrap_or_default();
+                policy.directives.push((directive.clone(), defaults));
+            }
+            policy = policy.with_directive(directive, &[&format!("'nonce-{}'", nonce)]);
+        }
+        policy
+    }
+}
+
+/// Returns a new random nonce, 128 bits read from the operating system's random generator and encoded in base64.
+/// If the operating system's random generator is not available, the Err variant is returned.
+pub fn generate_nonce() -> Result<String, CspError> {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes)
+        .map_err(|error| CspError::UnavailableRandomness(error.to_string()))?;
+    Ok(base64_encode(&bytes))
+}
+
+/// Internal function. Stamps the nonce on the scripts and styles of an element tree,
+/// and checks that the tree has no inline javascript.
+///
+/// - `element` : The element tree.
+/// - `nonce` : The nonce.
+/// - `scripts` : If true, the policy blocks the inline event handlers and `javascript:` urls.
+fn secure_element(element: &mut Element, nonce: &str, scripts: bool) -> HtmlResult<()> {
+    let Element::Element(element) = element else {
+        return Ok(());
+    };
+    let tag = String::from(element.tag.clone());
+    if scripts {
+        for (attribute, value) in element.config.attributes.iter() {
+            let name = attribute.to_lowercase();
+            if name.len() > 2 && name.starts_with("on") {
+                return Err(CspError::InlineHandler(tag, attribute.clone()).into());
+            }
+            let is_javascript = value
+                .as_ref()
+                .is_some_and(|value| value.trim_start().to_lowercase().starts_with("javascript:"));
+            if is_javascript {
+                return Err(CspError::JavascriptUrl(tag, attribute.clone()).into());
+            }
+        }
+    }
+    match element.tag {
+        TagType::Script | TagType::Style => {
+            element
+                .config
+                .attributes
+                .insert("nonce".to_string(), Some(nonce.to_string()));
+        }
+        _ => {}
+    }
+    for (index, child) in element.children.iter_mut().enumerate() {
+        secure_element(child, nonce, scripts).map_err(|error| error.inside(tag.clone(), index))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::{Error, TreePath},
+        head::Head,
+    };
+
+    fn element(tag: TagType, attributes: &[(&str, &str)]) -> Element {
+        let mut configs = HtmlElementConfig::new_empty();
+        for (name, value) in attributes {
+            configs = configs.set_attribute(name.to_string(), Some(value.to_string()));
+        }
+        Element::Element(HtmlElement::new(tag, configs))
+    }
+
+    fn nonce_of(element: &Element) -> Option<String> {
+        match element {
+            Element::Element(element) => element.config.attributes.get("nonce").cloned().flatten(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn apply_stamps_the_nonce_on_scripts_and_styles() {
+        let head = Head::new()
+            .with_raw_javascript(String::from("run();"), None)
+            .with_style(String::from("p { color: red; }"));
+        let body = vec![element(TagType::Div, &[]) + element(TagType::Script, &[("src", "a.js")])];
+        let protected = ContentSecurityPolicy::strict()
+            .apply(&Document::new(head, body))
+            .unwrap();
+        let nonce = Some(protected.nonce.clone());
+        for element in &protected.document.head.content {
+            assert_eq!(nonce_of(element), nonce);
+        }
+        let Element::Element(div) = &protected.document.body[0] else {
+            panic!("the body's first element is not a div");
+        };
+        assert_eq!(nonce_of(&div.children[0]), nonce);
+        assert_eq!(nonce_of(&protected.document.body[0]), None);
+        assert!(protected
+            .header_value
+            .contains(&format!("script-src 'self' 'nonce-{}'", protected.nonce)));
+    }
+
+    #[test]
+    fn apply_rejects_inline_event_handlers_with_their_path() {
+        let body = vec![
+            element(TagType::P, &[]),
+            element(TagType::Div, &[]) + element(TagType::Button, &[("onclick", "run()")]),
+        ];
+        let error = ContentSecurityPolicy::strict()
+            .apply(&Document::new(Head::new(), body))
+            .unwrap_err();
+        assert_eq!(
+            *error.path().unwrap(),
+            TreePath {
+                steps: vec![("body".to_string(), 1), ("div".to_string(), 0)],
+            }
+        );
+        let Error::InTree(_, source) = error else {
+            panic!("the error has no path");
+        };
+        assert!(matches!(
+            *source,
+            Error::Csp(CspError::InlineHandler(tag, attribute))
+                if tag == "button" && attribute == "onclick"
+        ));
+    }
+
+    #[test]
+    fn apply_rejects_javascript_urls() {
+        let body = vec![element(TagType::A, &[("href", " JavaScript:run()")])];
+        let error = ContentSecurityPolicy::strict()
+            .apply(&Document::new(Head::new(), body))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::InTree(_, source) if matches!(*source, Error::Csp(CspError::JavascriptUrl(_, _)))
+        ));
+    }
+
+    #[test]
+    fn unsafe_inline_allows_event_handlers() {
+        let policy = ContentSecurityPolicy::new()
+            .with_directive(CspDirective::ScriptSrc, &[SELF, UNSAFE_INLINE]);
+        let body = vec![element(TagType::Button, &[("onclick", "run()")])];
+        let protected = policy.apply(&Document::new(Head::new(), body)).unwrap();
+        assert_eq!(protected.header_value, "script-src 'self' 'unsafe-inline'");
+    }
+
+    #[test]
+    fn nonces_are_random_and_encoded() {
+        let first = generate_nonce().unwrap();
+        let second = generate_nonce().unwrap();
+        assert_ne!(first, second);
+        assert_eq!(first.len(), 24);
+    }
+
+    #[test]
+    fn meta_element_leaves_out_header_only_directives() {
+        let policy =
+            ContentSecurityPolicy::strict().with_directive(CspDirective::FrameAncestors, &[NONE]);
+        let Element::Element(meta) = policy.to_meta_element(None) else {
+            panic!("the policy is not an element");
+        };
+        let content = meta.config.attributes["content"].clone().unwrap();
+        assert!(!content.contains("frame-ancestors"));
+        assert!(policy
+            .to_header_value(None)
+            .contains("frame-ancestors 'none'"));
+    }
+}
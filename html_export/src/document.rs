@@ -0,0 +1,124 @@
+use crate::{
+    assets::inline_document,
+    csp::ContentSecurityPolicy,
+    element::Element,
+    errors::HtmlResult,
+    export_localized_to_file, export_to_file,
+    head::Head,
+    text::{to_markdown, to_plain_text},
+};
+
+/// Represents a complete html document, ready for export.
+///
+/// - `head` : The document's `<head>` content.
+/// - `body` : The document's `<body>` content.
+/// - `locale` : The document's locale, written in the `lang` and `dir` attributes of the `<html>` tag.
+/// - `content_security_policy` : The policy written in a `<meta>` tag, with a new nonce at each export.
+#[derive(Clone, Debug)]
+pub struct Document {
+    pub head: Head,
+    pub body: Vec<Element>,
+    pub locale: Option<String>,
+    pub content_security_policy: Option<ContentSecurityPolicy>,
+}
+
+impl Document {
+    /// Creates a new document.
+    ///
+    /// - `head` : The document's `<head>` content.
+    /// - `body` : The document's `<body>` content.
+    pub fn new(head: Head, body: Vec<Element>) -> Self {
+        Self {
+            head,
+            body,
+            locale: None,
+            content_security_policy: None,
+        }
+    }
+
+    /// Sets the document's locale.
+    ///
+    /// - `locale` : The locale (`en`, `ar-EG`...).
+    pub fn with_locale(mut self, locale: String) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Sets the document's Content-Security-Policy, delivered in a `<meta>` tag placed after the charset.
+    /// To deliver the policy in an http header, use `ContentSecurityPolicy::apply` instead.
+    ///
+    /// - `policy` : The policy.
+    pub fn with_content_security_policy(mut self, policy: ContentSecurityPolicy) -> Self {
+        self.content_security_policy = Some(policy);
+        self
+    }
+
+    /// Exports the document to an html file, shortcut for `export_to_file`,
+    /// or `export_localized_to_file` if the document has a locale.
+    /// If the document has a Content-Security-Policy, it is applied with a new nonce, see `ContentSecurityPolicy::apply`.
+    /// If the file can not be written or if the document violates its policy,
+    /// the Err variant is returned with the path of the failing element.
+    ///
+    /// - `destination` : The destination folder's path.
+    /// - `filename` : The html file's name.
+    pub fn export_to_file(&self, destination: String, filename: String) -> HtmlResult<()> {
+        if let Some(policy) = &self.content_security_policy {
+            let protected = policy.apply(self)?;
+            let mut document = protected.document;
+            let position = document
+                .head
+                .content
+                .iter()
+                .take_while(|element| match element {
+                    Element::Element(element) => {
+                        element.config.has_attribute("charset".to_string())
+                    }
+                    _ => false,
+                })
+                .count();
+            document
+                .head
+                .content
+                .insert(position, policy.to_meta_element(Some(&protected.nonce)));
+            return document.export_to_file(destination, filename);
+        }
+        match &self.locale {
+            Some(locale) => export_localized_to_file(
+                destination,
+                filename,
+                locale,
+                self.head.clone(),
+                self.body.clone(),
+            ),
+            None => export_to_file(destination, filename, self.head.clone(), self.body.clone()),
+        }
+    }
+
+    /// Exports the document to a single html file, embedding the local css, javascript, images and fonts.
+    /// See `assets::inline_document`.
+    /// If a referenced file can not be read or if the file can not be written, the Err variant is returned.
+    ///
+    /// - `source` : The folder containing the local files, the references are relative to this folder.
+    /// - `destination` : The destination folder's path.
+    /// - `filename` : The html file's name.
+    pub fn export_to_single_file(
+        &self,
+        source: &str,
+        destination: String,
+        filename: String,
+    ) -> HtmlResult<()> {
+        inline_document(self, source)?.export_to_file(destination, filename)
+    }
+
+    /// Renders the document's body to GitHub-flavoured Markdown, shortcut for `text::to_markdown`.
+    pub fn to_markdown(&self) -> String {
+        to_markdown(&self.body)
+    }
+
+    /// Renders the document's body to plain text, shortcut for `text::to_plain_text`.
+    ///
+    /// - `width` : The maximal line width.
+    pub fn to_plain_text(&self, width: usize) -> String {
+        to_plain_text(&self.body, width)
+    }
+}
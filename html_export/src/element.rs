@@ -5,7 +5,10 @@ use std::{
 };
 
 use crate::{
-    errors::{CreateElementResult, ElementError, WrapElementError, WrapResult},
+    errors::{
+        CreateElementResult, ElementError, ElementResult, HtmlResult, TemplateError,
+        WrapElementError, WrapResult,
+    },
     html::Html,
     miscellaneous::WrapMode,
     tags::TagType,
@@ -13,22 +16,53 @@ use crate::{
 
 /// Represents an Element.
 /// The Html tree is either composed of `HtmlElement` or raw String.
+/// Templates may also contain named slots, replaced by elements with `Template::fill`.
+///
+/// With the `serde` feature, the tree has the following stable JSON representation:
+/// - A raw text is a JSON string : `"Hello"`.
+/// - An Html element is a JSON object :
+///   `{"tag": "a", "classes": ["link"], "id": "home", "attributes": {"download": null, "href": "/"}, "children": ["Home"]}`.
+///     - `tag` : The Html tag name.
+///     - `classes` : The element's css classes, sorted. Omitted if the element has no class.
+///     - `id` : The element's id. Omitted if the element has no id.
+///     - `attributes` : The element's attributes sorted by name, `null` for attributes without value. Omitted if the element has no attribute.
+///     - `children` : The element's sub-elements. Omitted if the element has no sub-element.
+/// - A slot is a JSON object : `{"slot": "sidebar"}`.
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
 pub enum Element {
     Text(String),
     Element(HtmlElement),
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_slot",
+            deserialize_with = "deserialize_slot"
+        )
+    )]
+    Slot(String),
 }
 
 /// Represents an Html Element.
 ///
 /// `tag` : The element's Html tag.
-/// `children` : The element's sub-elements.
 /// `config` : The element's Html configs.
+/// `children` : The element's sub-elements.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HtmlElement {
     pub tag: TagType,
-    pub children: Vec<Box<Element>>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub config: HtmlElementConfig,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub children: Vec<Box<Element>>,
 }
 
 /// Represents an Html element config
@@ -36,10 +70,31 @@ pub struct HtmlElement {
 /// `classes` : The element's css classes.
 /// `id` : The element's id.
 /// `attributes` : The element's key-value attributes.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HtmlElementConfig {
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "HashSet::is_empty",
+            serialize_with = "serialize_sorted_classes"
+        )
+    )]
     pub classes: HashSet<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "HashMap::is_empty",
+            serialize_with = "serialize_sorted_attributes"
+        )
+    )]
     pub attributes: HashMap<String, Option<String>>,
 }
 
@@ -52,7 +107,7 @@ impl Element {
         if !element.tag.can_wrap() {
             return Err(WrapElementError::NonWrapperElement(element.tag));
         }
-        if element.children.len() > 0 && element.tag.is_single_child() {
+        if !element.children.is_empty() && element.tag.is_single_child() {
             return Err(WrapElementError::SingleChildExceeded(element.tag));
         }
         match mode {
@@ -75,25 +130,65 @@ impl Element {
     /// Indicates if the element can contain another child.
     pub fn can_add_child(&self) -> bool {
         match self {
-            Element::Text(_) => false,
+            Element::Text(_) | Element::Slot(_) => false,
             Element::Element(html_element) => html_element.can_add_child(),
         }
     }
 
     /// Adds without checking the child to the element.
+    /// Panics if the element is raw text or a slot, prefer `Element::try_push` to handle this case.
     ///
     /// `child` : The sub-element to add.
     pub fn add_mut_element(&mut self, child: Box<Element>) {
         match self {
-            Element::Text(_) => panic!("Can not add child to {:?}", self),
+            Element::Text(_) | Element::Slot(_) => panic!("Can not add child to {:?}", self),
             Element::Element(e) => e.add_mut_child(child),
         }
     }
 
+    /// Attempts to add the sub-element at the end of the element's sub-elements.
+    /// If the element is raw text, a slot or can not contain another child, the Err variant is returned
+    /// and the element is left untouched.
+    ///
+    /// `child` : The sub-element to add.
+    pub fn try_push(&mut self, child: Element) -> Result<(), ElementError> {
+        match self {
+            Element::Text(_) => Err(ElementError::TextContent),
+            Element::Slot(name) => Err(ElementError::SlotContent(name.clone())),
+            Element::Element(html_element) => html_element.try_push(child),
+        }
+    }
+
+    /// Attempts to add the sub-elements at the end of the element's sub-elements.
+    /// If the element is raw text, a slot or can not contain all the children, the Err variant is returned
+    /// and the element is left untouched.
+    ///
+    /// `children` : The sub-elements to add.
+    pub fn try_extend<T>(&mut self, children: T) -> Result<(), ElementError>
+    where
+        T: IntoIterator<Item = Element>,
+    {
+        match self {
+            Element::Text(_) => Err(ElementError::TextContent),
+            Element::Slot(name) => Err(ElementError::SlotContent(name.clone())),
+            Element::Element(html_element) => html_element.try_extend(children),
+        }
+    }
+
+    /// Checked version of the `+` operator.
+    /// Attempts to add the sub-element to the element and returns the updated element.
+    /// If the element is raw text, a slot or can not contain another child, the Err variant is returned.
+    ///
+    /// `child` : The sub-element to add.
+    pub fn checked_add(mut self, child: Element) -> ElementResult {
+        self.try_push(child)?;
+        Ok(self)
+    }
+
     /// Indicates if the element is allowed in the html document's `<head>`.
     pub fn is_allowed_in_head(&self) -> bool {
         match self {
-            Element::Text(_) => false,
+            Element::Text(_) | Element::Slot(_) => false,
             Element::Element(html_element) => html_element.tag.is_allowed_is_head(),
         }
     }
@@ -116,6 +211,7 @@ impl HtmlElement {
     /// Note that some tags restrict their content's length:
     /// - Some elements can not have sub-elements.
     /// - Some elements can have at most 1 sub-element.
+    ///
     /// If the content does not respect the restrictions, the Err variant is returned.
     ///
     /// `tag` : The Html element's type.
@@ -130,7 +226,8 @@ impl HtmlElement {
         T: Iterator<Item = Box<Element>>,
     {
         let children = content.collect::<Vec<Box<Element>>>();
-        if (!tag.can_wrap() && children.len() > 0) || (tag.is_single_child() && children.len() > 1)
+        if (!tag.can_wrap() && !children.is_empty())
+            || (tag.is_single_child() && children.len() > 1)
         {
             return Err(ElementError::InvalidContent(tag));
         }
@@ -150,6 +247,7 @@ impl HtmlElement {
     /// Note that some tags restrict their content's length:
     /// - Some elements can not have sub-elements.
     /// - Some elements can have at most 1 sub-element.
+    ///
     /// If the restrictions are not respected when adding the child, the Err variant is returned.
     ///
     /// `child` : The sub-element to add.
@@ -165,6 +263,7 @@ impl HtmlElement {
     /// Note that some tags restrict their content's length:
     /// - Some elements can not have sub-elements.
     /// - Some elements can have at most 1 sub-element.
+    ///
     /// If the restrictions are not respected when adding the children, the Err variant is returned.
     ///
     /// `children` : The sub-elements to add.
@@ -172,11 +271,43 @@ impl HtmlElement {
     where
         T: Iterator<Item = Box<Element>>,
     {
-        if !self.tag.can_wrap() || (self.tag.is_single_child() && !self.children.is_empty()) {
-            return Err(ElementError::InvalidContent(self.tag));
+        self.try_extend(children.map(|child| *child))?;
+        Ok(self)
+    }
+
+    /// Attempts to add the sub-element at the end of the element's sub-elements.
+    /// If the restrictions are not respected when adding the child, the Err variant is returned
+    /// and the element is left untouched.
+    ///
+    /// `child` : The sub-element to add.
+    pub fn try_push(&mut self, child: Element) -> Result<(), ElementError> {
+        if !self.can_add_child() {
+            return Err(ElementError::InvalidContent(self.tag.clone()));
+        }
+        self.children.push(Box::new(child));
+        Ok(())
+    }
+
+    /// Attempts to add the sub-elements at the end of the element's sub-elements.
+    /// If the restrictions are not respected when adding the children, the Err variant is returned
+    /// and the element is left untouched.
+    ///
+    /// `children` : The sub-elements to add.
+    pub fn try_extend<T>(&mut self, children: T) -> Result<(), ElementError>
+    where
+        T: IntoIterator<Item = Element>,
+    {
+        let children = children.into_iter().map(Box::new).collect::<Vec<_>>();
+        if children.is_empty() {
+            return Ok(());
+        }
+        if !self.tag.can_wrap()
+            || (self.tag.is_single_child() && self.children.len() + children.len() > 1)
+        {
+            return Err(ElementError::InvalidContent(self.tag.clone()));
         }
         self.children.extend(children);
-        Ok(self)
+        Ok(())
     }
 
     /// Attempts to remove the element's sub-element at the given index.
@@ -352,10 +483,7 @@ impl HtmlElementConfig {
     ///
     /// `name` : The targeted attribute.
     pub fn get_attribute(&self, name: String) -> Option<Option<String>> {
-        match self.attributes.get(&name) {
-            Some(string) => Some(string.clone()),
-            _ => None,
-        }
+        self.attributes.get(&name).cloned()
     }
 
     /// Checks if the element has an attribute named `name`.
@@ -407,18 +535,9 @@ impl HtmlElementConfig {
     }
 }
 
-impl Default for HtmlElementConfig {
-    fn default() -> Self {
-        Self {
-            classes: HashSet::new(),
-            id: None,
-            attributes: HashMap::new(),
-        }
-    }
-}
-
 impl Html for Element {
     /// Writes the Element in Html format onto the given writer.
+    /// If the element is a slot that was not filled, the Err variant is returned.
     ///
     /// `writer` : The writer
     /// `tabs` : Amount of tabulations to insert before writing the element.
@@ -426,13 +545,14 @@ impl Html for Element {
         &self,
         mut writer: std::io::BufWriter<std::fs::File>,
         tabs: usize,
-    ) -> std::io::Result<std::io::BufWriter<std::fs::File>> {
+    ) -> HtmlResult<std::io::BufWriter<std::fs::File>> {
         match &self {
             Element::Text(text) => {
-                writer.write(format!("{}\n", text).as_bytes())?;
+                writer.write_all(format!("{}\n", text).as_bytes())?;
                 Ok(writer)
             }
             Element::Element(html_element) => html_element.write_html(writer, tabs),
+            Element::Slot(name) => Err(TemplateError::MissingSlot(name.clone()).into()),
         }
     }
 }
@@ -446,27 +566,67 @@ impl Html for HtmlElement {
         &self,
         mut writer: std::io::BufWriter<std::fs::File>,
         tabs: usize,
-    ) -> std::io::Result<std::io::BufWriter<std::fs::File>> {
-        let tabbing = std::iter::repeat("\t").take(tabs).collect::<String>();
-        writer.write(format!("{}<{}", tabbing, self.tag.get_tag_name()).as_bytes())?;
+    ) -> HtmlResult<std::io::BufWriter<std::fs::File>> {
+        let tabbing = "\t".repeat(tabs);
+        writer.write_all(format!("{}<{}", tabbing, self.tag.get_tag_name()).as_bytes())?;
         writer = self.config.write_html(writer, tabs)?;
         if self.children.is_empty() {
             if self.tag.is_auto_closing() {
-                writer.write(" />\n".as_bytes())?;
+                writer.write_all(" />\n".as_bytes())?;
             } else {
-                writer.write(format!("></{}>\n", self.tag.get_tag_name()).as_bytes())?;
+                writer.write_all(format!("></{}>\n", self.tag.get_tag_name()).as_bytes())?;
+            }
+        } else if self.tag.preserves_whitespace() {
+            writer.write_all(">".as_bytes())?;
+            for (index, child) in self.children.iter().enumerate() {
+                writer = write_preformatted(child, writer)
+                    .map_err(|error| error.inside(self.tag.get_tag_name(), index))?;
             }
+            writer.write_all(format!("</{}>\n", self.tag.get_tag_name()).as_bytes())?;
         } else {
-            writer.write(">\n".as_bytes())?;
-            for child in self.children.iter() {
-                writer = child.write_html(writer, tabs + 1)?;
+            writer.write_all(">\n".as_bytes())?;
+            for (index, child) in self.children.iter().enumerate() {
+                writer = child
+                    .write_html(writer, tabs + 1)
+                    .map_err(|error| error.inside(self.tag.get_tag_name(), index))?;
             }
-            writer.write(format!("{}</{}>\n", tabbing, self.tag.get_tag_name()).as_bytes())?;
+            writer.write_all(format!("{}</{}>\n", tabbing, self.tag.get_tag_name()).as_bytes())?;
         }
         Ok(writer)
     }
 }
 
+/// Internal function. Writes the element without indentation nor line breaks,
+/// to keep the whitespaces of a preformatted content unchanged.
+/// If the element is a slot that was not filled, the Err variant is returned.
+///
+/// `element` : The element to write.
+/// `writer` : The writer.
+fn write_preformatted(
+    element: &Element,
+    mut writer: std::io::BufWriter<std::fs::File>,
+) -> HtmlResult<std::io::BufWriter<std::fs::File>> {
+    match element {
+        Element::Text(text) => writer.write_all(text.as_bytes())?,
+        Element::Element(html_element) => {
+            writer.write_all(format!("<{}", html_element.tag.get_tag_name()).as_bytes())?;
+            writer = html_element.config.write_html(writer, 0)?;
+            if html_element.children.is_empty() && html_element.tag.is_auto_closing() {
+                writer.write_all(" />".as_bytes())?;
+                return Ok(writer);
+            }
+            writer.write_all(">".as_bytes())?;
+            for (index, child) in html_element.children.iter().enumerate() {
+                writer = write_preformatted(child, writer)
+                    .map_err(|error| error.inside(html_element.tag.get_tag_name(), index))?;
+            }
+            writer.write_all(format!("</{}>", html_element.tag.get_tag_name()).as_bytes())?;
+        }
+        Element::Slot(name) => return Err(TemplateError::MissingSlot(name.clone()).into()),
+    }
+    Ok(writer)
+}
+
 impl Html for HtmlElementConfig {
     /// Writes the Html element's config in Html format onto the given writer.
     /// The configs are written in an xml-attribute format.
@@ -479,9 +639,9 @@ impl Html for HtmlElementConfig {
         &self,
         mut writer: std::io::BufWriter<std::fs::File>,
         _tabs: usize,
-    ) -> std::io::Result<std::io::BufWriter<std::fs::File>> {
-        if self.classes.len() > 0 {
-            writer.write(
+    ) -> HtmlResult<std::io::BufWriter<std::fs::File>> {
+        if !self.classes.is_empty() {
+            writer.write_all(
                 format!(
                     " class=\"{}\"",
                     self.classes
@@ -494,12 +654,14 @@ impl Html for HtmlElementConfig {
             )?;
         }
         if let Some(id) = &self.id {
-            writer.write(format!(" id=\"{}\"", id).as_bytes())?;
+            writer.write_all(format!(" id=\"{}\"", id).as_bytes())?;
         }
         for (key, value) in self.attributes.iter() {
             match value {
-                Some(content) => writer.write(format!(" {}=\"{}\"", key, content).as_bytes())?,
-                None => writer.write(format!(" {}", key).as_bytes())?,
+                Some(content) => {
+                    writer.write_all(format!(" {}=\"{}\"", key, content).as_bytes())?
+                }
+                None => writer.write_all(format!(" {}", key).as_bytes())?,
             };
         }
 
@@ -512,6 +674,7 @@ impl PartialEq for Element {
         match (self, other) {
             (Self::Text(l0), Self::Text(r0)) => l0 == r0,
             (Self::Element(l0), Self::Element(r0)) => l0 == r0,
+            (Self::Slot(l0), Self::Slot(r0)) => l0 == r0,
             _ => false,
         }
     }
@@ -541,6 +704,8 @@ impl PartialEq for HtmlElementConfig {
 }
 
 impl AddAssign for Element {
+    /// Convenience operator adding the sub-element to the element.
+    /// Panics if the element can not contain another child, prefer `Element::try_push` to handle this case.
     fn add_assign(&mut self, rhs: Self) {
         if !self.can_add_child() {
             panic!("Can not add child to {:?}", self)
@@ -550,6 +715,8 @@ impl AddAssign for Element {
 }
 
 impl AddAssign for HtmlElement {
+    /// Convenience operator adding the sub-element to the Html element.
+    /// Panics if the Html element can not contain another child, prefer `HtmlElement::try_push` to handle this case.
     fn add_assign(&mut self, rhs: Self) {
         if !self.can_add_child() {
             panic!("Can not add child to {:?}", self)
@@ -561,9 +728,11 @@ impl AddAssign for HtmlElement {
 impl Add for Element {
     type Output = Element;
 
+    /// Convenience operator adding the sub-element to the element.
+    /// Panics if the element can not contain another child, prefer `Element::checked_add` to handle this case.
     fn add(self, rhs: Self) -> Self::Output {
         match self {
-            Element::Text(_) => panic!("Can not add child to {:?}", self),
+            Element::Text(_) | Element::Slot(_) => panic!("Can not add child to {:?}", self),
             Element::Element(html_element) => {
                 Element::Element(html_element.add_child(rhs).unwrap())
             }
@@ -571,8 +740,249 @@ impl Add for Element {
     }
 }
 
+impl Add<Element> for ElementResult {
+    type Output = ElementResult;
+
+    /// Checked `+` operator, allowing to chain fallible additions : `Ok(div) + label + input`.
+    /// The first error encountered is propagated.
+    fn add(self, rhs: Element) -> Self::Output {
+        self?.checked_add(rhs)
+    }
+}
+
+/// Internal function. Serializes the css classes in alphabetical order for a stable output.
+#[cfg(feature = "serde")]
+fn serialize_sorted_classes<S>(classes: &HashSet<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_seq(
+        classes
+            .iter()
+            .collect::<std::collections::BTreeSet<&String>>(),
+    )
+}
+
+/// Internal function. Serializes the attributes in alphabetical order for a stable output.
+#[cfg(feature = "serde")]
+fn serialize_sorted_attributes<S>(
+    attributes: &HashMap<String, Option<String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_map(
+        attributes
+            .iter()
+            .collect::<std::collections::BTreeMap<&String, &Option<String>>>(),
+    )
+}
+
+/// Internal function. Serializes a slot as `{"slot": name}`.
+#[cfg(feature = "serde")]
+fn serialize_slot<S>(name: &String, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeMap;
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry("slot", name)?;
+    map.end()
+}
+
+/// Internal function. Deserializes a slot from `{"slot": name}`.
+#[cfg(feature = "serde")]
+fn deserialize_slot<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Slot {
+        slot: String,
+    }
+    <Slot as serde::Deserialize>::deserialize(deserializer).map(|slot| slot.slot)
+}
+
 impl From<&str> for Element {
     fn from(value: &str) -> Self {
         Element::Text(value.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::{Error, TreePath};
+
+    fn element(tag: TagType) -> Element {
+        Element::Element(HtmlElement::new(tag, HtmlElementConfig::new_empty()))
+    }
+
+    fn children_count(element: &Element) -> usize {
+        match element {
+            Element::Element(element) => element.children.len(),
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn try_push_adds_the_child() {
+        let mut div = element(TagType::Div);
+        div.try_push(Element::Text("a".to_string())).unwrap();
+        div.try_push(element(TagType::P)).unwrap();
+        assert_eq!(children_count(&div), 2);
+    }
+
+    #[test]
+    fn try_push_rejects_invalid_parents() {
+        let mut text = Element::Text("a".to_string());
+        assert!(matches!(
+            text.try_push(element(TagType::P)),
+            Err(ElementError::TextContent)
+        ));
+        let mut slot = Element::Slot("content".to_string());
+        assert!(matches!(
+            slot.try_push(element(TagType::P)),
+            Err(ElementError::SlotContent(name)) if name == "content"
+        ));
+        let mut br = element(TagType::Br);
+        assert!(matches!(
+            br.try_push(Element::Text("a".to_string())),
+            Err(ElementError::InvalidContent(TagType::Br))
+        ));
+        assert_eq!(children_count(&br), 0);
+    }
+
+    #[test]
+    fn try_push_respects_single_child_tags() {
+        let mut title = element(TagType::Title);
+        title.try_push(Element::Text("a".to_string())).unwrap();
+        assert!(matches!(
+            title.try_push(Element::Text("b".to_string())),
+            Err(ElementError::InvalidContent(TagType::Title))
+        ));
+        assert_eq!(children_count(&title), 1);
+    }
+
+    #[test]
+    fn try_extend_leaves_the_element_untouched_on_error() {
+        let mut title = element(TagType::Title);
+        let children = vec![
+            Element::Text("a".to_string()),
+            Element::Text("b".to_string()),
+        ];
+        assert!(title.try_extend(children).is_err());
+        assert_eq!(children_count(&title), 0);
+
+        let mut list = element(TagType::Ul);
+        list.try_extend((0..3).map(|_| element(TagType::Li)))
+            .unwrap();
+        assert_eq!(children_count(&list), 3);
+        list.try_extend(vec![]).unwrap();
+        assert_eq!(children_count(&list), 3);
+        let mut input = element(TagType::Input);
+        input.try_extend(vec![]).unwrap();
+    }
+
+    #[test]
+    fn checked_add_returns_the_updated_element() {
+        let div = element(TagType::Div)
+            .checked_add(element(TagType::P))
+            .unwrap();
+        assert_eq!(children_count(&div), 1);
+        assert!(element(TagType::Hr)
+            .checked_add(element(TagType::P))
+            .is_err());
+    }
+
+    #[test]
+    fn errors_record_their_path_in_the_tree() {
+        let error = Error::from(ElementError::InvalidContent(TagType::Br))
+            .inside("tbody".to_string(), 0)
+            .inside("table".to_string(), 2)
+            .inside("body".to_string(), 1);
+        let path = error.path().unwrap();
+        assert_eq!(
+            *path,
+            TreePath {
+                steps: vec![
+                    ("body".to_string(), 1),
+                    ("table".to_string(), 2),
+                    ("tbody".to_string(), 0),
+                ],
+            }
+        );
+        assert_eq!(path.to_string(), "body[1] > table[2] > tbody[0]");
+        assert!(matches!(
+            std::error::Error::source(&error).and_then(|source| source.downcast_ref::<Error>()),
+            Some(Error::Element(ElementError::InvalidContent(TagType::Br)))
+        ));
+        assert!(Error::from(ElementError::TextContent).path().is_none());
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    fn tree() -> Element {
+        let link = HtmlElement::new(
+            TagType::A,
+            HtmlElementConfig::new_empty()
+                .with_class("link".to_string())
+                .with_class("active".to_string())
+                .with_id("home".to_string())
+                .set_attribute("href".to_string(), Some("/".to_string()))
+                .set_attribute("download".to_string(), None),
+        );
+        Element::Element(HtmlElement::new(
+            TagType::Div,
+            HtmlElementConfig::new_empty(),
+        ))
+        .checked_add(
+            Element::Element(link)
+                .checked_add(Element::Text("Home".to_string()))
+                .unwrap(),
+        )
+        .unwrap()
+        .checked_add(Element::Slot("sidebar".to_string()))
+        .unwrap()
+    }
+
+    #[test]
+    fn tree_has_the_documented_json_shape() {
+        let json = serde_json::to_string(&tree()).unwrap();
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"tag":"div","children":["#,
+                r#"{"tag":"a","classes":["active","link"],"id":"home","#,
+                r#""attributes":{"download":null,"href":"/"},"children":["Home"]},"#,
+                r#"{"slot":"sidebar"}]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn tree_survives_a_round_trip() {
+        let tree = tree();
+        let json = serde_json::to_string(&tree).unwrap();
+        let restored: Element = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, tree);
+        let Element::Element(div) = restored else {
+            panic!("the root is not an html element");
+        };
+        assert!(matches!(div.children[1].as_ref(), Element::Slot(name) if name == "sidebar"));
+    }
+
+    #[test]
+    fn unknown_tag_names_are_rejected() {
+        let error = serde_json::from_str::<TagType>(r#""blink""#).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("<blink> is not a known Html tag."));
+        assert!(serde_json::from_str::<Element>(r#"{"tag":"blink"}"#).is_err());
+    }
+}
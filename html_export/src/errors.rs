@@ -7,6 +7,49 @@ use crate::{
 
 pub type WrapResult = Result<Element, WrapElementError>;
 pub type CreateElementResult = Result<HtmlElement, ElementError>;
+pub type ElementResult = Result<Element, ElementError>;
+pub type HtmlResult<T> = Result<T, Error>;
+
+#[derive(Debug)]
+/// Defines the crate's errors.
+/// All the crate's specific errors can be converted into this type, which allows using `?`.
+pub enum Error {
+    /// Error when building Html elements.
+    Element(ElementError),
+    /// Error when wrapping Html elements.
+    Wrap(WrapElementError),
+    /// Error when creating form fields.
+    FieldCreation(FieldCreationError),
+    /// Error when creating the html document's `<head>`.
+    HeadCreation(HeadCreationError),
+    /// Error when filling a template.
+    Template(TemplateError),
+    /// Error when rendering a layout.
+    Layout(LayoutError),
+    /// Error when exporting a site.
+    Site(SiteError),
+    /// Error when registering the documents assets.
+    Asset(AssetError),
+    /// Error when loading or using the message catalogs.
+    I18n(I18nError),
+    /// Error when securing a document with a Content-Security-Policy.
+    Csp(CspError),
+    /// Error when computing or checking the subresource integrity hashes.
+    Integrity(IntegrityError),
+    /// Error when writing the html document.
+    Io(std::io::Error),
+    /// Error that occured inside the Html tree, at the given path.
+    InTree(TreePath, Box<Error>),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Locates an element in the Html tree.
+/// Each step is a parent's tag name and the index of the sub-element in this parent.
+/// The path is displayed as `body[1] > table[2] > tbody[0]` : the first sub-element of the `<tbody>`
+/// which is the third sub-element of the `<table>` which is the second sub-element of the `<body>`.
+pub struct TreePath {
+    pub steps: Vec<(String, usize)>,
+}
 
 #[derive(Debug)]
 /// Defines Html element's related errors.
@@ -15,6 +58,12 @@ pub enum ElementError {
     InvalidContent(TagType),
     /// Error when indexing sub-element to Html elements.
     InvalidIndex(usize, usize),
+    /// Error when adding a sub-element to a raw text element.
+    TextContent,
+    /// Error when parsing an unknown Html tag name.
+    UnknownTag(String),
+    /// Error when adding a sub-element to a slot.
+    SlotContent(String),
 }
 
 #[derive(Debug)]
@@ -33,34 +82,156 @@ pub enum WrapElementError {
 pub enum FieldCreationError {
     /// Error when defining which element is checked. The given index is invalid.
     SetCheckedInvalidIndex(usize, usize),
+    /// Error when assembling the field's html elements.
+    InvalidElement(ElementError),
+    /// Error when building the input of a form field (field's name, cause).
+    InvalidField(String, Box<Error>),
+    /// Error when an item has no form field (item's type).
+    MissingField(String),
 }
 
 #[derive(Debug)]
 /// The `<head>` tag errors.
 pub enum HeadCreationError {
     /// Error when adding an html incompatible hmlt tag.
-    InvalidContent(Element),
+    InvalidContent(Box<Element>),
+    /// Error when a metadata property required by the given protocol is missing (protocol, property).
+    MissingProperty(String, String),
+    /// Error when a head entry that must be unique has two different values (entry, first value, second value).
+    Conflict(String, String, String),
+}
+
+#[derive(Debug)]
+/// Defines the template filling errors.
+pub enum TemplateError {
+    /// Error when a slot of the template has no content.
+    MissingSlot(String),
+    /// Error when a slot content is given for a slot that is not part of the template.
+    UnknownSlot(String),
+    /// Error when a text placeholder of the template has no value.
+    MissingValue(String),
+    /// Error when a value is given for a placeholder that is not part of the template.
+    UnknownValue(String),
+    /// Error when a text contains an empty or unclosed placeholder.
+    InvalidPlaceholder(String),
+}
+
+#[derive(Debug)]
+/// Defines the layout rendering errors.
+pub enum LayoutError {
+    /// Error when a layout defines a block that is not part of the base layout.
+    UnknownBlock(String),
+    /// Error when the `title` block contains other elements than raw text.
+    InvalidTitle,
+}
+
+#[derive(Debug)]
+/// Defines the site export errors.
+pub enum SiteError {
+    /// Error when a route contains an empty, `.` or `..` segment.
+    InvalidRoute(String),
+    /// Error when two routes map to the same file path : the path and both routes.
+    DuplicatePath(String, String, String),
+    /// Error when a page links to an unknown route : the page's path and the route.
+    UnknownRoute(String, String),
+}
+
+#[derive(Debug)]
+/// Defines the asset registration errors.
+pub enum AssetError {
+    /// Error when a referenced file can not be read : the file's path and the document or css file referencing it.
+    MissingFile(String, Option<String>),
+    /// Error when a reference leaves the source folder.
+    InvalidPath(String),
+    /// Error when css files reference each other.
+    Cycle(String),
+}
+
+#[derive(Debug)]
+/// Defines the localization errors.
+pub enum I18nError {
+    /// Error when a catalog file can not be read.
+    MissingFile(String),
+    /// Error when a catalog line is not a `key = message` entry : the file's path and the line number.
+    InvalidLine(String, usize),
+    /// Error when no catalog of the locale chain defines the message : the locale and the message key.
+    MissingMessage(String, String),
+    /// Error when a message uses an argument that is not given : the message key and the argument name.
+    MissingArgument(String, String),
+}
+
+#[derive(Debug)]
+/// Defines the Content-Security-Policy errors.
+pub enum CspError {
+    /// Error when an element has an inline event handler blocked by the policy (tag, attribute).
+    InlineHandler(String, String),
+    /// Error when an element has a `javascript:` url blocked by the policy (tag, attribute).
+    JavascriptUrl(String, String),
+    /// Error when the operating system's random generator can not provide the nonce.
+    UnavailableRandomness(String),
+}
+
+#[derive(Debug)]
+/// Defines the subresource integrity errors.
+pub enum IntegrityError {
+    /// Error when a referenced local file can not be read.
+    MissingFile(String),
+    /// Error when a local file has changed since its hash was recorded : the file's path, the recorded and the computed hashes.
+    ChangedFile(String, String, String),
+    /// Error when a line of a hashes file is not a `path hash` entry : the file's path and the line number.
+    InvalidLine(String, usize),
+}
+
+impl Error {
+    /// Records that the error occured inside the sub-element at `index` of a `tag` element.
+    /// Successive calls build the path from the deepest element to the root.
+    ///
+    /// - `tag` : The parent's tag name.
+    /// - `index` : The index of the sub-element in its parent.
+    pub fn inside(self, tag: String, index: usize) -> Self {
+        match self {
+            Error::InTree(mut path, source) => {
+                path.steps.insert(0, (tag, index));
+                Error::InTree(path, source)
+            }
+            error => Error::InTree(
+                TreePath {
+                    steps: vec![(tag, index)],
+                },
+                Box::new(error),
+            ),
+        }
+    }
+
+    /// Returns the path in the Html tree where the error occured.
+    /// If the error is not related to a specific element, the function returns None.
+    pub fn path(&self) -> Option<&TreePath> {
+        match self {
+            Error::InTree(path, _) => Some(path),
+            _ => None,
+        }
+    }
 }
 
 impl Display for WrapElementError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             WrapElementError::NonWrapperElement(tag_type) => {
-                writeln!(
+                write!(
                     f,
                     "Element Tag=<{}> can not wrap another element.",
                     tag_type.get_tag_name()
                 )
             }
             WrapElementError::SingleChildExceeded(tag_type) => {
-                writeln!(
+                write!(
                     f,
                     "Element Tag=<{}> can have at most 1 child element.",
                     tag_type.get_tag_name()
                 )
             }
             WrapElementError::InvalidIndex(index, size) => {
-                writeln!(
+                write!(
                     f,
                     "Attempted to insert a child element at position {} but the container had {} child-elements.",
                     index,
@@ -100,6 +271,11 @@ impl Display for ElementError {
                 "Could not remove element at index {} from the collection of size {}.",
                 index, size
             ),
+            ElementError::TextContent => write!(f, "A raw text element can not have sub-elements."),
+            ElementError::UnknownTag(name) => write!(f, "<{}> is not a known Html tag.", name),
+            ElementError::SlotContent(name) => {
+                write!(f, "The slot `{}` can not have sub-elements.", name)
+            }
         }
     }
 }
@@ -112,10 +288,25 @@ impl Display for FieldCreationError {
                 "Can not set element checked at index {} for a list of size {}",
                 index, size
             ),
+            FieldCreationError::InvalidElement(_) => {
+                write!(f, "Could not assemble the field's html elements.")
+            }
+            FieldCreationError::InvalidField(name, _) => {
+                write!(f, "Could not build the input of the form field {}.", name)
+            }
+            FieldCreationError::MissingField(item) => {
+                write!(f, "The item {} has no form field.", item)
+            }
         }
     }
 }
 
+impl From<ElementError> for FieldCreationError {
+    fn from(value: ElementError) -> Self {
+        FieldCreationError::InvalidElement(value)
+    }
+}
+
 impl Display for HeadCreationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -126,6 +317,311 @@ impl Display for HeadCreationError {
                     element
                 )
             }
+            HeadCreationError::MissingProperty(protocol, property) => write!(
+                f,
+                "The {} metadata requires the `{}` property.",
+                protocol, property
+            ),
+            HeadCreationError::Conflict(entry, first, second) => write!(
+                f,
+                "The head can only contain one {}, but `{}` and `{}` are given.",
+                entry, first, second
+            ),
+        }
+    }
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::MissingSlot(name) => write!(f, "The slot `{}` is not filled.", name),
+            TemplateError::UnknownSlot(name) => {
+                write!(f, "The template has no slot named `{}`.", name)
+            }
+            TemplateError::MissingValue(name) => {
+                write!(f, "The placeholder `{{{}}}` has no value.", name)
+            }
+            TemplateError::UnknownValue(name) => {
+                write!(f, "The template has no placeholder named `{{{}}}`.", name)
+            }
+            TemplateError::InvalidPlaceholder(text) => {
+                write!(f, "The text `{}` contains an invalid placeholder.", text)
+            }
+        }
+    }
+}
+
+impl Display for LayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LayoutError::UnknownBlock(name) => {
+                write!(f, "The base layout has no block named `{}`.", name)
+            }
+            LayoutError::InvalidTitle => {
+                write!(f, "The `title` block can only contain raw text.")
+            }
+        }
+    }
+}
+
+impl Display for SiteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SiteError::InvalidRoute(route) => write!(f, "The route `{}` is invalid.", route),
+            SiteError::DuplicatePath(path, first, second) => write!(
+                f,
+                "The routes `{}` and `{}` are both written to `{}`.",
+                first, second, path
+            ),
+            SiteError::UnknownRoute(path, route) => {
+                write!(
+                    f,
+                    "The page `{}` links to the unknown route `{}`.",
+                    path, route
+                )
+            }
+        }
+    }
+}
+
+impl Display for AssetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssetError::MissingFile(file, Some(referrer)) => write!(
+                f,
+                "The file `{}` referenced by `{}` can not be read.",
+                file, referrer
+            ),
+            AssetError::MissingFile(file, None) => {
+                write!(f, "The file `{}` can not be read.", file)
+            }
+            AssetError::InvalidPath(reference) => write!(
+                f,
+                "The reference `{}` leaves the assets source folder.",
+                reference
+            ),
+            AssetError::Cycle(file) => {
+                write!(f, "The css file `{}` references itself.", file)
+            }
+        }
+    }
+}
+
+impl Display for I18nError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            I18nError::MissingFile(file) => {
+                write!(f, "The catalog file `{}` can not be read.", file)
+            }
+            I18nError::InvalidLine(file, line) => write!(
+                f,
+                "The line {} of the catalog file `{}` is not a `key = message` entry.",
+                line, file
+            ),
+            I18nError::MissingMessage(locale, key) => write!(
+                f,
+                "The message `{}` is not defined for the locale `{}`.",
+                key, locale
+            ),
+            I18nError::MissingArgument(key, argument) => write!(
+                f,
+                "The message `{}` uses the argument `{}` which is not given.",
+                key, argument
+            ),
         }
     }
 }
+
+impl Display for CspError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CspError::InlineHandler(tag, attribute) => write!(
+                f,
+                "The `{}` attribute of the <{}> tag is an inline event handler blocked by the Content-Security-Policy.",
+                attribute, tag
+            ),
+            CspError::JavascriptUrl(tag, attribute) => write!(
+                f,
+                "The `{}` attribute of the <{}> tag is a `javascript:` url blocked by the Content-Security-Policy.",
+                attribute, tag
+            ),
+            CspError::UnavailableRandomness(error) => {
+                write!(f, "The nonce can not be generated : {}.", error)
+            }
+        }
+    }
+}
+
+impl Display for IntegrityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntegrityError::MissingFile(file) => {
+                write!(f, "The file `{}` can not be read to compute its hash.", file)
+            }
+            IntegrityError::ChangedFile(file, recorded, computed) => write!(
+                f,
+                "The file `{}` has changed since its hash was recorded : `{}` was expected, `{}` was computed.",
+                file, recorded, computed
+            ),
+            IntegrityError::InvalidLine(file, line) => write!(
+                f,
+                "The line {} of the hashes file `{}` is not a `path hash` entry.",
+                line, file
+            ),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Element(error) => write!(f, "{}", error),
+            Error::Wrap(error) => write!(f, "{}", error),
+            Error::FieldCreation(error) => write!(f, "{}", error),
+            Error::HeadCreation(error) => write!(f, "{}", error),
+            Error::Template(error) => write!(f, "{}", error),
+            Error::Layout(error) => write!(f, "{}", error),
+            Error::Site(error) => write!(f, "{}", error),
+            Error::Asset(error) => write!(f, "{}", error),
+            Error::I18n(error) => write!(f, "{}", error),
+            Error::Csp(error) => write!(f, "{}", error),
+            Error::Integrity(error) => write!(f, "{}", error),
+            Error::Io(_) => write!(f, "Could not write the html document."),
+            Error::InTree(path, _) => write!(f, "Error in the html tree at `{}`.", path),
+        }
+    }
+}
+
+impl Display for TreePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let steps = self
+            .steps
+            .iter()
+            .map(|(tag, index)| format!("{}[{}]", tag, index))
+            .collect::<Vec<String>>();
+        write!(f, "{}", steps.join(" > "))
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Element(error) => error.source(),
+            Error::Wrap(error) => error.source(),
+            Error::FieldCreation(error) => error.source(),
+            Error::HeadCreation(error) => error.source(),
+            Error::Template(error) => error.source(),
+            Error::Layout(error) => error.source(),
+            Error::Site(error) => error.source(),
+            Error::Asset(error) => error.source(),
+            Error::I18n(error) => error.source(),
+            Error::Csp(error) => error.source(),
+            Error::Integrity(error) => error.source(),
+            Error::Io(error) => Some(error),
+            Error::InTree(_, error) => Some(error.as_ref()),
+        }
+    }
+}
+
+impl std::error::Error for ElementError {}
+
+impl std::error::Error for WrapElementError {}
+
+impl std::error::Error for FieldCreationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FieldCreationError::SetCheckedInvalidIndex(_, _) => None,
+            FieldCreationError::InvalidElement(error) => Some(error),
+            FieldCreationError::InvalidField(_, error) => Some(error.as_ref()),
+            FieldCreationError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::error::Error for HeadCreationError {}
+
+impl std::error::Error for TemplateError {}
+
+impl std::error::Error for LayoutError {}
+
+impl std::error::Error for SiteError {}
+
+impl std::error::Error for AssetError {}
+
+impl std::error::Error for I18nError {}
+
+impl std::error::Error for CspError {}
+
+impl std::error::Error for IntegrityError {}
+
+impl From<ElementError> for Error {
+    fn from(value: ElementError) -> Self {
+        Error::Element(value)
+    }
+}
+
+impl From<WrapElementError> for Error {
+    fn from(value: WrapElementError) -> Self {
+        Error::Wrap(value)
+    }
+}
+
+impl From<FieldCreationError> for Error {
+    fn from(value: FieldCreationError) -> Self {
+        Error::FieldCreation(value)
+    }
+}
+
+impl From<HeadCreationError> for Error {
+    fn from(value: HeadCreationError) -> Self {
+        Error::HeadCreation(value)
+    }
+}
+
+impl From<TemplateError> for Error {
+    fn from(value: TemplateError) -> Self {
+        Error::Template(value)
+    }
+}
+
+impl From<LayoutError> for Error {
+    fn from(value: LayoutError) -> Self {
+        Error::Layout(value)
+    }
+}
+
+impl From<SiteError> for Error {
+    fn from(value: SiteError) -> Self {
+        Error::Site(value)
+    }
+}
+
+impl From<AssetError> for Error {
+    fn from(value: AssetError) -> Self {
+        Error::Asset(value)
+    }
+}
+
+impl From<I18nError> for Error {
+    fn from(value: I18nError) -> Self {
+        Error::I18n(value)
+    }
+}
+
+impl From<CspError> for Error {
+    fn from(value: CspError) -> Self {
+        Error::Csp(value)
+    }
+}
+
+impl From<IntegrityError> for Error {
+    fn from(value: IntegrityError) -> Self {
+        Error::Integrity(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::Io(value)
+    }
+}
@@ -0,0 +1,738 @@
+use std::fmt::Display;
+
+use crate::{
+    element::{Element, HtmlElement, HtmlElementConfig},
+    errors::HtmlResult,
+    i18n::{normalize_locale, PluralCategory},
+    miscellaneous::escape_html,
+    tags::TagType,
+};
+
+/// The narrow no-break space, used as group separator and before the percent sign in french.
+const NARROW_NO_BREAK_SPACE: &str = "\u{202f}";
+
+/// The no-break space, placed between a value and its unit or currency.
+const NO_BREAK_SPACE: &str = "\u{a0}";
+
+/// Represents a calendar date.
+///
+/// - `year` : The year.
+/// - `month` : The month, from 1 to 12.
+/// - `day` : The day of the month, from 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+/// Represents a time of the day.
+///
+/// - `hour` : The hour, from 0 to 23.
+/// - `minute` : The minute, from 0 to 59.
+/// - `second` : The second, from 0 to 59.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Time {
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+/// The date formats.
+///
+/// - `Short` : Numeric date (`3/14/2024`, `14/03/2024`).
+/// - `Medium` : Date with an abbreviated month (`Mar 14, 2024`, `14 mars 2024`).
+/// - `Long` : Date with the full month name (`March 14, 2024`, `14 mars 2024`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DateStyle {
+    Short,
+    Medium,
+    Long,
+}
+
+/// Formats numbers, currencies, dates, times and durations for a locale.
+/// The separators and patterns of the english, french, german, spanish, italian, portuguese, dutch,
+/// russian, japanese and chinese locales are supported, the other locales use the english ones.
+/// The month names and the durations are translated in english, french, german and spanish.
+///
+/// Each function returning a text has an `_element` variant, wrapping the text in a `<data value>`
+/// or `<time datetime>` element holding the machine-readable value.
+///
+/// - `locale` : The locale (`en-US`, `fr`...).
+#[derive(Clone, Debug)]
+pub struct Formatter {
+    pub locale: String,
+}
+
+/// Internal struct. The number symbols of a locale.
+///
+/// - `group` : The thousands separator.
+/// - `decimal` : The decimal separator.
+/// - `percent_space` : The separator between a number and the percent sign.
+/// - `currency_before` : If true, the currency symbol is placed before the amount.
+/// - `currency_space` : The separator between the amount and the currency symbol.
+struct NumberSymbols {
+    group: &'static str,
+    decimal: &'static str,
+    percent_space: &'static str,
+    currency_before: bool,
+    currency_space: &'static str,
+}
+
+impl Date {
+    /// Creates a date. If the date does not exist, None is returned.
+    ///
+    /// - `year` : The year.
+    /// - `month` : The month, from 1 to 12.
+    /// - `day` : The day of the month, from 1.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=days)
+            .contains(&day)
+            .then_some(Self { year, month, day })
+    }
+
+    /// Returns the ISO 8601 date (`2024-03-14`), used in the `datetime` attribute.
+    pub fn to_iso(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl Time {
+    /// Creates a time. If the time does not exist, None is returned.
+    ///
+    /// - `hour` : The hour, from 0 to 23.
+    /// - `minute` : The minute, from 0 to 59.
+    /// - `second` : The second, from 0 to 59.
+    pub fn new(hour: u32, minute: u32, second: u32) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60).then_some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// Returns the ISO 8601 time (`14:05`, `14:05:09`), used in the `datetime` attribute.
+    pub fn to_iso(&self) -> String {
+        if self.second == 0 {
+            format!("{:02}:{:02}", self.hour, self.minute)
+        } else {
+            format!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+        }
+    }
+}
+
+impl Formatter {
+    /// Creates a formatter.
+    ///
+    /// - `locale` : The locale.
+    pub fn new(locale: &str) -> Self {
+        Self {
+            locale: normalize_locale(locale),
+        }
+    }
+
+    /// Formats a number with grouped thousands (`1,234.57`, `1 234,57`).
+    ///
+    /// - `value` : The number.
+    /// - `decimals` : The amount of decimals, the number is rounded half away from zero.
+    pub fn number(&self, value: f64, decimals: usize) -> String {
+        let symbols = self.symbols();
+        let scale = 10f64.powi(decimals as i32);
+        let rounded = format!("{:.*}", decimals, (value.abs() * scale).round() / scale);
+        let (integer, fraction) = match rounded.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (rounded.as_str(), None),
+        };
+        let mut formatted = String::new();
+        if value < 0.0 && rounded.chars().any(|c| c != '0' && c != '.') {
+            formatted.push('-');
+        }
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                formatted.push_str(symbols.group);
+            }
+            formatted.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            formatted.push_str(symbols.decimal);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    /// Formats a ratio as a percentage (`0.125` gives `12.5%`, `12,5 %`).
+    ///
+    /// - `ratio` : The ratio, 1 being 100 %.
+    /// - `decimals` : The amount of decimals of the percentage.
+    pub fn percent(&self, ratio: f64, decimals: usize) -> String {
+        format!(
+            "{}{}%",
+            self.number(ratio * 100.0, decimals),
+            self.symbols().percent_space
+        )
+    }
+
+    /// Formats an amount of money with the currency's symbol and decimals (`$1,234.50`, `1 234,50 €`, `¥1,235`).
+    /// The currencies without known symbol are written with their code (`CHF 1,234.50`).
+    ///
+    /// - `amount` : The amount.
+    /// - `currency` : The ISO 4217 currency code (`USD`, `EUR`...).
+    pub fn currency(&self, amount: f64, currency: &str) -> String {
+        let symbols = self.symbols();
+        let number = self.number(amount.abs(), currency_decimals(currency));
+        let symbol = currency_symbol(currency);
+        let sign = if self
+            .number(amount, currency_decimals(currency))
+            .starts_with('-')
+        {
+            "-"
+        } else {
+            ""
+        };
+        let space = if symbol == currency && symbols.currency_before {
+            NO_BREAK_SPACE
+        } else {
+            symbols.currency_space
+        };
+        if symbols.currency_before {
+            format!("{}{}{}{}", sign, symbol, space, number)
+        } else {
+            format!("{}{}{}{}", sign, number, space, symbol)
+        }
+    }
+
+    /// Formats a value with its unit (`12.5 kg`).
+    ///
+    /// - `value` : The value.
+    /// - `decimals` : The amount of decimals.
+    /// - `unit` : The unit's symbol (`kg`, `km/h`...).
+    pub fn unit(&self, value: f64, decimals: usize, unit: &str) -> String {
+        format!("{}{}{}", self.number(value, decimals), NO_BREAK_SPACE, unit)
+    }
+
+    /// Formats a date (`March 14, 2024`, `14 mars 2024`, `14.03.2024`).
+    ///
+    /// - `date` : The date.
+    /// - `style` : The date format.
+    pub fn date(&self, date: &Date, style: DateStyle) -> String {
+        let language = self.language();
+        let us = self.locale == "en-US" || self.locale == "en";
+        let month_name = |abbreviated: bool| {
+            let names = month_names(&language, abbreviated);
+            names[date.month as usize - 1]
+        };
+        match (style, language.as_str()) {
+            (DateStyle::Short, "en") if us => {
+                format!("{}/{}/{}", date.month, date.day, date.year)
+            }
+            (DateStyle::Short, "en" | "fr" | "es" | "it" | "pt") => {
+                format!("{:02}/{:02}/{}", date.day, date.month, date.year)
+            }
+            (DateStyle::Short, "de" | "ru") => {
+                format!("{:02}.{:02}.{}", date.day, date.month, date.year)
+            }
+            (DateStyle::Short, "nl") => format!("{:02}-{:02}-{}", date.day, date.month, date.year),
+            (DateStyle::Short, "ja" | "zh") => {
+                format!("{}/{:02}/{:02}", date.year, date.month, date.day)
+            }
+            (DateStyle::Short, _) => date.to_iso(),
+            (_, "en") if us => format!(
+                "{} {}, {}",
+                month_name(style == DateStyle::Medium),
+                date.day,
+                date.year
+            ),
+            (_, "de") => format!(
+                "{}. {} {}",
+                date.day,
+                month_name(style == DateStyle::Medium),
+                date.year
+            ),
+            (DateStyle::Long, "es") => {
+                format!("{} de {} de {}", date.day, month_name(false), date.year)
+            }
+            (_, _) => format!(
+                "{} {} {}",
+                date.day,
+                month_name(style == DateStyle::Medium),
+                date.year
+            ),
+        }
+    }
+
+    /// Formats a time, with a 12-hour clock for american english (`2:05 PM`) and a 24-hour clock otherwise (`14:05`).
+    /// The seconds are written if they are not zero.
+    ///
+    /// - `time` : The time.
+    pub fn time(&self, time: &Time) -> String {
+        let seconds = if time.second == 0 {
+            String::new()
+        } else {
+            format!(":{:02}", time.second)
+        };
+        if self.locale == "en-US" || self.locale == "en" {
+            let hour = match time.hour % 12 {
+                0 => 12,
+                hour => hour,
+            };
+            let period = if time.hour < 12 { "AM" } else { "PM" };
+            format!("{}:{:02}{} {}", hour, time.minute, seconds, period)
+        } else {
+            format!("{:02}:{:02}{}", time.hour, time.minute, seconds)
+        }
+    }
+
+    /// Formats a date and a time (`March 14, 2024 2:05 PM`).
+    ///
+    /// - `date` : The date.
+    /// - `time` : The time.
+    /// - `style` : The date format.
+    pub fn date_time(&self, date: &Date, time: &Time, style: DateStyle) -> String {
+        format!("{} {}", self.date(date, style), self.time(time))
+    }
+
+    /// Formats a duration relative to now, in the largest fitting unit (`in 3 days`, `il y a 2 heures`).
+    /// A month counts 30 days and a year 365 days.
+    ///
+    /// - `seconds` : The duration in seconds, positive in the future and negative in the past.
+    pub fn relative_duration(&self, seconds: i64) -> String {
+        const UNITS: [(u64, usize); 7] = [
+            (365 * 86_400, 6),
+            (30 * 86_400, 5),
+            (7 * 86_400, 4),
+            (86_400, 3),
+            (3_600, 2),
+            (60, 1),
+            (1, 0),
+        ];
+        let absolute = seconds.unsigned_abs();
+        let (length, unit) = UNITS
+            .iter()
+            .find(|(length, _)| absolute >= *length)
+            .copied()
+            .unwrap_or((1, 0));
+        let count = absolute / length;
+        let language = self.language();
+        let rules = match language.as_str() {
+            "fr" | "de" | "es" => self.locale.as_str(),
+            _ => "en",
+        };
+        let plural = PluralCategory::for_count(rules, count) != PluralCategory::One;
+        let (one, other) = duration_unit(&language, unit);
+        let unit = if plural { other } else { one };
+        let amount = format!("{} {}", count, unit);
+        match (language.as_str(), seconds < 0) {
+            ("fr", true) => format!("il y a {}", amount),
+            ("fr", false) => format!("dans {}", amount),
+            ("de", true) => format!("vor {}", amount),
+            ("de", false) => format!("in {}", amount),
+            ("es", true) => format!("hace {}", amount),
+            ("es", false) => format!("dentro de {}", amount),
+            (_, true) => format!("{} ago", amount),
+            (_, false) => format!("in {}", amount),
+        }
+    }
+
+    /// Formats a number in a `<data value>` element, see `Formatter::number`.
+    /// If the element can not be built, the Err variant is returned.
+    ///
+    /// - `value` : The number.
+    /// - `decimals` : The amount of decimals.
+    pub fn number_element(&self, value: f64, decimals: usize) -> HtmlResult<Element> {
+        data_element(value, &self.number(value, decimals))
+    }
+
+    /// Formats a percentage in a `<data value>` element holding the ratio, see `Formatter::percent`.
+    /// If the element can not be built, the Err variant is returned.
+    ///
+    /// - `ratio` : The ratio, 1 being 100 %.
+    /// - `decimals` : The amount of decimals of the percentage.
+    pub fn percent_element(&self, ratio: f64, decimals: usize) -> HtmlResult<Element> {
+        data_element(ratio, &self.percent(ratio, decimals))
+    }
+
+    /// Formats an amount of money in a `<data value>` element, see `Formatter::currency`.
+    /// If the element can not be built, the Err variant is returned.
+    ///
+    /// - `amount` : The amount.
+    /// - `currency` : The ISO 4217 currency code.
+    pub fn currency_element(&self, amount: f64, currency: &str) -> HtmlResult<Element> {
+        data_element(amount, &self.currency(amount, currency))
+    }
+
+    /// Formats a value with its unit in a `<data value>` element, see `Formatter::unit`.
+    /// If the element can not be built, the Err variant is returned.
+    ///
+    /// - `value` : The value.
+    /// - `decimals` : The amount of decimals.
+    /// - `unit` : The unit's symbol.
+    pub fn unit_element(&self, value: f64, decimals: usize, unit: &str) -> HtmlResult<Element> {
+        data_element(value, &self.unit(value, decimals, unit))
+    }
+
+    /// Formats a date in a `<time datetime>` element, see `Formatter::date`.
+    /// If the element can not be built, the Err variant is returned.
+    ///
+    /// - `date` : The date.
+    /// - `style` : The date format.
+    pub fn date_element(&self, date: &Date, style: DateStyle) -> HtmlResult<Element> {
+        time_element(&date.to_iso(), &self.date(date, style))
+    }
+
+    /// Formats a time in a `<time datetime>` element, see `Formatter::time`.
+    /// If the element can not be built, the Err variant is returned.
+    ///
+    /// - `time` : The time.
+    pub fn time_element(&self, time: &Time) -> HtmlResult<Element> {
+        time_element(&time.to_iso(), &self.time(time))
+    }
+
+    /// Formats a date and a time in a `<time datetime>` element, see `Formatter::date_time`.
+    /// If the element can not be built, the Err variant is returned.
+    ///
+    /// - `date` : The date.
+    /// - `time` : The time.
+    /// - `style` : The date format.
+    pub fn date_time_element(
+        &self,
+        date: &Date,
+        time: &Time,
+        style: DateStyle,
+    ) -> HtmlResult<Element> {
+        time_element(
+            &format!("{}T{}", date.to_iso(), time.to_iso()),
+            &self.date_time(date, time, style),
+        )
+    }
+
+    /// Formats a relative duration in a `<time datetime>` element holding the duration (`PT7200S`),
+    /// see `Formatter::relative_duration`.
+    /// If the element can not be built, the Err variant is returned.
+    ///
+    /// - `seconds` : The duration in seconds, positive in the future and negative in the past.
+    pub fn relative_duration_element(&self, seconds: i64) -> HtmlResult<Element> {
+        time_element(
+            &format!("PT{}S", seconds.unsigned_abs()),
+            &self.relative_duration(seconds),
+        )
+    }
+
+    /// Internal function. Returns the lowercased language subtag of the locale.
+    fn language(&self) -> String {
+        self.locale
+            .split('-')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase()
+    }
+
+    /// Internal function. Returns the number symbols of the locale.
+    fn symbols(&self) -> NumberSymbols {
+        let (group, decimal, percent_space, currency_before, currency_space) =
+            match self.language().as_str() {
+                "fr" => (
+                    NARROW_NO_BREAK_SPACE,
+                    ",",
+                    NARROW_NO_BREAK_SPACE,
+                    false,
+                    NO_BREAK_SPACE,
+                ),
+                "de" | "es" => (".", ",", NO_BREAK_SPACE, false, NO_BREAK_SPACE),
+                "it" => (".", ",", "", false, NO_BREAK_SPACE),
+                "pt" => (".", ",", "", true, NO_BREAK_SPACE),
+                "nl" => (".", ",", "", true, NO_BREAK_SPACE),
+                "ru" => (NO_BREAK_SPACE, ",", NO_BREAK_SPACE, false, NO_BREAK_SPACE),
+                _ => (",", ".", "", true, ""),
+            };
+        NumberSymbols {
+            group,
+            decimal,
+            percent_space,
+            currency_before,
+            currency_space,
+        }
+    }
+}
+
+impl Default for Formatter {
+    fn default() -> Self {
+        Self::new("en")
+    }
+}
+
+/// Returns a `<data>` element holding a machine-readable value.
+/// If the element can not be built, the Err variant is returned.
+///
+/// - `value` : The machine-readable value.
+/// - `text` : The displayed text, escaped by the function.
+pub fn data_element<T>(value: T, text: &str) -> HtmlResult<Element>
+where
+    T: Display,
+{
+    Element::Element(HtmlElement::new(
+        TagType::Data,
+        HtmlElementConfig::new_empty()
+            .set_attribute("value".to_string(), Some(escape_html(&value.to_string()))),
+    ))
+    .checked_add(Element::Text(escape_html(text)))
+    .map_err(Into::into)
+}
+
+/// Returns a `<time>` element holding a machine-readable date, time or duration.
+/// If the element can not be built, the Err variant is returned.
+///
+/// - `datetime` : The ISO 8601 date, time or duration.
+/// - `text` : The displayed text, escaped by the function.
+pub fn time_element(datetime: &str, text: &str) -> HtmlResult<Element> {
+    Element::Element(HtmlElement::new(
+        TagType::Time,
+        HtmlElementConfig::new_empty()
+            .set_attribute("datetime".to_string(), Some(escape_html(datetime))),
+    ))
+    .checked_add(Element::Text(escape_html(text)))
+    .map_err(Into::into)
+}
+
+/// Internal function. Returns the symbol of a currency, or its code if the symbol is unknown.
+///
+/// - `currency` : The ISO 4217 currency code.
+fn currency_symbol(currency: &str) -> &str {
+    match currency {
+        "USD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" | "CNY" => "¥",
+        "INR" => "₹",
+        "KRW" => "₩",
+        "BRL" => "R$",
+        "RUB" => "₽",
+        _ => currency,
+    }
+}
+
+/// Internal function. Returns the amount of decimals of a currency.
+///
+/// - `currency` : The ISO 4217 currency code.
+fn currency_decimals(currency: &str) -> usize {
+    match currency {
+        "JPY" | "KRW" | "CLP" | "ISK" | "VND" => 0,
+        "BHD" | "KWD" | "OMR" | "TND" => 3,
+        _ => 2,
+    }
+}
+
+/// Internal function. Returns the month names of a language, english for the unsupported languages.
+///
+/// - `language` : The language subtag.
+/// - `abbreviated` : If true, the abbreviated names are returned.
+fn month_names(language: &str, abbreviated: bool) -> [&'static str; 12] {
+    match (language, abbreviated) {
+        ("fr", false) => [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        ("fr", true) => [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        ("de", false) => [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        ("de", true) => [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+        ("es", false) => [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        ("es", true) => [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        (_, false) => [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        (_, true) => [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+    }
+}
+
+/// Internal function. Returns the singular and plural names of a duration unit, english for the unsupported languages.
+/// The german names are in the dative case, used after `in` and `vor`.
+///
+/// - `language` : The language subtag.
+/// - `unit` : The unit, from 0 (second) to 6 (year).
+fn duration_unit(language: &str, unit: usize) -> (&'static str, &'static str) {
+    let units = match language {
+        "fr" => [
+            ("seconde", "secondes"),
+            ("minute", "minutes"),
+            ("heure", "heures"),
+            ("jour", "jours"),
+            ("semaine", "semaines"),
+            ("mois", "mois"),
+            ("an", "ans"),
+        ],
+        "de" => [
+            ("Sekunde", "Sekunden"),
+            ("Minute", "Minuten"),
+            ("Stunde", "Stunden"),
+            ("Tag", "Tagen"),
+            ("Woche", "Wochen"),
+            ("Monat", "Monaten"),
+            ("Jahr", "Jahren"),
+        ],
+        "es" => [
+            ("segundo", "segundos"),
+            ("minuto", "minutos"),
+            ("hora", "horas"),
+            ("día", "días"),
+            ("semana", "semanas"),
+            ("mes", "meses"),
+            ("año", "años"),
+        ],
+        _ => [
+            ("second", "seconds"),
+            ("minute", "minutes"),
+            ("hour", "hours"),
+            ("day", "days"),
+            ("week", "weeks"),
+            ("month", "months"),
+            ("year", "years"),
+        ],
+    };
+    units[unit]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_groups_thousands_with_the_locale_symbols() {
+        assert_eq!(Formatter::new("en").number(1234567.891, 2), "1,234,567.89");
+        assert_eq!(Formatter::new("de").number(1234.5, 1), "1.234,5");
+        assert_eq!(
+            Formatter::new("fr_FR").number(1234.5, 2),
+            format!("1{}234,50", NARROW_NO_BREAK_SPACE)
+        );
+        assert_eq!(Formatter::new("en").number(123.0, 0), "123");
+        assert_eq!(Formatter::new("en").number(123456.0, 0), "123,456");
+    }
+
+    #[test]
+    fn number_rounds_half_away_from_zero() {
+        let formatter = Formatter::default();
+        assert_eq!(formatter.number(2.5, 0), "3");
+        assert_eq!(formatter.number(-2.5, 0), "-3");
+        assert_eq!(formatter.number(0.125, 2), "0.13");
+        assert_eq!(formatter.number(999.996, 2), "1,000.00");
+    }
+
+    #[test]
+    fn negative_zero_has_no_sign() {
+        let formatter = Formatter::default();
+        assert_eq!(formatter.number(-0.004, 2), "0.00");
+        assert_eq!(formatter.currency(-0.001, "USD"), "$0.00");
+        assert_eq!(formatter.currency(-12.5, "USD"), "-$12.50");
+    }
+
+    #[test]
+    fn percent_and_currency_follow_the_locale() {
+        assert_eq!(Formatter::new("en").percent(0.125, 1), "12.5%");
+        assert_eq!(
+            Formatter::new("fr").percent(0.125, 1),
+            format!("12,5{}%", NARROW_NO_BREAK_SPACE)
+        );
+        assert_eq!(Formatter::new("en").currency(1234.5, "JPY"), "¥1,235");
+        assert_eq!(
+            Formatter::new("de").currency(1234.5, "EUR"),
+            format!("1.234,50{}€", NO_BREAK_SPACE)
+        );
+        assert_eq!(
+            Formatter::new("en").currency(1234.5, "CHF"),
+            format!("CHF{}1,234.50", NO_BREAK_SPACE)
+        );
+    }
+
+    #[test]
+    fn dates_and_times_are_validated_and_formatted() {
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Time::new(24, 0, 0).is_none());
+        let date = Date::new(2024, 3, 14).unwrap();
+        let time = Time::new(14, 5, 0).unwrap();
+        assert_eq!(
+            Formatter::new("en-US").date(&date, DateStyle::Short),
+            "3/14/2024"
+        );
+        assert_eq!(
+            Formatter::new("de").date(&date, DateStyle::Short),
+            "14.03.2024"
+        );
+        assert_eq!(Formatter::new("en").time(&time), "2:05 PM");
+        assert_eq!(Formatter::new("fr").time(&time), "14:05");
+    }
+
+    #[test]
+    fn relative_duration_uses_the_largest_unit() {
+        let formatter = Formatter::default();
+        assert_eq!(formatter.relative_duration(3 * 86_400), "in 3 days");
+        assert_eq!(formatter.relative_duration(-3_600), "1 hour ago");
+        assert_eq!(
+            Formatter::new("fr").relative_duration(-7_200),
+            "il y a 2 heures"
+        );
+    }
+}
@@ -1,15 +1,119 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
 use crate::{
     element::{Element, HtmlElement, HtmlElementConfig},
-    errors::HeadCreationError,
-    miscellaneous::{BaseUrlTarget, ScriptLoadMode},
+    errors::{HeadCreationError, HtmlResult},
+    miscellaneous::{
+        escape_html, BaseUrlTarget, CrossOrigin, FeedFormat, HttpEquiv, PreloadDestination,
+        ReferrerPolicy, ResourceHint, ScriptLoadMode,
+    },
+    structured_data::StructuredData,
     tags::TagType,
 };
 
 /// Defines the html document's `<head>` tag content.
+#[derive(Clone, Debug, Default)]
 pub struct Head {
     pub content: Vec<Element>,
 }
 
+/// The `viewport` meta tag content, controlling the layout width on mobile devices.
+/// The default viewport uses the device's width with no initial zoom.
+///
+/// - `width` : The layout width in pixels, if None the device's width is used.
+/// - `initial_scale` : The optional zoom level when the page is loaded.
+/// - `minimum_scale` : The optional minimum zoom level.
+/// - `maximum_scale` : The optional maximum zoom level.
+/// - `user_scalable` : If false, the user can not zoom.
+/// - `cover` : If true, the page fills the whole screen of the devices with a notch.
+#[derive(Clone, Debug)]
+pub struct Viewport {
+    pub width: Option<u32>,
+    pub initial_scale: Option<f64>,
+    pub minimum_scale: Option<f64>,
+    pub maximum_scale: Option<f64>,
+    pub user_scalable: bool,
+    pub cover: bool,
+}
+
+/// The fetch attributes of an external resource.
+///
+/// - `cross_origin` : The optional credentials mode, required to preload fonts and to check subresource integrity.
+/// - `referrer_policy` : The optional referrer policy.
+/// - `integrity` : The optional subresource integrity hashes (`sha384-...`), see `integrity::compute_integrity`.
+#[derive(Clone, Debug, Default)]
+pub struct FetchOptions {
+    pub cross_origin: Option<CrossOrigin>,
+    pub referrer_policy: Option<ReferrerPolicy>,
+    pub integrity: Option<String>,
+}
+
+/// The Open Graph object types.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OpenGraphType {
+    Website,
+    Article,
+    Profile,
+    Book,
+    VideoMovie,
+    MusicSong,
+}
+
+/// The Twitter card types.
+///
+/// - `Summary` : Small square image next to the title and description.
+/// - `SummaryLargeImage` : Large image above the title and description, an image is required.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TwitterCard {
+    Summary,
+    SummaryLargeImage,
+}
+
+/// The preview image of a page shared on social networks.
+///
+/// - `url` : The absolute url of the image.
+/// - `width` : The optional width in pixels.
+/// - `height` : The optional height in pixels.
+/// - `alt` : The optional description of the image.
+/// - `mime_type` : The optional image's MIME type (`image/png`...).
+#[derive(Clone, Debug)]
+pub struct SocialImage {
+    pub url: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub alt: Option<String>,
+    pub mime_type: Option<String>,
+}
+
+/// The Open Graph and Twitter card metadata, displayed as a preview when the page is shared.
+/// The Open Graph protocol requires a title, a type, an image and an url,
+/// the Twitter cards reuse the Open Graph properties.
+///
+/// - `title` : The page's title.
+/// - `description` : The optional one or two sentences description.
+/// - `url` : The page's canonical absolute url.
+/// - `image` : The preview image.
+/// - `object_type` : The Open Graph type, `website` by default.
+/// - `site_name` : The optional name of the whole site.
+/// - `locale` : The optional locale of the page (`en_US`...).
+/// - `card` : The Twitter card type, if None no Twitter metadata is rendered.
+/// - `twitter_site` : The optional `@username` of the site.
+#[derive(Clone, Debug)]
+pub struct SocialMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub image: Option<SocialImage>,
+    pub object_type: OpenGraphType,
+    pub site_name: Option<String>,
+    pub locale: Option<String>,
+    pub card: Option<TwitterCard>,
+    pub twitter_site: Option<String>,
+}
+
 impl Head {
     /// Creates a blank `<head>` tag.
     pub fn new() -> Self {
@@ -25,8 +129,65 @@ impl Head {
             self.content.push(element);
             Ok(self)
         } else {
-            Err(HeadCreationError::InvalidContent(element))
+            Err(HeadCreationError::InvalidContent(Box::new(element)))
+        }
+    }
+
+    /// Normalizes the head's content :
+    /// - the charset meta tag is placed first, so that it is within the first 1024 bytes of the document,
+    ///   followed by the `<base>` tag, so that it applies to every url of the head.
+    /// - the identical entries, and the stylesheets and scripts of an already loaded url, are removed.
+    /// - the title, base url, charset, viewport and canonical url are kept unique.
+    ///
+    /// If one of the unique entries is given twice with different values, the Err variant is returned.
+    pub fn normalize(self) -> Result<Self, HeadCreationError> {
+        let mut unique_entries: HashMap<&str, String> = HashMap::new();
+        let mut resources = HashSet::new();
+        let mut content: Vec<Element> = Vec::new();
+        for element in self.content {
+            if content.contains(&element) {
+                continue;
+            }
+            if let Some((entry, value)) = unique_entry(&element) {
+                match unique_entries.get(entry) {
+                    Some(existing) if *existing == value => continue,
+                    Some(existing) => {
+                        return Err(HeadCreationError::Conflict(
+                            entry.to_string(),
+                            existing.clone(),
+                            value,
+                        ))
+                    }
+                    None => {
+                        unique_entries.insert(entry, value);
+                    }
+                }
+            }
+            if let Some(url) = resource_url(&element) {
+                if !resources.insert(url) {
+                    continue;
+                }
+            }
+            content.push(element);
         }
+        let (mut ordered, others): (Vec<Element>, Vec<Element>) = content
+            .into_iter()
+            .partition(|element| matches!(unique_entry(element), Some(("charset", _))));
+        let (base, others): (Vec<Element>, Vec<Element>) = others
+            .into_iter()
+            .partition(|element| matches!(unique_entry(element), Some(("base url", _))));
+        ordered.extend(base);
+        ordered.extend(others);
+        Ok(Self { content: ordered })
+    }
+
+    /// Appends the content of another head and normalizes the result, see `Head::normalize`.
+    /// If one of the unique entries is given twice with different values, the Err variant is returned.
+    ///
+    /// - `other` : The head to merge.
+    pub fn merge(mut self, other: Head) -> Result<Self, HeadCreationError> {
+        self.content.extend(other.content);
+        self.normalize()
     }
 
     /// Adds a title to the html page
@@ -122,6 +283,246 @@ impl Head {
         self
     }
 
+    /// Adds the Open Graph and Twitter card meta tags to the html page.
+    /// If a property required by one of the protocols is missing, the Err variant is returned.
+    ///
+    /// - `metadata` : The social metadata.
+    pub fn with_social_metadata(
+        mut self,
+        metadata: &SocialMetadata,
+    ) -> Result<Self, HeadCreationError> {
+        self.content.extend(metadata.to_elements()?);
+        Ok(self)
+    }
+
+    /// Adds a schema.org entity to the html page, as a `<script type="application/ld+json">` tag.
+    /// If the script element can not be built, the Err variant is returned.
+    ///
+    /// - `entity` : The entity (`Article`, `Dataset`...).
+    pub fn with_structured_data<T>(mut self, entity: &T) -> HtmlResult<Self>
+    where
+        T: StructuredData,
+    {
+        self.content.push(entity.to_script()?);
+        Ok(self)
+    }
+
+    /// Adds the `viewport` meta tag to the html page.
+    ///
+    /// - `viewport` : The viewport configuration.
+    pub fn with_viewport(self, viewport: &Viewport) -> Self {
+        self.with_meta("viewport".to_string(), viewport.to_content())
+    }
+
+    /// Adds the `theme-color` meta tag, coloring the browser's interface.
+    ///
+    /// - `color` : The css color.
+    pub fn with_theme_color(self, color: String) -> Self {
+        self.with_meta("theme-color".to_string(), color)
+    }
+
+    /// Adds a pragma directive meta tag to the html page.
+    ///
+    /// - `directive` : The directive.
+    /// - `content` : The directive's value.
+    pub fn with_http_equiv(mut self, directive: HttpEquiv, content: String) -> Self {
+        self.content.push(Element::Element(HtmlElement::new(
+            TagType::Meta,
+            HtmlElementConfig::new_empty()
+                .set_attribute("http-equiv".to_string(), Some(directive.to_string()))
+                .set_attribute("content".to_string(), Some(escape_html(&content))),
+        )));
+        self
+    }
+
+    /// Adds a resource hint to the html page.
+    ///
+    /// - `hint` : The hint.
+    /// - `href` : The resource's url, or the origin for `Preconnect` and `DnsPrefetch`.
+    /// - `destination` : The resource's type, required by `Preload`. If the value is None, this attribute is ignored.
+    /// - `options` : The fetch attributes.
+    pub fn with_resource_hint(
+        mut self,
+        hint: ResourceHint,
+        href: String,
+        destination: Option<PreloadDestination>,
+        options: &FetchOptions,
+    ) -> Self {
+        let mut configs = HtmlElementConfig::new_empty()
+            .set_attribute("rel".to_string(), Some(hint.to_string()))
+            .set_attribute("href".to_string(), Some(href));
+        if let Some(destination) = destination {
+            configs = configs.set_attribute("as".to_string(), Some(destination.to_string()));
+        }
+        self.content.push(Element::Element(HtmlElement::new(
+            TagType::Link,
+            options.apply(configs),
+        )));
+        self
+    }
+
+    /// Adds a css file to the page, with fetch attributes.
+    ///
+    /// - `href` : The file's path.
+    /// - `options` : The fetch attributes.
+    pub fn with_css_file_options(mut self, href: String, options: &FetchOptions) -> Self {
+        self.content.push(Element::Element(HtmlElement::new(
+            TagType::Link,
+            options.apply(
+                HtmlElementConfig::new_empty()
+                    .set_attribute("rel".to_string(), Some("stylesheet".to_string()))
+                    .set_attribute("href".to_string(), Some(href)),
+            ),
+        )));
+        self
+    }
+
+    /// Adds a javascript file to the html page, with fetch attributes.
+    ///
+    /// - `src` : The file's path.
+    /// - `loading_mode` : The optional script loading mode. If the value is None, this attribute is ignored.
+    /// - `options` : The fetch attributes.
+    pub fn with_javascript_file_options(
+        mut self,
+        src: String,
+        loading_mode: Option<ScriptLoadMode>,
+        options: &FetchOptions,
+    ) -> Self {
+        let mut configs =
+            HtmlElementConfig::new_empty().set_attribute("src".to_string(), Some(src));
+        if let Some(loading_mode) = loading_mode {
+            configs = configs.set_attribute(loading_mode.to_string(), None);
+        }
+        self.content.push(Element::Element(HtmlElement::new(
+            TagType::Script,
+            options.apply(configs),
+        )));
+        self
+    }
+
+    /// Adds a javascript module file to the html page. Modules are deferred by default.
+    ///
+    /// - `src` : The file's path.
+    /// - `options` : The fetch attributes.
+    pub fn with_module_file(mut self, src: String, options: &FetchOptions) -> Self {
+        self.content.push(Element::Element(HtmlElement::new(
+            TagType::Script,
+            options.apply(
+                HtmlElementConfig::new_empty()
+                    .set_attribute("type".to_string(), Some("module".to_string()))
+                    .set_attribute("src".to_string(), Some(src)),
+            ),
+        )));
+        self
+    }
+
+    /// Adds a script tag to the html page with a raw javascript module.
+    ///
+    /// - `content` : The module's raw javascript content.
+    pub fn with_raw_module(mut self, content: String) -> Self {
+        self.content.push(
+            Element::Element(HtmlElement::new(
+                TagType::Script,
+                HtmlElementConfig::new_empty()
+                    .set_attribute("type".to_string(), Some("module".to_string())),
+            )) + Element::Text(content),
+        );
+        self
+    }
+
+    /// Adds an icon to the page, in one or several sizes.
+    ///
+    /// - `href` : The icon's path.
+    /// - `mime_type` : The icon's MIME type (`image/png`, `image/svg+xml`...).
+    /// - `sizes` : The widths and heights contained in the file. If empty, this attribute is ignored.
+    pub fn with_icon(mut self, href: String, mime_type: String, sizes: &[(u32, u32)]) -> Self {
+        let mut configs = HtmlElementConfig::new_empty()
+            .set_attribute("rel".to_string(), Some("icon".to_string()))
+            .set_attribute("type".to_string(), Some(mime_type))
+            .set_attribute("href".to_string(), Some(href));
+        if !sizes.is_empty() {
+            configs = configs.set_attribute("sizes".to_string(), Some(format_sizes(sizes)));
+        }
+        self.content
+            .push(Element::Element(HtmlElement::new(TagType::Link, configs)));
+        self
+    }
+
+    /// Adds an icon used when the page is added to an iOS home screen.
+    ///
+    /// - `href` : The png icon's path.
+    /// - `size` : The optional width and height of the square icon (180 by default on iOS).
+    pub fn with_apple_touch_icon(mut self, href: String, size: Option<u32>) -> Self {
+        let mut configs = HtmlElementConfig::new_empty()
+            .set_attribute("rel".to_string(), Some("apple-touch-icon".to_string()))
+            .set_attribute("href".to_string(), Some(href));
+        if let Some(size) = size {
+            configs =
+                configs.set_attribute("sizes".to_string(), Some(format_sizes(&[(size, size)])));
+        }
+        self.content
+            .push(Element::Element(HtmlElement::new(TagType::Link, configs)));
+        self
+    }
+
+    /// Adds a link to the web application manifest, see `manifest::WebManifest`.
+    ///
+    /// - `href` : The manifest's path.
+    pub fn with_manifest(mut self, href: String) -> Self {
+        self.content.push(Element::Element(HtmlElement::new(
+            TagType::Link,
+            HtmlElementConfig::new_empty()
+                .set_attribute("rel".to_string(), Some("manifest".to_string()))
+                .set_attribute("href".to_string(), Some(href)),
+        )));
+        self
+    }
+
+    /// Adds the canonical url of the page, used by the search engines for duplicated pages.
+    ///
+    /// - `href` : The canonical url.
+    pub fn with_canonical_url(mut self, href: String) -> Self {
+        self.content.push(Element::Element(HtmlElement::new(
+            TagType::Link,
+            HtmlElementConfig::new_empty()
+                .set_attribute("rel".to_string(), Some("canonical".to_string()))
+                .set_attribute("href".to_string(), Some(href)),
+        )));
+        self
+    }
+
+    /// Adds a link to a feed of the page.
+    ///
+    /// - `href` : The feed's url.
+    /// - `format` : The feed's format.
+    /// - `title` : The feed's title, displayed by the feed readers.
+    pub fn with_alternate_feed(mut self, href: String, format: FeedFormat, title: String) -> Self {
+        self.content.push(Element::Element(HtmlElement::new(
+            TagType::Link,
+            HtmlElementConfig::new_empty()
+                .set_attribute("rel".to_string(), Some("alternate".to_string()))
+                .set_attribute("type".to_string(), Some(format.get_mime_type().to_string()))
+                .set_attribute("title".to_string(), Some(escape_html(&title)))
+                .set_attribute("href".to_string(), Some(href)),
+        )));
+        self
+    }
+
+    /// Adds a link to a translation of the page.
+    ///
+    /// - `href` : The translated page's url.
+    /// - `hreflang` : The translation's locale, or `x-default` for the page used when no translation matches.
+    pub fn with_alternate_language(mut self, href: String, hreflang: String) -> Self {
+        self.content.push(Element::Element(HtmlElement::new(
+            TagType::Link,
+            HtmlElementConfig::new_empty()
+                .set_attribute("rel".to_string(), Some("alternate".to_string()))
+                .set_attribute("hreflang".to_string(), Some(escape_html(&hreflang)))
+                .set_attribute("href".to_string(), Some(href)),
+        )));
+        self
+    }
+
     /// Adds a script tag to the html page with raw javascript.
     ///
     /// - `content` : The script raw javascript content.
@@ -174,10 +575,807 @@ impl Head {
     }
 }
 
-impl Default for Head {
+impl Viewport {
+    /// Creates the default viewport, using the device's width with no initial zoom.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a fixed layout width.
+    ///
+    /// - `width` : The width in pixels.
+    pub fn with_width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the zoom level when the page is loaded.
+    ///
+    /// - `scale` : The zoom level, 1 being no zoom.
+    pub fn with_initial_scale(mut self, scale: f64) -> Self {
+        self.initial_scale = Some(scale);
+        self
+    }
+
+    /// Sets the zoom limits.
+    ///
+    /// - `minimum` : The minimum zoom level.
+    /// - `maximum` : The maximum zoom level.
+    pub fn with_scale_limits(mut self, minimum: f64, maximum: f64) -> Self {
+        self.minimum_scale = Some(minimum);
+        self.maximum_scale = Some(maximum);
+        self
+    }
+
+    /// Prevents the user from zooming, which harms accessibility.
+    pub fn without_user_scaling(mut self) -> Self {
+        self.user_scalable = false;
+        self
+    }
+
+    /// Fills the whole screen of the devices with a notch.
+    pub fn with_cover(mut self) -> Self {
+        self.cover = true;
+        self
+    }
+
+    /// Returns the `content` attribute of the meta tag (`width=device-width, initial-scale=1`).
+    pub fn to_content(&self) -> String {
+        let mut properties = vec![match self.width {
+            Some(width) => format!("width={}", width),
+            None => "width=device-width".to_string(),
+        }];
+        if let Some(scale) = self.initial_scale {
+            properties.push(format!("initial-scale={}", scale));
+        }
+        if let Some(scale) = self.minimum_scale {
+            properties.push(format!("minimum-scale={}", scale));
+        }
+        if let Some(scale) = self.maximum_scale {
+            properties.push(format!("maximum-scale={}", scale));
+        }
+        if !self.user_scalable {
+            properties.push("user-scalable=no".to_string());
+        }
+        if self.cover {
+            properties.push("viewport-fit=cover".to_string());
+        }
+        properties.join(", ")
+    }
+}
+
+impl Default for Viewport {
     fn default() -> Self {
         Self {
-            content: Vec::new(),
+            width: None,
+            initial_scale: Some(1.0),
+            minimum_scale: None,
+            maximum_scale: None,
+            user_scalable: true,
+            cover: false,
+        }
+    }
+}
+
+impl FetchOptions {
+    /// Creates fetch options without attribute.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the credentials mode.
+    ///
+    /// - `cross_origin` : The credentials mode.
+    pub fn with_cross_origin(mut self, cross_origin: CrossOrigin) -> Self {
+        self.cross_origin = Some(cross_origin);
+        self
+    }
+
+    /// Sets the referrer policy.
+    ///
+    /// - `referrer_policy` : The referrer policy.
+    pub fn with_referrer_policy(mut self, referrer_policy: ReferrerPolicy) -> Self {
+        self.referrer_policy = Some(referrer_policy);
+        self
+    }
+
+    /// Sets the precomputed subresource integrity hashes of a remote resource.
+    /// If no credentials mode is set, the `anonymous` mode is used, the integrity being only checked for CORS requests.
+    ///
+    /// - `integrity` : The hashes, separated by spaces (`sha384-...`).
+    pub fn with_integrity(mut self, integrity: String) -> Self {
+        self.integrity = Some(integrity);
+        self
+    }
+
+    /// Internal function. Adds the `crossorigin`, `referrerpolicy` and `integrity` attributes to an element's configuration.
+    ///
+    /// - `configs` : The element's configuration.
+    fn apply(&self, mut configs: HtmlElementConfig) -> HtmlElementConfig {
+        let cross_origin = match (&self.cross_origin, &self.integrity) {
+            (None, Some(_)) => Some(CrossOrigin::Anonymous),
+            (cross_origin, _) => cross_origin.clone(),
+        };
+        if let Some(cross_origin) = cross_origin {
+            configs =
+                configs.set_attribute("crossorigin".to_string(), Some(cross_origin.to_string()));
+        }
+        if let Some(integrity) = &self.integrity {
+            configs = configs.set_attribute("integrity".to_string(), Some(integrity.clone()));
+        }
+        if let Some(referrer_policy) = &self.referrer_policy {
+            configs = configs.set_attribute(
+                "referrerpolicy".to_string(),
+                Some(referrer_policy.to_string()),
+            );
+        }
+        configs
+    }
+}
+
+impl Display for OpenGraphType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            OpenGraphType::Website => "website",
+            OpenGraphType::Article => "article",
+            OpenGraphType::Profile => "profile",
+            OpenGraphType::Book => "book",
+            OpenGraphType::VideoMovie => "video.movie",
+            OpenGraphType::MusicSong => "music.song",
+        })
+    }
+}
+
+impl Display for TwitterCard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TwitterCard::Summary => "summary",
+            TwitterCard::SummaryLargeImage => "summary_large_image",
+        })
+    }
+}
+
+impl SocialImage {
+    /// Creates a preview image.
+    ///
+    /// - `url` : The absolute url of the image.
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            width: None,
+            height: None,
+            alt: None,
+            mime_type: None,
         }
     }
+
+    /// Sets the image's dimensions, letting the preview be displayed before the image is downloaded.
+    ///
+    /// - `width` : The width in pixels.
+    /// - `height` : The height in pixels.
+    pub fn with_dimensions(mut self, width: u32, height: u32) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the image's description.
+    ///
+    /// - `alt` : The description.
+    pub fn with_alt(mut self, alt: String) -> Self {
+        self.alt = Some(alt);
+        self
+    }
+
+    /// Sets the image's MIME type.
+    ///
+    /// - `mime_type` : The MIME type (`image/png`...).
+    pub fn with_mime_type(mut self, mime_type: String) -> Self {
+        self.mime_type = Some(mime_type);
+        self
+    }
+}
+
+impl SocialMetadata {
+    /// Creates empty social metadata, with the `website` type and no Twitter card.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the page's title.
+    ///
+    /// - `title` : The title.
+    pub fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the page's description.
+    ///
+    /// - `description` : The description.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the page's canonical url.
+    ///
+    /// - `url` : The absolute url.
+    pub fn with_url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Sets the preview image.
+    ///
+    /// - `image` : The image.
+    pub fn with_image(mut self, image: SocialImage) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Sets the Open Graph type.
+    ///
+    /// - `object_type` : The type.
+    pub fn with_type(mut self, object_type: OpenGraphType) -> Self {
+        self.object_type = object_type;
+        self
+    }
+
+    /// Sets the name of the whole site.
+    ///
+    /// - `site_name` : The site's name.
+    pub fn with_site_name(mut self, site_name: String) -> Self {
+        self.site_name = Some(site_name);
+        self
+    }
+
+    /// Sets the page's locale.
+    ///
+    /// - `locale` : The locale, `-` is replaced by the `_` used by Open Graph (`en-US` gives `en_US`).
+    pub fn with_locale(mut self, locale: String) -> Self {
+        self.locale = Some(locale.replace('-', "_"));
+        self
+    }
+
+    /// Enables the Twitter metadata.
+    ///
+    /// - `card` : The card type.
+    /// - `site` : The optional `@username` of the site.
+    pub fn with_twitter_card(mut self, card: TwitterCard, site: Option<String>) -> Self {
+        self.card = Some(card);
+        self.twitter_site = site;
+        self
+    }
+
+    /// Returns the `<meta>` elements of the metadata.
+    /// If a property required by one of the protocols is missing, the Err variant is returned.
+    pub fn to_elements(&self) -> Result<Vec<Element>, HeadCreationError> {
+        let missing = |protocol: &str, property: &str| {
+            HeadCreationError::MissingProperty(protocol.to_string(), property.to_string())
+        };
+        let title = self
+            .title
+            .as_ref()
+            .ok_or(missing("Open Graph", "og:title"))?;
+        let url = self.url.as_ref().ok_or(missing("Open Graph", "og:url"))?;
+        let image = self
+            .image
+            .as_ref()
+            .ok_or(missing("Open Graph", "og:image"))?;
+        let mut properties = vec![
+            ("og:title", title.clone()),
+            ("og:type", self.object_type.to_string()),
+            ("og:url", url.clone()),
+            ("og:image", image.url.clone()),
+        ];
+        let optionals = [
+            ("og:image:width", image.width.map(|width| width.to_string())),
+            (
+                "og:image:height",
+                image.height.map(|height| height.to_string()),
+            ),
+            ("og:image:alt", image.alt.clone()),
+            ("og:image:type", image.mime_type.clone()),
+            ("og:description", self.description.clone()),
+            ("og:site_name", self.site_name.clone()),
+            ("og:locale", self.locale.clone()),
+        ];
+        properties.extend(
+            optionals
+                .into_iter()
+                .filter_map(|(property, value)| value.map(|value| (property, value))),
+        );
+        let mut elements: Vec<Element> = properties
+            .into_iter()
+            .map(|(property, content)| meta_element("property", property, &content))
+            .collect();
+
+        // Twitter falls back to the Open Graph title, description and image,
+        // only the card type and the site are specific.
+        if let Some(card) = &self.card {
+            elements.push(meta_element("name", "twitter:card", &card.to_string()));
+            if let Some(site) = &self.twitter_site {
+                elements.push(meta_element("name", "twitter:site", site));
+            }
+        }
+        Ok(elements)
+    }
+}
+
+impl Default for SocialMetadata {
+    fn default() -> Self {
+        Self {
+            title: None,
+            description: None,
+            url: None,
+            image: None,
+            object_type: OpenGraphType::Website,
+            site_name: None,
+            locale: None,
+            card: None,
+            twitter_site: None,
+        }
+    }
+}
+
+/// Internal function. Returns the name and value of a head entry that must be unique,
+/// or None if the element can be repeated.
+///
+/// - `element` : The head's element.
+fn unique_entry(element: &Element) -> Option<(&'static str, String)> {
+    let Element::Element(element) = element else {
+        return None;
+    };
+    match element.tag {
+        TagType::Title => Some((
+            "title",
+            element
+                .children
+                .iter()
+                .filter_map(|child| match child.as_ref() {
+                    Element::Text(text) => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect(),
+        )),
+        TagType::Base => Some((
+            "base url",
+            [
+                get_attribute(element, "href"),
+                get_attribute(element, "target"),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<String>>()
+            .join(" "),
+        )),
+        TagType::Meta => {
+            if let Some(charset) = get_attribute(element, "charset") {
+                Some(("charset", charset.to_lowercase()))
+            } else if get_attribute(element, "name").as_deref() == Some("viewport") {
+                Some((
+                    "viewport",
+                    get_attribute(element, "content").unwrap_or_default(),
+                ))
+            } else {
+                None
+            }
+        }
+        TagType::Link if get_attribute(element, "rel").as_deref() == Some("canonical") => Some((
+            "canonical url",
+            get_attribute(element, "href").unwrap_or_default(),
+        )),
+        _ => None,
+    }
+}
+
+/// Internal function. Returns the url of a stylesheet or of an external script, or None for the other elements.
+///
+/// - `element` : The head's element.
+fn resource_url(element: &Element) -> Option<String> {
+    let Element::Element(element) = element else {
+        return None;
+    };
+    match element.tag {
+        TagType::Link if get_attribute(element, "rel").as_deref() == Some("stylesheet") => {
+            get_attribute(element, "href").map(|href| format!("stylesheet {}", href))
+        }
+        TagType::Script => get_attribute(element, "src").map(|src| format!("script {}", src)),
+        _ => None,
+    }
+}
+
+/// Internal function. Returns the value of an element's attribute, or None if it is missing or has no value.
+///
+/// - `element` : The element.
+/// - `name` : The attribute's name.
+fn get_attribute(element: &HtmlElement, name: &str) -> Option<String> {
+    element.config.attributes.get(name).cloned().flatten()
+}
+
+/// Internal function. Returns the `sizes` attribute of an icon (`16x16 32x32`).
+///
+/// - `sizes` : The widths and heights.
+pub(crate) fn format_sizes(sizes: &[(u32, u32)]) -> String {
+    sizes
+        .iter()
+        .map(|(width, height)| format!("{}x{}", width, height))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Internal function. Returns a `<meta>` element with an escaped content.
+///
+/// - `key` : The attribute naming the metadata (`name`, `property`).
+/// - `name` : The metadata's name.
+/// - `content` : The metadata's value.
+fn meta_element(key: &str, name: &str, content: &str) -> Element {
+    Element::Element(HtmlElement::new(
+        TagType::Meta,
+        HtmlElementConfig::new_empty()
+            .set_attribute(key.to_string(), Some(name.to_string()))
+            .set_attribute("content".to_string(), Some(escape_html(content))),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(head: &Head) -> Vec<TagType> {
+        head.content
+            .iter()
+            .filter_map(|element| match element {
+                Element::Element(element) => Some(element.tag.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn normalize_places_charset_and_base_first() {
+        let head = Head::new()
+            .with_title(String::from("Home"))
+            .with_css_file(String::from("style.css"))
+            .with_base_url(String::from("/"), None)
+            .with_charset_meta(String::from("UTF-8"))
+            .normalize()
+            .unwrap();
+        assert_eq!(
+            tags(&head),
+            vec![TagType::Meta, TagType::Base, TagType::Title, TagType::Link]
+        );
+    }
+
+    #[test]
+    fn merge_removes_duplicates_and_loaded_resources() {
+        let first = Head::new()
+            .with_charset_meta(String::from("utf-8"))
+            .with_css_file(String::from("style.css"))
+            .with_javascript_file(String::from("app.js"), None);
+        let second = Head::new()
+            .with_charset_meta(String::from("UTF-8"))
+            .with_css_file(String::from("style.css"))
+            .with_javascript_file(String::from("app.js"), Some(ScriptLoadMode::Defer))
+            .with_meta(String::from("author"), String::from("Ada"));
+        let head = first.merge(second).unwrap();
+        assert_eq!(
+            tags(&head),
+            vec![TagType::Meta, TagType::Link, TagType::Script, TagType::Meta]
+        );
+    }
+
+    #[test]
+    fn merge_rejects_conflicting_unique_entries() {
+        let first = Head::new().with_title(String::from("Home"));
+        let second = Head::new().with_title(String::from("About"));
+        let error = first.merge(second).unwrap_err();
+        assert!(matches!(
+            error,
+            HeadCreationError::Conflict(entry, first, second)
+                if entry == "title" && first == "Home" && second == "About"
+        ));
+
+        let first = Head::new().with_canonical_url(String::from("https://example.com/a"));
+        let second = Head::new().with_canonical_url(String::from("https://example.com/b"));
+        assert!(matches!(
+            first.merge(second),
+            Err(HeadCreationError::Conflict(entry, _, _)) if entry == "canonical url"
+        ));
+    }
+
+    #[test]
+    fn identical_unique_entries_are_kept_once() {
+        let viewport = Viewport::new();
+        let head = Head::new()
+            .with_viewport(&viewport)
+            .merge(Head::new().with_viewport(&viewport))
+            .unwrap();
+        assert_eq!(head.content.len(), 1);
+    }
+
+    #[test]
+    fn with_element_rejects_body_content() {
+        let element = Element::Element(HtmlElement::new(
+            TagType::Div,
+            HtmlElementConfig::new_empty(),
+        ));
+        assert!(matches!(
+            Head::new().with_element(element),
+            Err(HeadCreationError::InvalidContent(_))
+        ));
+    }
+
+    fn metas(elements: &[Element]) -> Vec<(String, String)> {
+        elements
+            .iter()
+            .map(|element| match element {
+                Element::Element(element) if element.tag == TagType::Meta => {
+                    let key = element
+                        .config
+                        .get_attribute("property".to_string())
+                        .or_else(|| element.config.get_attribute("name".to_string()))
+                        .flatten()
+                        .unwrap();
+                    let content = element
+                        .config
+                        .get_attribute("content".to_string())
+                        .flatten()
+                        .unwrap();
+                    (key, content)
+                }
+                _ => panic!("the element is not a meta element"),
+            })
+            .collect()
+    }
+
+    fn social_metadata() -> SocialMetadata {
+        SocialMetadata::new()
+            .with_title(String::from("Q1 & Q2 report"))
+            .with_url(String::from("https://example.com/reports/q1"))
+            .with_image(SocialImage::new(String::from("https://example.com/q1.png")))
+    }
+
+    #[test]
+    fn social_metadata_requires_title_url_and_image() {
+        let missing = [
+            (
+                "og:title",
+                SocialMetadata {
+                    title: None,
+                    ..social_metadata()
+                },
+            ),
+            (
+                "og:url",
+                SocialMetadata {
+                    url: None,
+                    ..social_metadata()
+                },
+            ),
+            (
+                "og:image",
+                SocialMetadata {
+                    image: None,
+                    ..social_metadata()
+                },
+            ),
+        ];
+        for (property, metadata) in missing {
+            assert!(
+                matches!(
+                    metadata.to_elements(),
+                    Err(HeadCreationError::MissingProperty(protocol, missing))
+                        if protocol == "Open Graph" && missing == property
+                ),
+                "{}",
+                property
+            );
+            assert!(Head::new().with_social_metadata(&metadata).is_err());
+        }
+    }
+
+    #[test]
+    fn social_metadata_renders_the_meta_tags() {
+        let metadata = social_metadata();
+        let expected = [
+            ("og:title", "Q1 &amp; Q2 report"),
+            ("og:type", "website"),
+            ("og:url", "https://example.com/reports/q1"),
+            ("og:image", "https://example.com/q1.png"),
+        ];
+        assert_eq!(
+            metas(&metadata.to_elements().unwrap()),
+            expected.map(|(key, content)| (key.to_string(), content.to_string()))
+        );
+
+        let metadata = metadata
+            .with_type(OpenGraphType::Article)
+            .with_image(
+                SocialImage::new(String::from("https://example.com/q1.png"))
+                    .with_dimensions(1200, 630)
+                    .with_alt(String::from("Sales chart"))
+                    .with_mime_type(String::from("image/png")),
+            )
+            .with_description(String::from("Quarterly sales"))
+            .with_site_name(String::from("Reports"))
+            .with_locale(String::from("en-US"))
+            .with_twitter_card(
+                TwitterCard::SummaryLargeImage,
+                Some(String::from("@reports")),
+            );
+        let expected = [
+            ("og:title", "Q1 &amp; Q2 report"),
+            ("og:type", "article"),
+            ("og:url", "https://example.com/reports/q1"),
+            ("og:image", "https://example.com/q1.png"),
+            ("og:image:width", "1200"),
+            ("og:image:height", "630"),
+            ("og:image:alt", "Sales chart"),
+            ("og:image:type", "image/png"),
+            ("og:description", "Quarterly sales"),
+            ("og:site_name", "Reports"),
+            ("og:locale", "en_US"),
+            ("twitter:card", "summary_large_image"),
+            ("twitter:site", "@reports"),
+        ];
+        let head = Head::new().with_social_metadata(&metadata).unwrap();
+        assert_eq!(
+            metas(&head.content),
+            expected.map(|(key, content)| (key.to_string(), content.to_string()))
+        );
+    }
+
+    fn attributes(head: &Head) -> Vec<Vec<(String, Option<String>)>> {
+        head.content
+            .iter()
+            .map(|element| match element {
+                Element::Element(element) => {
+                    let mut attributes = element
+                        .config
+                        .attributes
+                        .iter()
+                        .map(|(name, value)| (name.clone(), value.clone()))
+                        .collect::<Vec<(String, Option<String>)>>();
+                    attributes.sort();
+                    attributes
+                }
+                _ => panic!("the head entry is not an html element"),
+            })
+            .collect()
+    }
+
+    fn attribute(name: &str, value: &str) -> (String, Option<String>) {
+        (name.to_string(), Some(value.to_string()))
+    }
+
+    #[test]
+    fn viewport_content_lists_the_properties() {
+        let head = Head::new().with_viewport(&Viewport::new());
+        assert_eq!(
+            attributes(&head),
+            vec![vec![
+                attribute("content", "width=device-width, initial-scale=1"),
+                attribute("name", "viewport"),
+            ]]
+        );
+        let viewport = Viewport::new()
+            .with_width(800)
+            .with_initial_scale(1.5)
+            .with_scale_limits(0.5, 2.0)
+            .without_user_scaling()
+            .with_cover();
+        assert_eq!(
+            viewport.to_content(),
+            "width=800, initial-scale=1.5, minimum-scale=0.5, maximum-scale=2, user-scalable=no, viewport-fit=cover"
+        );
+    }
+
+    #[test]
+    fn resource_hints_have_their_destination_and_fetch_attributes() {
+        let options = FetchOptions::new()
+            .with_cross_origin(CrossOrigin::Anonymous)
+            .with_referrer_policy(ReferrerPolicy::NoReferrer);
+        let head = Head::new()
+            .with_resource_hint(
+                ResourceHint::Preload,
+                String::from("fonts/inter.woff2"),
+                Some(PreloadDestination::Font),
+                &options,
+            )
+            .with_resource_hint(
+                ResourceHint::Preconnect,
+                String::from("https://cdn.example.com"),
+                None,
+                &FetchOptions::new(),
+            );
+        assert_eq!(
+            attributes(&head),
+            vec![
+                vec![
+                    attribute("as", "font"),
+                    attribute("crossorigin", "anonymous"),
+                    attribute("href", "fonts/inter.woff2"),
+                    attribute("referrerpolicy", "no-referrer"),
+                    attribute("rel", "preload"),
+                ],
+                vec![
+                    attribute("href", "https://cdn.example.com"),
+                    attribute("rel", "preconnect"),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn icons_list_their_sizes() {
+        let head = Head::new()
+            .with_icon(
+                String::from("icon.png"),
+                String::from("image/png"),
+                &[(16, 16), (32, 32)],
+            )
+            .with_icon(String::from("icon.svg"), String::from("image/svg+xml"), &[])
+            .with_apple_touch_icon(String::from("apple.png"), Some(180))
+            .with_manifest(String::from("manifest.webmanifest"));
+        assert_eq!(
+            attributes(&head),
+            vec![
+                vec![
+                    attribute("href", "icon.png"),
+                    attribute("rel", "icon"),
+                    attribute("sizes", "16x16 32x32"),
+                    attribute("type", "image/png"),
+                ],
+                vec![
+                    attribute("href", "icon.svg"),
+                    attribute("rel", "icon"),
+                    attribute("type", "image/svg+xml"),
+                ],
+                vec![
+                    attribute("href", "apple.png"),
+                    attribute("rel", "apple-touch-icon"),
+                    attribute("sizes", "180x180"),
+                ],
+                vec![
+                    attribute("href", "manifest.webmanifest"),
+                    attribute("rel", "manifest"),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn alternate_links_describe_their_target() {
+        let head = Head::new()
+            .with_alternate_language(String::from("/fr/"), String::from("fr"))
+            .with_alternate_language(String::from("/"), String::from("x-default"))
+            .with_alternate_feed(
+                String::from("/feed.xml"),
+                FeedFormat::Rss,
+                String::from("News & reports"),
+            );
+        assert_eq!(
+            attributes(&head),
+            vec![
+                vec![
+                    attribute("href", "/fr/"),
+                    attribute("hreflang", "fr"),
+                    attribute("rel", "alternate"),
+                ],
+                vec![
+                    attribute("href", "/"),
+                    attribute("hreflang", "x-default"),
+                    attribute("rel", "alternate"),
+                ],
+                vec![
+                    attribute("href", "/feed.xml"),
+                    attribute("rel", "alternate"),
+                    attribute("title", "News &amp; reports"),
+                    attribute("type", "application/rss+xml"),
+                ],
+            ]
+        );
+    }
 }
@@ -1,16 +1,37 @@
-use std::{
-    fs::File,
-    io::{self, BufWriter},
-};
+use std::{fmt::Display, fs::File, io::BufWriter};
 
-use crate::element::Element;
+use crate::{element::Element, errors::HtmlResult, miscellaneous::escape_html};
+
+#[cfg(feature = "derive")]
+pub use html_export_derive::ToHtml;
 
 /// Defines Html export behavior.
 pub trait Html {
-    fn write_html(&self, writer: BufWriter<File>, tabs: usize) -> io::Result<BufWriter<File>>;
+    fn write_html(&self, writer: BufWriter<File>, tabs: usize) -> HtmlResult<BufWriter<File>>;
 }
 
 /// Defines Html conversion behavior.
 pub trait ToHtml {
     fn to_html(&self) -> Element;
 }
+
+/// Defines how a value embedded in the `html!` macro is converted to an element.
+/// Elements are inserted as is, other values are converted to escaped raw text.
+pub trait IntoElement {
+    fn into_element(self) -> Element;
+}
+
+impl IntoElement for Element {
+    fn into_element(self) -> Element {
+        self
+    }
+}
+
+impl<T> IntoElement for T
+where
+    T: Display,
+{
+    fn into_element(self) -> Element {
+        Element::Text(escape_html(&self.to_string()))
+    }
+}
@@ -0,0 +1,602 @@
+use std::{collections::HashMap, fs};
+
+use crate::{
+    element::{Element, HtmlElement, HtmlElementConfig},
+    errors::{HtmlResult, I18nError},
+    miscellaneous::escape_html,
+    tags::TagType,
+};
+
+/// The extension of the catalog files loaded by `Localizer::load_directory`.
+pub const CATALOG_EXTENSION: &str = "messages";
+
+/// The Unicode first strong isolate character, opening an isolated argument.
+const FIRST_STRONG_ISOLATE: char = '\u{2068}';
+
+/// The Unicode pop directional isolate character, closing an isolated argument.
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+/// The languages written from right to left.
+const RTL_LANGUAGES: [&str; 9] = ["ar", "dv", "fa", "he", "ks", "ps", "sd", "ur", "yi"];
+
+/// Represents the messages of a locale.
+///
+/// - `locale` : The catalog's locale (`en`, `fr-CA`...).
+/// - `messages` : The messages, by key.
+#[derive(Clone, Debug)]
+pub struct Catalog {
+    pub locale: String,
+    pub messages: HashMap<String, Message>,
+}
+
+/// Represents a message of a catalog.
+/// The messages contain `{name}` placeholders replaced by the arguments, `{{` and `}}` are literal braces.
+///
+/// - `Text` : A message without plural forms.
+/// - `Plural` : A message with a form by plural category. The `other` form is used when a category is missing.
+#[derive(Clone, Debug)]
+pub enum Message {
+    Text(String),
+    Plural(HashMap<PluralCategory, String>),
+}
+
+/// The CLDR plural categories.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// The writing direction of a locale.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextDirection {
+    Ltr,
+    Rtl,
+}
+
+/// Holds the catalogs of all the locales.
+/// A message is searched in the requested locale, then in its language (`fr` for `fr-CA`), then in the fallback locale.
+///
+/// - `catalogs` : The catalogs, by locale.
+/// - `fallback` : The locale used when a message is missing.
+/// - `isolate_arguments` : If true, the arguments are wrapped in Unicode isolation characters,
+///   so right-to-left user text does not reorder the message around it.
+#[derive(Clone, Debug)]
+pub struct Localizer {
+    pub catalogs: HashMap<String, Catalog>,
+    pub fallback: String,
+    pub isolate_arguments: bool,
+}
+
+/// Translates the messages for a locale, created by `Localizer::for_locale`.
+///
+/// - `localizer` : The catalogs.
+/// - `locale` : The selected locale.
+#[derive(Clone, Debug)]
+pub struct Translator<'l> {
+    pub localizer: &'l Localizer,
+    pub locale: String,
+}
+
+impl Catalog {
+    /// Creates an empty catalog.
+    ///
+    /// - `locale` : The catalog's locale.
+    pub fn new(locale: String) -> Self {
+        Self {
+            locale: normalize_locale(&locale),
+            messages: HashMap::new(),
+        }
+    }
+
+    /// Adds a message without plural forms.
+    ///
+    /// - `key` : The message's key.
+    /// - `text` : The message.
+    pub fn with_message(mut self, key: String, text: String) -> Self {
+        self.messages.insert(key, Message::Text(text));
+        self
+    }
+
+    /// Adds a plural form of a message.
+    ///
+    /// - `key` : The message's key.
+    /// - `category` : The form's plural category.
+    /// - `text` : The message's form.
+    pub fn with_plural_form(mut self, key: String, category: PluralCategory, text: String) -> Self {
+        let message = self
+            .messages
+            .entry(key)
+            .or_insert_with(|| Message::Plural(HashMap::new()));
+        match message {
+            Message::Plural(forms) => {
+                forms.insert(category, text);
+            }
+            Message::Text(other) => {
+                let forms =
+                    HashMap::from([(PluralCategory::Other, other.clone()), (category, text)]);
+                *message = Message::Plural(forms);
+            }
+        }
+        self
+    }
+
+    /// Parses a catalog file's content. Each line is a `key = message` entry,
+    /// the plural forms are written `key[one] = message`. Empty lines and lines starting with `#` are ignored.
+    /// In the messages, `\n` is a line break. If a line is not an entry, the Err variant is returned.
+    ///
+    /// - `locale` : The catalog's locale.
+    /// - `content` : The catalog file's content.
+    /// - `file` : The catalog file's path, used in the errors.
+    pub fn parse(locale: String, content: &str, file: &str) -> Result<Self, I18nError> {
+        let mut catalog = Self::new(locale);
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || I18nError::InvalidLine(file.to_string(), index + 1);
+            let (key, text) = line.split_once('=').ok_or_else(invalid)?;
+            let (key, text) = (key.trim(), text.trim().replace("\\n", "\n"));
+            match key.strip_suffix(']').and_then(|key| key.split_once('[')) {
+                Some((key, category)) => {
+                    let category = PluralCategory::from_name(category).ok_or_else(invalid)?;
+                    catalog = catalog.with_plural_form(key.trim().to_string(), category, text);
+                }
+                None if key.is_empty() || key.contains(char::is_whitespace) => {
+                    return Err(invalid())
+                }
+                None => catalog = catalog.with_message(key.to_string(), text),
+            }
+        }
+        Ok(catalog)
+    }
+
+    /// Loads a catalog file. See `Catalog::parse` for the file format.
+    /// If the file can not be read or is invalid, the Err variant is returned.
+    ///
+    /// - `locale` : The catalog's locale.
+    /// - `path` : The catalog file's path.
+    pub fn from_file(locale: String, path: &str) -> Result<Self, I18nError> {
+        let content =
+            fs::read_to_string(path).map_err(|_| I18nError::MissingFile(path.to_string()))?;
+        Self::parse(locale, &content, path)
+    }
+}
+
+impl PluralCategory {
+    /// Returns the category matching a CLDR name (`zero`, `one`, `two`, `few`, `many`, `other`).
+    ///
+    /// - `name` : The category's name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "zero" => Self::Zero,
+            "one" => Self::One,
+            "two" => Self::Two,
+            "few" => Self::Few,
+            "many" => Self::Many,
+            "other" => Self::Other,
+            _ => return None,
+        })
+    }
+
+    /// Returns the plural category of a count in a locale.
+    /// The rules of the common European, Slavic, Semitic and East Asian languages are supported,
+    /// the other languages use the English rule.
+    ///
+    /// - `locale` : The locale.
+    /// - `count` : The counted amount.
+    pub fn for_count(locale: &str, count: u64) -> Self {
+        let (last_digit, last_two_digits) = (count % 10, count % 100);
+        match language(locale).as_str() {
+            "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" => Self::Other,
+            "fr" | "pt" | "hi" | "bn" if count <= 1 => Self::One,
+            "fr" | "pt" | "hi" | "bn" => Self::Other,
+            "ru" | "uk" | "be" | "sr" | "hr" | "bs" => {
+                if last_digit == 1 && last_two_digits != 11 {
+                    Self::One
+                } else if (2..=4).contains(&last_digit) && !(12..=14).contains(&last_two_digits) {
+                    Self::Few
+                } else {
+                    Self::Many
+                }
+            }
+            "pl" => {
+                if count == 1 {
+                    Self::One
+                } else if (2..=4).contains(&last_digit) && !(12..=14).contains(&last_two_digits) {
+                    Self::Few
+                } else {
+                    Self::Many
+                }
+            }
+            "cs" | "sk" => match count {
+                1 => Self::One,
+                2..=4 => Self::Few,
+                _ => Self::Other,
+            },
+            "ar" => match (count, last_two_digits) {
+                (0, _) => Self::Zero,
+                (1, _) => Self::One,
+                (2, _) => Self::Two,
+                (_, 3..=10) => Self::Few,
+                (_, 11..=99) => Self::Many,
+                _ => Self::Other,
+            },
+            "he" => match count {
+                1 => Self::One,
+                2 => Self::Two,
+                _ => Self::Other,
+            },
+            _ if count == 1 => Self::One,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl TextDirection {
+    /// Returns the writing direction of a locale.
+    ///
+    /// - `locale` : The locale.
+    pub fn for_locale(locale: &str) -> Self {
+        if RTL_LANGUAGES.contains(&language(locale).as_str()) {
+            Self::Rtl
+        } else {
+            Self::Ltr
+        }
+    }
+
+    /// Returns the value of the `dir` attribute.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+        }
+    }
+}
+
+impl Localizer {
+    /// Creates a localizer without catalogs. The arguments are isolated.
+    ///
+    /// - `fallback` : The locale used when a message is missing.
+    pub fn new(fallback: String) -> Self {
+        Self {
+            catalogs: HashMap::new(),
+            fallback: normalize_locale(&fallback),
+            isolate_arguments: true,
+        }
+    }
+
+    /// Adds a catalog, replacing the catalog of the same locale.
+    ///
+    /// - `catalog` : The catalog to add.
+    pub fn with_catalog(mut self, catalog: Catalog) -> Self {
+        self.catalogs.insert(catalog.locale.clone(), catalog);
+        self
+    }
+
+    /// Disables the isolation of the arguments, for outputs not supporting the Unicode isolation characters.
+    pub fn without_isolation(mut self) -> Self {
+        self.isolate_arguments = false;
+        self
+    }
+
+    /// Loads all the catalog files of a folder, named after their locale : `fr-CA.messages`.
+    /// If a file can not be read or is invalid, the Err variant is returned.
+    ///
+    /// - `folder` : The folder's path.
+    pub fn load_directory(mut self, folder: &str) -> Result<Self, I18nError> {
+        let entries =
+            fs::read_dir(folder).map_err(|_| I18nError::MissingFile(folder.to_string()))?;
+        let mut paths = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == CATALOG_EXTENSION))
+            .collect::<Vec<_>>();
+        paths.sort();
+        for path in paths {
+            let locale = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let catalog = Catalog::from_file(locale, &path.to_string_lossy())?;
+            self = self.with_catalog(catalog);
+        }
+        Ok(self)
+    }
+
+    /// Returns a translator for a locale.
+    ///
+    /// - `locale` : The selected locale.
+    pub fn for_locale(&self, locale: &str) -> Translator<'_> {
+        Translator {
+            localizer: self,
+            locale: normalize_locale(locale),
+        }
+    }
+}
+
+impl Translator<'_> {
+    /// Returns the message with its placeholders replaced by the arguments.
+    /// If the message is not defined or if an argument is missing, the Err variant is returned.
+    ///
+    /// - `key` : The message's key.
+    /// - `arguments` : The arguments, by name.
+    pub fn translate(&self, key: &str, arguments: &[(&str, String)]) -> Result<String, I18nError> {
+        let text = match self.message(key)? {
+            Message::Text(text) => text,
+            Message::Plural(forms) => forms.get(&PluralCategory::Other).ok_or_else(|| {
+                I18nError::MissingMessage(self.locale.clone(), format!("{}[other]", key))
+            })?,
+        };
+        self.interpolate(key, text, arguments)
+    }
+
+    /// Returns the plural form of the message matching the count, with its placeholders replaced by the arguments.
+    /// The count is available as the `{count}` argument.
+    /// If the message is not defined or if an argument is missing, the Err variant is returned.
+    ///
+    /// - `key` : The message's key.
+    /// - `count` : The counted amount.
+    /// - `arguments` : The other arguments, by name.
+    pub fn translate_plural(
+        &self,
+        key: &str,
+        count: u64,
+        arguments: &[(&str, String)],
+    ) -> Result<String, I18nError> {
+        let text = match self.message(key)? {
+            Message::Text(text) => text,
+            Message::Plural(forms) => forms
+                .get(&PluralCategory::for_count(&self.locale, count))
+                .or_else(|| forms.get(&PluralCategory::Other))
+                .ok_or_else(|| {
+                    I18nError::MissingMessage(self.locale.clone(), format!("{}[other]", key))
+                })?,
+        };
+        let mut arguments = arguments.to_vec();
+        arguments.push(("count", count.to_string()));
+        self.interpolate(key, text, &arguments)
+    }
+
+    /// Returns the writing direction of the locale.
+    pub fn direction(&self) -> TextDirection {
+        TextDirection::for_locale(&self.locale)
+    }
+
+    /// Internal function. Returns the message from the first catalog of the locale chain defining it.
+    ///
+    /// - `key` : The message's key.
+    fn message(&self, key: &str) -> Result<&Message, I18nError> {
+        let fallback = &self.localizer.fallback;
+        let chain = [
+            self.locale.clone(),
+            language(&self.locale),
+            fallback.clone(),
+            language(fallback),
+        ];
+        chain
+            .iter()
+            .filter_map(|locale| self.localizer.catalogs.get(locale))
+            .find_map(|catalog| catalog.messages.get(key))
+            .ok_or_else(|| I18nError::MissingMessage(self.locale.clone(), key.to_string()))
+    }
+
+    /// Internal function. Replaces the placeholders of a message by the arguments.
+    ///
+    /// - `key` : The message's key, used in the errors.
+    /// - `text` : The message.
+    /// - `arguments` : The arguments, by name.
+    fn interpolate(
+        &self,
+        key: &str,
+        text: &str,
+        arguments: &[(&str, String)],
+    ) -> Result<String, I18nError> {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find(['{', '}']) {
+            result.push_str(&rest[..start]);
+            let brace = &rest[start..start + 1];
+            if rest[start + 1..].starts_with(brace) {
+                result.push_str(brace);
+                rest = &rest[start + 2..];
+                continue;
+            }
+            let end = match (brace, rest[start..].find('}')) {
+                ("{", Some(end)) => start + end,
+                _ => {
+                    result.push_str(brace);
+                    rest = &rest[start + 1..];
+                    continue;
+                }
+            };
+            let name = rest[start + 1..end].trim();
+            let value = arguments
+                .iter()
+                .rev()
+                .find(|(argument, _)| *argument == name)
+                .map(|(_, value)| value)
+                .ok_or_else(|| I18nError::MissingArgument(key.to_string(), name.to_string()))?;
+            if self.localizer.isolate_arguments {
+                result.push(FIRST_STRONG_ISOLATE);
+                result.push_str(value);
+                result.push(POP_DIRECTIONAL_ISOLATE);
+            } else {
+                result.push_str(value);
+            }
+            rest = &rest[end + 1..];
+        }
+        result.push_str(rest);
+        Ok(result)
+    }
+}
+
+/// Returns a `<bdi>` element isolating a user text from the surrounding text direction.
+/// Use it for names, titles or any text whose direction is unknown.
+/// If the element can not be built, the Err variant is returned.
+///
+/// - `text` : The user text, escaped by the function.
+pub fn isolate(text: &str) -> HtmlResult<Element> {
+    Element::Element(HtmlElement::new(
+        TagType::Bdi,
+        HtmlElementConfig::new_empty(),
+    ))
+    .checked_add(Element::Text(escape_html(text)))
+    .map_err(Into::into)
+}
+
+/// Returns the locale with `_` separators replaced by `-` (`pt_BR` becomes `pt-BR`).
+///
+/// - `locale` : The locale.
+pub fn normalize_locale(locale: &str) -> String {
+    locale.trim().replace('_', "-")
+}
+
+/// Internal function. Returns the lowercased language subtag of a locale (`pt` for `pt-BR`).
+///
+/// - `locale` : The locale.
+fn language(locale: &str) -> String {
+    normalize_locale(locale)
+        .split('-')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn localizer() -> Localizer {
+        let english = Catalog::new(String::from("en"))
+            .with_message(String::from("hello"), String::from("Hello {name}!"))
+            .with_message(String::from("braces"), String::from("{{literal}} and }}"))
+            .with_plural_form(
+                String::from("files"),
+                PluralCategory::One,
+                String::from("{count} file"),
+            )
+            .with_plural_form(
+                String::from("files"),
+                PluralCategory::Other,
+                String::from("{count} files"),
+            );
+        let french = Catalog::new(String::from("fr"))
+            .with_message(String::from("hello"), String::from("Bonjour {name} !"));
+        Localizer::new(String::from("en"))
+            .with_catalog(english)
+            .with_catalog(french)
+            .without_isolation()
+    }
+
+    #[test]
+    fn plural_categories_follow_the_locale_rules() {
+        assert_eq!(PluralCategory::for_count("en", 1), PluralCategory::One);
+        assert_eq!(PluralCategory::for_count("en", 0), PluralCategory::Other);
+        assert_eq!(PluralCategory::for_count("fr-CA", 0), PluralCategory::One);
+        assert_eq!(PluralCategory::for_count("ru", 21), PluralCategory::One);
+        assert_eq!(PluralCategory::for_count("ru", 12), PluralCategory::Many);
+        assert_eq!(PluralCategory::for_count("pl", 23), PluralCategory::Few);
+        assert_eq!(PluralCategory::for_count("ar", 2), PluralCategory::Two);
+        assert_eq!(PluralCategory::for_count("ar", 105), PluralCategory::Few);
+        assert_eq!(PluralCategory::for_count("ja", 1), PluralCategory::Other);
+    }
+
+    #[test]
+    fn translate_plural_selects_the_form_and_falls_back_to_other() {
+        let localizer = localizer();
+        let translator = localizer.for_locale("en");
+        assert_eq!(
+            translator.translate_plural("files", 1, &[]).unwrap(),
+            "1 file"
+        );
+        assert_eq!(
+            translator.translate_plural("files", 3, &[]).unwrap(),
+            "3 files"
+        );
+        let translator = localizer.for_locale("ru");
+        assert_eq!(
+            translator.translate_plural("files", 3, &[]).unwrap(),
+            "3 files"
+        );
+    }
+
+    #[test]
+    fn doubled_braces_are_escapes() {
+        let localizer = localizer();
+        let text = localizer.for_locale("en").translate("braces", &[]).unwrap();
+        assert_eq!(text, "{literal} and }");
+    }
+
+    #[test]
+    fn missing_argument_is_an_error() {
+        let localizer = localizer();
+        let error = localizer
+            .for_locale("en")
+            .translate("hello", &[])
+            .unwrap_err();
+        assert!(
+            matches!(error, I18nError::MissingArgument(key, name) if key == "hello" && name == "name")
+        );
+    }
+
+    #[test]
+    fn arguments_are_isolated_by_default() {
+        let localizer = Localizer::new(String::from("en")).with_catalog(
+            Catalog::new(String::from("en"))
+                .with_message(String::from("hello"), String::from("Hello {name}")),
+        );
+        let text = localizer
+            .for_locale("en")
+            .translate("hello", &[("name", String::from("Ada"))])
+            .unwrap();
+        assert_eq!(
+            text,
+            format!(
+                "Hello {}Ada{}",
+                FIRST_STRONG_ISOLATE, POP_DIRECTIONAL_ISOLATE
+            )
+        );
+    }
+
+    #[test]
+    fn messages_fall_back_along_the_locale_chain() {
+        let localizer = localizer();
+        let name = [("name", String::from("Ada"))];
+        let french = localizer.for_locale("fr_CA");
+        assert_eq!(french.translate("hello", &name).unwrap(), "Bonjour Ada !");
+        assert_eq!(french.translate_plural("files", 2, &[]).unwrap(), "2 files");
+        let error = french.translate("unknown", &[]).unwrap_err();
+        assert!(
+            matches!(error, I18nError::MissingMessage(locale, key) if locale == "fr-CA" && key == "unknown")
+        );
+    }
+
+    #[test]
+    fn parse_reads_entries_and_reports_invalid_lines() {
+        let content = "# comment\n\ngreeting = Hi\\nthere\nitems[one] = {count} item\nitems[other] = {count} items\n";
+        let catalog = Catalog::parse(String::from("en"), content, "en.messages").unwrap();
+        let localizer = Localizer::new(String::from("en")).with_catalog(catalog);
+        let translator = localizer.for_locale("en");
+        assert_eq!(translator.translate("greeting", &[]).unwrap(), "Hi\nthere");
+        assert!(translator.translate_plural("items", 1, &[]).is_ok());
+
+        let error = Catalog::parse(String::from("en"), "ok = Fine\nbroken line", "en.messages")
+            .unwrap_err();
+        assert!(matches!(error, I18nError::InvalidLine(file, 2) if file == "en.messages"));
+        let error =
+            Catalog::parse(String::from("en"), "items[several] = x", "en.messages").unwrap_err();
+        assert!(matches!(error, I18nError::InvalidLine(_, 1)));
+    }
+
+    #[test]
+    fn text_direction_depends_on_the_language() {
+        assert_eq!(TextDirection::for_locale("ar-EG"), TextDirection::Rtl);
+        assert_eq!(TextDirection::for_locale("HE"), TextDirection::Rtl);
+        assert_eq!(TextDirection::for_locale("en-US").get_name(), "ltr");
+    }
+}
@@ -0,0 +1,387 @@
+use std::{collections::BTreeMap, fs};
+
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use crate::{
+    assets::{base64_encode, is_local, normalize_path, split_suffix},
+    document::Document,
+    element::Element,
+    errors::{HtmlResult, IntegrityError},
+    miscellaneous::CrossOrigin,
+    tags::TagType,
+};
+
+/// The hash algorithms of the subresource integrity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+/// Computes the subresource integrity hashes of the local scripts and stylesheets of the documents,
+/// and records them to detect the files changed since a previous export.
+///
+/// The checked references are the `src` attribute of the `<script>` elements and the `href` attribute
+/// of the `stylesheet`, `preload` and `modulepreload` `<link>` elements.
+/// The remote references keep the hashes given with `FetchOptions::with_integrity`.
+///
+/// - `source` : The folder containing the local files, the references are relative to this folder.
+/// - `algorithm` : The hash algorithm, `Sha384` by default.
+/// - `recorded` : The recorded hashes, by file path relative to the source folder.
+#[derive(Clone, Debug)]
+pub struct IntegrityRegistry {
+    pub source: String,
+    pub algorithm: HashAlgorithm,
+    pub recorded: BTreeMap<String, String>,
+}
+
+impl HashAlgorithm {
+    /// Returns the algorithm named `sha256`, `sha384` or `sha512`, or None for the other names.
+    ///
+    /// - `name` : The algorithm's name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "sha256" => Some(HashAlgorithm::Sha256),
+            "sha384" => Some(HashAlgorithm::Sha384),
+            "sha512" => Some(HashAlgorithm::Sha512),
+            _ => None,
+        }
+    }
+
+    /// Returns the algorithm's name, used as prefix of the hashes.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Sha384 => "sha384",
+            HashAlgorithm::Sha512 => "sha512",
+        }
+    }
+}
+
+/// Returns the subresource integrity hash of a content (`sha384-...`).
+///
+/// - `content` : The file's content.
+/// - `algorithm` : The hash algorithm.
+pub fn compute_integrity(content: &[u8], algorithm: HashAlgorithm) -> String {
+    let digest = match algorithm {
+        HashAlgorithm::Sha256 => Sha256::digest(content).to_vec(),
+        HashAlgorithm::Sha384 => Sha384::digest(content).to_vec(),
+        HashAlgorithm::Sha512 => Sha512::digest(content).to_vec(),
+    };
+    format!("{}-{}", algorithm.get_name(), base64_encode(&digest))
+}
+
+impl IntegrityRegistry {
+    /// Creates a registry without recorded hash, using the `Sha384` algorithm.
+    ///
+    /// - `source` : The folder containing the local files.
+    pub fn new(source: String) -> Self {
+        Self {
+            source,
+            algorithm: HashAlgorithm::Sha384,
+            recorded: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the hash algorithm.
+    ///
+    /// - `algorithm` : The algorithm.
+    pub fn with_algorithm(mut self, algorithm: HashAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Records the expected hash of a local file.
+    ///
+    /// - `path` : The file's path, relative to the source folder.
+    /// - `integrity` : The hash (`sha384-...`).
+    pub fn with_recorded(mut self, path: String, integrity: String) -> Self {
+        self.recorded.insert(path, integrity);
+        self
+    }
+
+    /// Loads the hashes recorded in a file written by `IntegrityRegistry::save`.
+    /// The file contains a `path hash` entry per line.
+    /// If the file can not be read or if a line is not an entry, the Err variant is returned.
+    ///
+    /// - `file` : The hashes file's path.
+    pub fn load(mut self, file: &str) -> Result<Self, IntegrityError> {
+        let content =
+            fs::read_to_string(file).map_err(|_| IntegrityError::MissingFile(file.to_string()))?;
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            match line.rsplit_once(' ') {
+                Some((path, integrity)) if !path.trim().is_empty() => {
+                    self.recorded
+                        .insert(path.trim().to_string(), integrity.to_string());
+                }
+                _ => return Err(IntegrityError::InvalidLine(file.to_string(), index + 1)),
+            }
+        }
+        Ok(self)
+    }
+
+    /// Writes the recorded hashes to a file, a `path hash` entry per line sorted by path.
+    /// If the file can not be written, the Err variant is returned.
+    ///
+    /// - `file` : The hashes file's path.
+    pub fn save(&self, file: &str) -> HtmlResult<()> {
+        let content = self
+            .recorded
+            .iter()
+            .map(|(path, integrity)| format!("{} {}\n", path, integrity))
+            .collect::<String>();
+        fs::write(file, content)?;
+        Ok(())
+    }
+
+    /// Returns the hash of a local file and records it.
+    /// If the file can not be read or if it differs from its recorded hash, the Err variant is returned.
+    ///
+    /// - `reference` : The file's path, relative to the source folder.
+    pub fn hash_file(&mut self, reference: &str) -> Result<String, IntegrityError> {
+        let path = normalize_path("", reference)
+            .ok_or_else(|| IntegrityError::MissingFile(reference.to_string()))?;
+        let file = format!("{}/{}", self.source, path);
+        let content = fs::read(&file).map_err(|_| IntegrityError::MissingFile(file.clone()))?;
+        if let Some(recorded) = self.recorded.get(&path) {
+            // The recorded hash is checked with its own algorithm, which may differ from the registry's one.
+            let algorithm = recorded
+                .split('-')
+                .next()
+                .and_then(HashAlgorithm::from_name)
+                .unwrap_or(self.algorithm);
+            let computed = compute_integrity(&content, algorithm);
+            if computed != *recorded {
+                return Err(IntegrityError::ChangedFile(
+                    file,
+                    recorded.clone(),
+                    computed,
+                ));
+            }
+        }
+        let computed = compute_integrity(&content, self.algorithm);
+        self.recorded.insert(path, computed.clone());
+        Ok(computed)
+    }
+
+    /// Adds the `integrity` attribute to the local scripts and stylesheets of a document.
+    /// The `crossorigin` attribute is added to the remote resources with an integrity hash.
+    /// If a file can not be read or if it differs from its recorded hash, the Err variant is returned
+    /// with the path of the element.
+    ///
+    /// - `document` : The document to update.
+    pub fn apply(&mut self, document: &mut Document) -> HtmlResult<()> {
+        for (index, element) in document.head.content.iter_mut().enumerate() {
+            self.apply_element(element)
+                .map_err(|error| error.inside("head".to_string(), index))?;
+        }
+        for (index, element) in document.body.iter_mut().enumerate() {
+            self.apply_element(element)
+                .map_err(|error| error.inside("body".to_string(), index))?;
+        }
+        Ok(())
+    }
+
+    /// Adds the integrity hashes to a document and exports it, see `IntegrityRegistry::apply` and `Document::export_to_file`.
+    /// If a file differs from its recorded hash, the document is not written and the Err variant is returned.
+    ///
+    /// - `document` : The document to export.
+    /// - `destination` : The destination folder's path.
+    /// - `filename` : The html file's name.
+    pub fn export_document(
+        &mut self,
+        document: &Document,
+        destination: String,
+        filename: String,
+    ) -> HtmlResult<()> {
+        let mut document = document.clone();
+        self.apply(&mut document)?;
+        document.export_to_file(destination, filename)
+    }
+
+    /// Internal function. Adds the integrity hashes to an element and its sub-elements.
+    ///
+    /// - `element` : The element to update.
+    fn apply_element(&mut self, element: &mut Element) -> HtmlResult<()> {
+        let Element::Element(element) = element else {
+            return Ok(());
+        };
+        let rel = element.config.attributes.get("rel").cloned().flatten();
+        let reference = match element.tag {
+            TagType::Script => "src",
+            TagType::Link
+                if matches!(
+                    rel.as_deref(),
+                    Some("stylesheet" | "preload" | "modulepreload")
+                ) =>
+            {
+                "href"
+            }
+            _ => "",
+        };
+        if let Some(Some(url)) = element.config.attributes.get(reference).cloned() {
+            if is_local(&url) {
+                let (path, _) = split_suffix(&url);
+                let integrity = self.hash_file(path)?;
+                element
+                    .config
+                    .attributes
+                    .insert("integrity".to_string(), Some(integrity));
+            } else if element.config.attributes.contains_key("integrity") {
+                element
+                    .config
+                    .attributes
+                    .entry("crossorigin".to_string())
+                    .or_insert(Some(CrossOrigin::Anonymous.to_string()));
+            }
+        }
+        let tag = String::from(element.tag.clone());
+        for (index, child) in element.children.iter_mut().enumerate() {
+            self.apply_element(child)
+                .map_err(|error| error.inside(tag.clone(), index))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        element::{HtmlElement, HtmlElementConfig},
+        errors::Error,
+        head::{FetchOptions, Head},
+    };
+
+    fn source_folder(name: &str, files: &[(&str, &str)]) -> String {
+        let folder = std::env::temp_dir().join(format!(
+            "html_export_integrity_{}_{}",
+            name,
+            std::process::id()
+        ));
+        fs::create_dir_all(folder.join("js")).unwrap();
+        for (path, content) in files {
+            fs::write(folder.join(path), content).unwrap();
+        }
+        folder.to_string_lossy().to_string()
+    }
+
+    fn attribute(element: &Element, name: &str) -> Option<String> {
+        match element {
+            Element::Element(element) => element.config.attributes.get(name).cloned().flatten(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn compute_integrity_prefixes_the_algorithm() {
+        assert_eq!(
+            compute_integrity(b"abc", HashAlgorithm::Sha256),
+            "sha256-ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0="
+        );
+        assert!(compute_integrity(b"abc", HashAlgorithm::Sha384).starts_with("sha384-"));
+        assert_eq!(HashAlgorithm::from_name("md5"), None);
+    }
+
+    #[test]
+    fn hash_file_records_and_detects_changed_files() {
+        let source = source_folder("changed", &[("js/app.js", "run();")]);
+        let mut registry = IntegrityRegistry::new(source.clone());
+        let first = registry.hash_file("./js/app.js").unwrap();
+        assert_eq!(registry.recorded.get("js/app.js"), Some(&first));
+        assert_eq!(registry.hash_file("js/app.js").unwrap(), first);
+
+        fs::write(format!("{}/js/app.js", source), "stop();").unwrap();
+        let error = registry.hash_file("js/app.js").unwrap_err();
+        fs::remove_dir_all(&source).unwrap();
+        assert!(matches!(
+            error,
+            IntegrityError::ChangedFile(file, recorded, computed)
+                if file.ends_with("js/app.js") && recorded == first && computed != first
+        ));
+    }
+
+    #[test]
+    fn recorded_hashes_are_checked_with_their_own_algorithm() {
+        let source = source_folder("algorithm", &[("js/app.js", "abc")]);
+        let mut registry = IntegrityRegistry::new(source.clone()).with_recorded(
+            String::from("js/app.js"),
+            compute_integrity(b"abc", HashAlgorithm::Sha256),
+        );
+        let integrity = registry.hash_file("js/app.js");
+        fs::remove_dir_all(&source).unwrap();
+        assert!(integrity.unwrap().starts_with("sha384-"));
+    }
+
+    #[test]
+    fn save_and_load_keep_the_recorded_hashes() {
+        let source = source_folder("save", &[]);
+        let file = format!("{}/hashes.txt", source);
+        let registry = IntegrityRegistry::new(source.clone())
+            .with_recorded(String::from("js/b.js"), String::from("sha384-b"))
+            .with_recorded(String::from("css/a.css"), String::from("sha384-a"));
+        registry.save(&file).unwrap();
+        let loaded = IntegrityRegistry::new(source.clone()).load(&file).unwrap();
+        fs::write(&file, "js/a.js sha384-a\nbroken\n").unwrap();
+        let error = IntegrityRegistry::new(source.clone())
+            .load(&file)
+            .unwrap_err();
+        fs::remove_dir_all(&source).unwrap();
+        assert_eq!(loaded.recorded, registry.recorded);
+        assert!(matches!(error, IntegrityError::InvalidLine(_, 2)));
+    }
+
+    #[test]
+    fn apply_adds_integrity_to_local_and_crossorigin_to_remote_resources() {
+        let source = source_folder("apply", &[("js/app.js", "run();")]);
+        let head = Head::new()
+            .with_javascript_file(String::from("js/app.js?v=3"), None)
+            .with_css_file_options(
+                String::from("https://cdn.example.com/style.css"),
+                &FetchOptions::new().with_integrity(String::from("sha384-remote")),
+            );
+        let mut document = Document::new(head, vec![]);
+        let mut registry = IntegrityRegistry::new(source.clone());
+        registry.apply(&mut document).unwrap();
+        fs::remove_dir_all(&source).unwrap();
+        let content = &document.head.content;
+        assert_eq!(
+            attribute(&content[0], "integrity"),
+            Some(compute_integrity(b"run();", HashAlgorithm::Sha384))
+        );
+        assert_eq!(
+            attribute(&content[1], "integrity").as_deref(),
+            Some("sha384-remote")
+        );
+        assert_eq!(
+            attribute(&content[1], "crossorigin").as_deref(),
+            Some("anonymous")
+        );
+    }
+
+    #[test]
+    fn apply_reports_missing_files_with_their_path() {
+        let source = source_folder("missing", &[]);
+        let script = Element::Element(HtmlElement::new(
+            TagType::Script,
+            HtmlElementConfig::new_empty()
+                .set_attribute("src".to_string(), Some("js/missing.js".to_string())),
+        ));
+        let mut document = Document::new(Head::new(), vec![script]);
+        let error = IntegrityRegistry::new(source.clone())
+            .apply(&mut document)
+            .unwrap_err();
+        fs::remove_dir_all(&source).unwrap();
+        assert_eq!(error.path().unwrap().to_string(), "body[0]");
+        assert!(matches!(
+            error,
+            Error::InTree(_, source)
+                if matches!(*source, Error::Integrity(IntegrityError::MissingFile(_)))
+        ));
+    }
+}
@@ -0,0 +1,388 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    document::Document,
+    element::{Element, HtmlElement, HtmlElementConfig},
+    errors::{HtmlResult, LayoutError},
+    head::Head,
+    tags::TagType,
+};
+
+/// The name of the block rendered as the document's `<title>`. It can only contain raw text.
+pub const TITLE_BLOCK: &str = "title";
+
+/// The name of the block appended to the document's `<head>`.
+pub const HEAD_EXTRA_BLOCK: &str = "head_extra";
+
+/// Represents a page layout.
+/// The base layout defines the page structure, in which each block is placed with a slot (`Element::Slot`).
+/// Child layouts override or extend the blocks of their parent, their own body is not used.
+/// The `title` and `head_extra` blocks are always available and are rendered in the document's `<head>`.
+///
+/// - `parent` : The layout this layout inherits from. None for a base layout.
+/// - `head` : The `<head>` entries added by this layout, merged with the parents' ones.
+/// - `body` : The page structure. Only used by the base layout.
+/// - `blocks` : The blocks defined by this layout, by block name.
+#[derive(Clone, Debug)]
+pub struct Layout {
+    pub parent: Option<Box<Layout>>,
+    pub head: Head,
+    pub body: Vec<Element>,
+    pub blocks: HashMap<String, Block>,
+}
+
+/// Defines how a layout changes a block of its parent.
+///
+/// - `Override` : The block's content replaces the parent's one.
+/// - `Extend` : The block's content is appended to the parent's one.
+#[derive(Clone, Debug)]
+pub enum Block {
+    Override(Vec<Element>),
+    Extend(Vec<Element>),
+}
+
+impl Layout {
+    /// Creates a base layout.
+    ///
+    /// - `body` : The page structure, containing a slot for each block.
+    pub fn new(body: Vec<Element>) -> Self {
+        Self {
+            parent: None,
+            head: Head::new(),
+            body,
+            blocks: HashMap::new(),
+        }
+    }
+
+    /// Creates a layout inheriting from another one.
+    ///
+    /// - `parent` : The parent layout.
+    pub fn extend(parent: &Layout) -> Self {
+        Self {
+            parent: Some(Box::new(parent.clone())),
+            ..Self::new(vec![])
+        }
+    }
+
+    /// Sets the `<head>` entries added by this layout.
+    ///
+    /// - `head` : The layout's head entries.
+    pub fn with_head(mut self, head: Head) -> Self {
+        self.head = head;
+        self
+    }
+
+    /// Defines a block, replacing the parent's content.
+    ///
+    /// - `name` : The block's name.
+    /// - `content` : The block's content.
+    pub fn with_block(mut self, name: String, content: Vec<Element>) -> Self {
+        self.blocks.insert(name, Block::Override(content));
+        self
+    }
+
+    /// Defines a block, appended to the parent's content.
+    ///
+    /// - `name` : The block's name.
+    /// - `content` : The content to append.
+    pub fn extend_block(mut self, name: String, content: Vec<Element>) -> Self {
+        self.blocks.insert(name, Block::Extend(content));
+        self
+    }
+
+    /// Defines the `title` block, shortcut for `with_block`.
+    ///
+    /// - `title` : The document's title.
+    pub fn with_title(self, title: String) -> Self {
+        self.with_block(TITLE_BLOCK.to_string(), vec![Element::Text(title)])
+    }
+
+    /// Renders the layout to a document.
+    /// The head entries of all the layouts are merged from the base layout to this one.
+    /// The `title` block replaces the `<title>` entries, its texts are joined. Otherwise the last `<title>` entry is kept.
+    /// The title is placed at the position of the first `<title>` entry.
+    /// The merged head is normalized, see `Head::normalize`.
+    /// The blocks without content are rendered empty.
+    ///
+    /// If a layout defines a block which is not part of the base layout, if the `title` block is not raw text,
+    /// if a `head_extra` element is not allowed in the `<head>` or if two layouts set different charsets,
+    /// base urls, viewports or canonical urls, the Err variant is returned.
+    pub fn render(&self) -> HtmlResult<Document> {
+        let mut levels = vec![self];
+        while let Some(parent) = &levels[levels.len() - 1].parent {
+            levels.push(parent);
+        }
+        levels.reverse();
+
+        let base = levels[0];
+        let mut known_blocks =
+            HashSet::from([TITLE_BLOCK.to_string(), HEAD_EXTRA_BLOCK.to_string()]);
+        for element in base.body.iter() {
+            collect_slots(element, &mut known_blocks);
+        }
+
+        let mut blocks: HashMap<String, Vec<Element>> = HashMap::new();
+        let mut head_content = vec![];
+        for level in levels.iter() {
+            head_content.extend(level.head.content.iter().cloned());
+            let mut names = level.blocks.keys().collect::<Vec<&String>>();
+            names.sort();
+            for name in names {
+                if !known_blocks.contains(name) {
+                    return Err(LayoutError::UnknownBlock(name.clone()).into());
+                }
+                match &level.blocks[name] {
+                    Block::Override(content) => {
+                        blocks.insert(name.clone(), content.clone());
+                    }
+                    Block::Extend(content) => blocks
+                        .entry(name.clone())
+                        .or_default()
+                        .extend(content.iter().cloned()),
+                }
+            }
+        }
+
+        let mut title = match blocks.remove(TITLE_BLOCK) {
+            Some(content) => {
+                let mut text = String::new();
+                for element in content {
+                    match element {
+                        Element::Text(part) => text.push_str(&part),
+                        _ => return Err(LayoutError::InvalidTitle.into()),
+                    }
+                }
+                let mut title = HtmlElement::new(TagType::Title, HtmlElementConfig::new_empty());
+                title.try_push(Element::Text(text))?;
+                Some(Element::Element(title))
+            }
+            None => head_content.iter().rev().find(|e| is_title(e)).cloned(),
+        };
+        let mut head = Head::new();
+        for element in head_content {
+            if !is_title(&element) {
+                head.content.push(element);
+            } else if let Some(title) = title.take() {
+                head.content.push(title);
+            }
+        }
+        head.content.extend(title);
+        for element in blocks.remove(HEAD_EXTRA_BLOCK).unwrap_or_default() {
+            head = head.with_element(element)?;
+        }
+        let head = head.normalize()?;
+
+        let mut body = vec![];
+        for element in base.body.iter() {
+            fill_blocks(element, &blocks, &mut body);
+        }
+        Ok(Document::new(head, body))
+    }
+}
+
+/// Internal function. Adds the names of the slots found in the element and its sub-elements.
+///
+/// - `element` : The element to search.
+/// - `names` : The slots names found so far.
+fn collect_slots(element: &Element, names: &mut HashSet<String>) {
+    match element {
+        Element::Slot(name) => {
+            names.insert(name.clone());
+        }
+        Element::Element(html_element) => {
+            for child in html_element.children.iter() {
+                collect_slots(child, names);
+            }
+        }
+        Element::Text(_) => {}
+    }
+}
+
+/// Internal function. Pushes a copy of the element with its slots replaced by the blocks content.
+/// A slot is replaced by all the elements of its block.
+///
+/// - `element` : The element to fill.
+/// - `blocks` : The blocks content, by block name.
+/// - `filled` : The filled elements.
+fn fill_blocks(
+    element: &Element,
+    blocks: &HashMap<String, Vec<Element>>,
+    filled: &mut Vec<Element>,
+) {
+    match element {
+        Element::Slot(name) => filled.extend(blocks.get(name).into_iter().flatten().cloned()),
+        Element::Element(html_element) => {
+            let mut children = vec![];
+            for child in html_element.children.iter() {
+                fill_blocks(child, blocks, &mut children);
+            }
+            let mut filled_element =
+                HtmlElement::new(html_element.tag.clone(), html_element.config.clone());
+            filled_element.children = children.into_iter().map(Box::new).collect();
+            filled.push(Element::Element(filled_element));
+        }
+        Element::Text(_) => filled.push(element.clone()),
+    }
+}
+
+/// Internal function. Returns true if the element is a `<title>` element.
+///
+/// - `element` : The element to check.
+fn is_title(element: &Element) -> bool {
+    matches!(element, Element::Element(html_element) if matches!(html_element.tag, TagType::Title))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::{Error, HeadCreationError};
+
+    fn paragraph(text: &str) -> Element {
+        Element::Element(HtmlElement::new(TagType::P, HtmlElementConfig::new_empty()))
+            .checked_add(Element::Text(text.to_string()))
+            .unwrap()
+    }
+
+    fn main(children: Vec<Element>) -> Element {
+        let mut main = Element::Element(HtmlElement::new(
+            TagType::Main,
+            HtmlElementConfig::new_empty(),
+        ));
+        main.try_extend(children).unwrap();
+        main
+    }
+
+    fn base() -> Layout {
+        Layout::new(vec![
+            main(vec![Element::Slot("content".to_string())]),
+            Element::Slot("footer".to_string()),
+        ])
+        .with_title("Site".to_string())
+        .with_block("content".to_string(), vec![paragraph("Base")])
+    }
+
+    fn title(document: &Document) -> Vec<Element> {
+        document
+            .head
+            .content
+            .iter()
+            .filter(|element| is_title(element))
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn child_blocks_override_the_parent() {
+        let document = Layout::extend(&base())
+            .with_block("content".to_string(), vec![paragraph("Child")])
+            .render()
+            .unwrap();
+        assert_eq!(document.body, vec![main(vec![paragraph("Child")])]);
+    }
+
+    #[test]
+    fn extended_blocks_are_appended_to_the_parent() {
+        let document = Layout::extend(&base())
+            .extend_block("content".to_string(), vec![paragraph("Child")])
+            .render()
+            .unwrap();
+        assert_eq!(
+            document.body,
+            vec![main(vec![paragraph("Base"), paragraph("Child")])]
+        );
+    }
+
+    #[test]
+    fn blocks_are_inherited_through_several_levels() {
+        let middle = Layout::extend(&base())
+            .extend_block("content".to_string(), vec![paragraph("Middle")])
+            .with_block("footer".to_string(), vec![paragraph("Footer")]);
+        let document = Layout::extend(&middle)
+            .extend_block("content".to_string(), vec![paragraph("Leaf")])
+            .extend_block(
+                TITLE_BLOCK.to_string(),
+                vec![
+                    Element::Text(" | ".to_string()),
+                    Element::Text("Leaf".to_string()),
+                ],
+            )
+            .render()
+            .unwrap();
+        assert_eq!(
+            document.body,
+            vec![
+                main(vec![
+                    paragraph("Base"),
+                    paragraph("Middle"),
+                    paragraph("Leaf")
+                ]),
+                paragraph("Footer"),
+            ]
+        );
+        assert_eq!(
+            title(&document),
+            Head::new().with_title("Site | Leaf".to_string()).content
+        );
+    }
+
+    #[test]
+    fn head_entries_are_merged() {
+        let parent = Layout::new(vec![])
+            .with_head(
+                Head::new()
+                    .with_title("Parent".to_string())
+                    .with_css_file("site.css".to_string())
+                    .with_charset_meta("utf-8".to_string()),
+            )
+            .extend_block(
+                HEAD_EXTRA_BLOCK.to_string(),
+                Head::new().with_css_file("extra.css".to_string()).content,
+            );
+        let document = Layout::extend(&parent)
+            .with_head(
+                Head::new()
+                    .with_css_file("site.css".to_string())
+                    .with_css_file("page.css".to_string())
+                    .with_title("Child".to_string()),
+            )
+            .render()
+            .unwrap();
+        let expected = Head::new()
+            .with_charset_meta("utf-8".to_string())
+            .with_title("Child".to_string())
+            .with_css_file("site.css".to_string())
+            .with_css_file("page.css".to_string())
+            .with_css_file("extra.css".to_string());
+        assert_eq!(document.head.content, expected.content);
+    }
+
+    #[test]
+    fn conflicting_head_entries_are_rejected() {
+        let parent =
+            Layout::new(vec![]).with_head(Head::new().with_charset_meta("utf-8".to_string()));
+        let error = Layout::extend(&parent)
+            .with_head(Head::new().with_charset_meta("latin1".to_string()))
+            .render()
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::HeadCreation(HeadCreationError::Conflict(..))
+        ));
+    }
+
+    #[test]
+    fn unknown_blocks_and_invalid_titles_are_rejected() {
+        let error = Layout::extend(&base())
+            .with_block("sidebar".to_string(), vec![])
+            .render()
+            .unwrap_err();
+        assert!(
+            matches!(error, Error::Layout(LayoutError::UnknownBlock(name)) if name == "sidebar")
+        );
+        let error = Layout::extend(&base())
+            .with_block(TITLE_BLOCK.to_string(), vec![paragraph("Title")])
+            .render()
+            .unwrap_err();
+        assert!(matches!(error, Error::Layout(LayoutError::InvalidTitle)));
+    }
+}
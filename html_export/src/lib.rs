@@ -3,17 +3,37 @@ use std::{
     io::{BufWriter, Write},
 };
 
+use errors::HtmlResult;
 use head::Head;
 use html::Html;
+use i18n::TextDirection;
 
+pub mod assets;
+pub mod component;
 pub mod composed;
+pub mod csp;
+pub mod document;
 pub mod element;
 pub mod errors;
+pub mod formatting;
 pub mod head;
 pub mod html;
+pub mod i18n;
+pub mod integrity;
+pub mod layout;
+pub mod manifest;
+#[cfg(feature = "markdown")]
+pub mod markdown;
 pub mod miscellaneous;
 pub mod prebuild;
+pub mod site;
+pub mod structured_data;
 pub mod tags;
+pub mod template;
+pub mod text;
+
+#[cfg(feature = "derive")]
+pub use html_export_derive::html;
 
 /// Exports the elements to an html file.
 ///
@@ -21,39 +41,100 @@ pub mod tags;
 /// - `filename` : The html file's name.
 /// - `head` : The html document's head.
 /// - `elements` : The elements to export.
+///
+/// If the file can not be written, the Err variant is returned with the path of the failing element.
 pub fn export_to_file<T>(
     destination: String,
     filename: String,
     head: Head,
     elements: Vec<T>,
-) -> std::io::Result<()>
+) -> HtmlResult<()>
+where
+    T: html::Html,
+{
+    write_document(destination, filename, None, head, elements)
+}
+
+/// Exports the elements to an html file, setting the `lang` and `dir` attributes of the `<html>` tag.
+/// The direction is deduced from the locale's language.
+///
+/// - `destination` : The destination folder's path.
+/// - `filename` : The html file's name.
+/// - `locale` : The document's locale (`en`, `ar-EG`...).
+/// - `head` : The html document's head.
+/// - `elements` : The elements to export.
+///
+/// If the file can not be written, the Err variant is returned with the path of the failing element.
+pub fn export_localized_to_file<T>(
+    destination: String,
+    filename: String,
+    locale: &str,
+    head: Head,
+    elements: Vec<T>,
+) -> HtmlResult<()>
+where
+    T: html::Html,
+{
+    write_document(destination, filename, Some(locale), head, elements)
+}
+
+/// Internal function. Writes the html file.
+///
+/// - `destination` : The destination folder's path.
+/// - `filename` : The html file's name.
+/// - `locale` : The document's locale. If None, the `<html>` tag has no attribute.
+/// - `head` : The html document's head.
+/// - `elements` : The elements to export.
+fn write_document<T>(
+    destination: String,
+    filename: String,
+    locale: Option<&str>,
+    head: Head,
+    elements: Vec<T>,
+) -> HtmlResult<()>
 where
     T: html::Html,
 {
     fs::create_dir_all(destination.clone())?;
     let file = File::create(format!("{}/{}", destination, filename))?;
     let mut writer = BufWriter::new(file);
-    writer.write(
-        r#"
+    let attributes = match locale {
+        Some(locale) => format!(
+            " lang=\"{}\" dir=\"{}\"",
+            miscellaneous::escape_html(&i18n::normalize_locale(locale)),
+            TextDirection::for_locale(locale).get_name()
+        ),
+        None => String::new(),
+    };
+    writer.write_all(
+        format!(
+            r#"
 <!DOCTYPE html>
-<html>
+<html{}>
     <head>
-"#
+"#,
+            attributes
+        )
         .as_bytes(),
     )?;
-    for head_tag in head.content {
-        writer = head_tag.write_html(writer, 2)?;
+    for (index, head_tag) in head.content.iter().enumerate() {
+        writer = head_tag
+            .write_html(writer, 2)
+            .map_err(|error| error.inside("head".to_string(), index))?;
     }
-    writer.write(b"\t</head>\n\t<body>\n")?;
-    for element in elements {
-        writer = element.write_html(writer, 2)?;
+    writer.write_all(b"\t</head>\n\t<body>\n")?;
+    for (index, element) in elements.iter().enumerate() {
+        writer = element
+            .write_html(writer, 2)
+            .map_err(|error| error.inside("body".to_string(), index))?;
     }
-    writer.write(
+    writer.write_all(
         r#"
     </body>
 </html>
 "#
         .as_bytes(),
     )?;
+    writer.flush()?;
     Ok(())
 }
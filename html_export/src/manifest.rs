@@ -0,0 +1,277 @@
+use std::{fmt::Display, fs};
+
+use crate::{
+    errors::HtmlResult, head::format_sizes, i18n::TextDirection, structured_data::JsonValue,
+};
+
+/// The conventional file name of the web application manifest.
+pub const MANIFEST_FILENAME: &str = "manifest.webmanifest";
+
+/// The display modes of an installed web application.
+///
+/// - `Fullscreen` : The whole screen is used, without browser interface.
+/// - `Standalone` : The application has its own window, without browser interface.
+/// - `MinimalUi` : The application has its own window, with minimal navigation controls.
+/// - `Browser` : The application is opened in a browser tab.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DisplayMode {
+    Fullscreen,
+    Standalone,
+    MinimalUi,
+    Browser,
+}
+
+/// An icon of the web application.
+///
+/// - `src` : The icon's path.
+/// - `sizes` : The widths and heights contained in the file.
+/// - `mime_type` : The icon's MIME type.
+/// - `maskable` : If true, the icon can be cropped to the platform's shape.
+#[derive(Clone, Debug)]
+pub struct ManifestIcon {
+    pub src: String,
+    pub sizes: Vec<(u32, u32)>,
+    pub mime_type: String,
+    pub maskable: bool,
+}
+
+/// The web application manifest, describing how the site behaves once installed.
+/// Link it with `Head::with_manifest`.
+///
+/// - `name` : The application's name.
+/// - `short_name` : The optional name displayed where the space is limited.
+/// - `description` : The optional description.
+/// - `start_url` : The url opened when the application is launched, `/` by default.
+/// - `scope` : The optional url prefix of the application's pages.
+/// - `display` : The display mode, `Standalone` by default.
+/// - `background_color` : The optional css color of the splash screen.
+/// - `theme_color` : The optional css color of the interface.
+/// - `lang` : The optional locale of the texts.
+/// - `dir` : The optional writing direction of the texts.
+/// - `icons` : The application's icons.
+#[derive(Clone, Debug)]
+pub struct WebManifest {
+    pub name: String,
+    pub short_name: Option<String>,
+    pub description: Option<String>,
+    pub start_url: String,
+    pub scope: Option<String>,
+    pub display: DisplayMode,
+    pub background_color: Option<String>,
+    pub theme_color: Option<String>,
+    pub lang: Option<String>,
+    pub dir: Option<TextDirection>,
+    pub icons: Vec<ManifestIcon>,
+}
+
+impl Display for DisplayMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DisplayMode::Fullscreen => "fullscreen",
+            DisplayMode::Standalone => "standalone",
+            DisplayMode::MinimalUi => "minimal-ui",
+            DisplayMode::Browser => "browser",
+        })
+    }
+}
+
+impl ManifestIcon {
+    /// Creates an icon.
+    ///
+    /// - `src` : The icon's path.
+    /// - `mime_type` : The icon's MIME type.
+    /// - `sizes` : The widths and heights contained in the file.
+    pub fn new(src: String, mime_type: String, sizes: &[(u32, u32)]) -> Self {
+        Self {
+            src,
+            sizes: sizes.to_vec(),
+            mime_type,
+            maskable: false,
+        }
+    }
+
+    /// Allows the platform to crop the icon to its shape.
+    pub fn with_maskable(mut self) -> Self {
+        self.maskable = true;
+        self
+    }
+}
+
+impl WebManifest {
+    /// Creates a manifest.
+    ///
+    /// - `name` : The application's name.
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            short_name: None,
+            description: None,
+            start_url: "/".to_string(),
+            scope: None,
+            display: DisplayMode::Standalone,
+            background_color: None,
+            theme_color: None,
+            lang: None,
+            dir: None,
+            icons: Vec::new(),
+        }
+    }
+
+    /// Sets the name displayed where the space is limited.
+    ///
+    /// - `short_name` : The short name.
+    pub fn with_short_name(mut self, short_name: String) -> Self {
+        self.short_name = Some(short_name);
+        self
+    }
+
+    /// Sets the application's description.
+    ///
+    /// - `description` : The description.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the urls of the application.
+    ///
+    /// - `start_url` : The url opened when the application is launched.
+    /// - `scope` : The optional url prefix of the application's pages.
+    pub fn with_urls(mut self, start_url: String, scope: Option<String>) -> Self {
+        self.start_url = start_url;
+        self.scope = scope;
+        self
+    }
+
+    /// Sets the display mode.
+    ///
+    /// - `display` : The display mode.
+    pub fn with_display(mut self, display: DisplayMode) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Sets the colors of the application.
+    ///
+    /// - `background_color` : The css color of the splash screen.
+    /// - `theme_color` : The css color of the interface, also set it with `Head::with_theme_color`.
+    pub fn with_colors(mut self, background_color: String, theme_color: String) -> Self {
+        self.background_color = Some(background_color);
+        self.theme_color = Some(theme_color);
+        self
+    }
+
+    /// Sets the locale of the texts, and their direction deduced from the locale.
+    ///
+    /// - `locale` : The locale.
+    pub fn with_locale(mut self, locale: &str) -> Self {
+        self.lang = Some(locale.to_string());
+        self.dir = Some(TextDirection::for_locale(locale));
+        self
+    }
+
+    /// Adds an icon to the application.
+    ///
+    /// - `icon` : The icon.
+    pub fn with_icon(mut self, icon: ManifestIcon) -> Self {
+        self.icons.push(icon);
+        self
+    }
+
+    /// Returns the manifest's JSON.
+    pub fn to_json(&self) -> String {
+        let string = |value: &str| JsonValue::String(value.to_string());
+        let mut properties = vec![("name".to_string(), string(&self.name))];
+        let optionals = [
+            ("short_name", &self.short_name),
+            ("description", &self.description),
+            ("scope", &self.scope),
+            ("background_color", &self.background_color),
+            ("theme_color", &self.theme_color),
+            ("lang", &self.lang),
+        ];
+        properties.extend(optionals.into_iter().filter_map(|(key, value)| {
+            value.as_ref().map(|value| (key.to_string(), string(value)))
+        }));
+        properties.push(("start_url".to_string(), string(&self.start_url)));
+        properties.push(("display".to_string(), string(&self.display.to_string())));
+        if let Some(dir) = &self.dir {
+            properties.push(("dir".to_string(), string(dir.get_name())));
+        }
+        let icons = self
+            .icons
+            .iter()
+            .map(|icon| {
+                let mut properties = vec![
+                    ("src".to_string(), string(&icon.src)),
+                    ("sizes".to_string(), string(&format_sizes(&icon.sizes))),
+                    ("type".to_string(), string(&icon.mime_type)),
+                ];
+                if icon.maskable {
+                    properties.push(("purpose".to_string(), string("maskable")));
+                }
+                JsonValue::Object(properties)
+            })
+            .collect();
+        properties.push(("icons".to_string(), JsonValue::Array(icons)));
+        JsonValue::Object(properties).to_json()
+    }
+
+    /// Writes the manifest to a file.
+    /// If the file can not be written, the Err variant is returned.
+    ///
+    /// - `destination` : The destination folder's path.
+    /// - `filename` : The manifest's file name, usually `MANIFEST_FILENAME`.
+    pub fn export_to_file(&self, destination: String, filename: String) -> HtmlResult<()> {
+        fs::create_dir_all(destination.clone())?;
+        fs::write(format!("{}/{}", destination, filename), self.to_json())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimal_manifest_has_the_defaults() {
+        assert_eq!(
+            WebManifest::new("Reports".to_string()).to_json(),
+            r#"{"name":"Reports","start_url":"/","display":"standalone","icons":[]}"#
+        );
+    }
+
+    #[test]
+    fn manifest_lists_all_the_properties() {
+        let manifest = WebManifest::new("Sales & reports".to_string())
+            .with_short_name("Reports".to_string())
+            .with_description("Monthly sales".to_string())
+            .with_urls("/reports/".to_string(), Some("/reports/".to_string()))
+            .with_display(DisplayMode::MinimalUi)
+            .with_colors("#ffffff".to_string(), "#0a84ff".to_string())
+            .with_locale("ar")
+            .with_icon(ManifestIcon::new(
+                "icon.png".to_string(),
+                "image/png".to_string(),
+                &[(192, 192), (512, 512)],
+            ))
+            .with_icon(
+                ManifestIcon::new(
+                    "maskable.png".to_string(),
+                    "image/png".to_string(),
+                    &[(512, 512)],
+                )
+                .with_maskable(),
+            );
+        assert_eq!(
+            manifest.to_json(),
+            concat!(
+                r##"{"name":"Sales \u0026 reports","short_name":"Reports","description":"Monthly sales","##,
+                r##""scope":"/reports/","background_color":"#ffffff","theme_color":"#0a84ff","lang":"ar","##,
+                r##""start_url":"/reports/","display":"minimal-ui","dir":"rtl","icons":["##,
+                r##"{"src":"icon.png","sizes":"192x192 512x512","type":"image/png"},"##,
+                r##"{"src":"maskable.png","sizes":"512x512","type":"image/png","purpose":"maskable"}]}"##
+            )
+        );
+    }
+}
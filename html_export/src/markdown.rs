@@ -0,0 +1,836 @@
+use std::collections::{HashMap, HashSet};
+
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+use crate::{
+    element::{Element, HtmlElement, HtmlElementConfig},
+    miscellaneous::escape_html,
+    tags::TagType,
+};
+
+/// A function rewriting the links and images urls.
+pub type LinkRewriter = Box<dyn Fn(&str) -> String>;
+
+/// Defines how the raw html embedded in the markdown is converted.
+///
+/// - `Escape` : The html is escaped and displayed as text.
+/// - `Keep` : The html is written as is. Only use it with trusted markdown.
+/// - `Remove` : The html is removed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RawHtml {
+    #[default]
+    Escape,
+    Keep,
+    Remove,
+}
+
+/// The options of the markdown conversion.
+///
+/// - `heading_ids` : If true, each heading gets an id generated from its text (`## Sales report` gets `sales-report`).
+///   Duplicate ids get a numeric suffix (`sales-report-1`). An id set with `{#id}` is always kept.
+/// - `id_prefix` : The prefix of the generated heading and footnote ids, to avoid collisions when several
+///   markdown texts are placed in the same document.
+/// - `raw_html` : How the raw html is converted.
+/// - `link_rewriter` : The function applied to the links and images urls (`notes.md` to `notes.html`).
+///   The rewritten urls are checked with `is_safe_url`.
+pub struct MarkdownOptions {
+    pub heading_ids: bool,
+    pub id_prefix: String,
+    pub raw_html: RawHtml,
+    pub link_rewriter: Option<LinkRewriter>,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        Self {
+            heading_ids: true,
+            id_prefix: String::new(),
+            raw_html: RawHtml::Escape,
+            link_rewriter: None,
+        }
+    }
+}
+
+impl MarkdownOptions {
+    /// Creates the default options : heading ids are generated and raw html is escaped.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disables the heading ids generation.
+    pub fn without_heading_ids(mut self) -> Self {
+        self.heading_ids = false;
+        self
+    }
+
+    /// Sets the prefix of the generated ids.
+    ///
+    /// - `prefix` : The ids prefix (`notes-`).
+    pub fn with_id_prefix(mut self, prefix: String) -> Self {
+        self.id_prefix = prefix;
+        self
+    }
+
+    /// Sets how the raw html is converted.
+    ///
+    /// - `raw_html` : The raw html conversion.
+    pub fn with_raw_html(mut self, raw_html: RawHtml) -> Self {
+        self.raw_html = raw_html;
+        self
+    }
+
+    /// Sets the function applied to the links and images urls.
+    ///
+    /// - `rewriter` : The function returning the new url.
+    pub fn with_link_rewriter<F>(mut self, rewriter: F) -> Self
+    where
+        F: Fn(&str) -> String + 'static,
+    {
+        self.link_rewriter = Some(Box::new(rewriter));
+        self
+    }
+}
+
+/// Converts a CommonMark text to elements.
+/// Tables, footnotes, strikethrough and task lists are supported.
+/// The headings attributes only set the id and the classes (`## Title {#id .class}`), the other attributes are dropped.
+/// The footnotes are listed in a `<section class="footnotes">` placed after the other elements.
+/// The links and images urls with an unsafe scheme (`javascript:`...) are replaced with `#`, see `is_safe_url`.
+///
+/// - `markdown` : The markdown text.
+/// - `options` : The conversion options.
+pub fn markdown_to_elements(markdown: &str, options: &MarkdownOptions) -> Vec<Element> {
+    let parser_options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_HEADING_ATTRIBUTES;
+    let mut converter = Converter::new(options);
+    for event in Parser::new_ext(markdown, parser_options) {
+        converter.convert(event);
+    }
+    converter.finish()
+}
+
+/// Internal struct. Builds the elements from the markdown events.
+///
+/// - `options` : The conversion options.
+/// - `stack` : The elements being built, from the outermost to the innermost.
+/// - `elements` : The finished top level elements.
+/// - `ids` : The heading ids already used.
+/// - `heading_text` : The text of the heading being built.
+/// - `image` : The image being built with its alternative text, and the depth of the tags nested in its text.
+/// - `alignments` : The columns alignment of the table being built.
+/// - `cell` : The index of the next cell in the table row.
+/// - `in_table_head` : If true, the cells are header cells.
+/// - `footnote_numbers` : The footnotes numbers, by label, in order of first reference.
+/// - `footnote_label` : The label of the footnote definition being built.
+/// - `footnotes` : The footnote definitions, with their labels.
+struct Converter<'o> {
+    options: &'o MarkdownOptions,
+    stack: Vec<HtmlElement>,
+    elements: Vec<Element>,
+    ids: HashSet<String>,
+    heading_text: Option<String>,
+    image: Option<(HtmlElement, String, usize)>,
+    alignments: Vec<Alignment>,
+    cell: usize,
+    in_table_head: bool,
+    footnote_numbers: HashMap<String, usize>,
+    footnote_label: Option<String>,
+    footnotes: Vec<(String, HtmlElement)>,
+}
+
+impl<'o> Converter<'o> {
+    fn new(options: &'o MarkdownOptions) -> Self {
+        Self {
+            options,
+            stack: vec![],
+            elements: vec![],
+            ids: HashSet::new(),
+            heading_text: None,
+            image: None,
+            alignments: vec![],
+            cell: 0,
+            in_table_head: false,
+            footnote_numbers: HashMap::new(),
+            footnote_label: None,
+            footnotes: vec![],
+        }
+    }
+
+    /// Internal function. Applies a markdown event.
+    ///
+    /// - `event` : The event to apply.
+    fn convert(&mut self, event: Event) {
+        if let Some((_, alt, depth)) = self.image.as_mut() {
+            match event {
+                Event::Start(_) => *depth += 1,
+                Event::End(TagEnd::Image) if *depth == 0 => {
+                    let (mut image, alt, _) = self.image.take().unwrap();
+                    image.config = image
+                        .config
+                        .set_attribute("alt".to_string(), Some(escape_html(&alt)));
+                    self.append(Element::Element(image));
+                }
+                Event::End(_) => *depth -= 1,
+                Event::Text(text) | Event::Code(text) => alt.push_str(&text),
+                _ => {}
+            }
+            return;
+        }
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.push_text(&text),
+            Event::Code(code) => {
+                let element = Element::Element(new_element(TagType::Code))
+                    + Element::Text(escape_html(&code));
+                if let Some(heading_text) = self.heading_text.as_mut() {
+                    heading_text.push_str(&code);
+                }
+                self.append(element);
+            }
+            Event::InlineMath(math) | Event::DisplayMath(math) => self.push_text(&math),
+            Event::Html(html) | Event::InlineHtml(html) => match self.options.raw_html {
+                RawHtml::Escape => self.push_text(&html),
+                RawHtml::Keep => self.append_text(&html),
+                RawHtml::Remove => {}
+            },
+            Event::FootnoteReference(label) => {
+                let next = self.footnote_numbers.len() + 1;
+                let number = *self
+                    .footnote_numbers
+                    .entry(label.to_string())
+                    .or_insert(next);
+                let mut link = new_element(TagType::A)
+                    .with_attribute("href", format!("#{}", self.footnote_id(&label)));
+                if number == next {
+                    link.config = link.config.with_id(self.footnote_reference_id(&label));
+                }
+                let reference = Element::Element(HtmlElement::new(
+                    TagType::Sup,
+                    HtmlElementConfig::new_empty().with_class("footnote-ref".to_string()),
+                )) + (Element::Element(link) + Element::Text(number.to_string()));
+                self.append(reference);
+            }
+            Event::SoftBreak => self.append_text("\n"),
+            Event::HardBreak => self.append(Element::Element(new_element(TagType::Br))),
+            Event::Rule => self.append(Element::Element(new_element(TagType::Hr))),
+            Event::TaskListMarker(checked) => {
+                let mut checkbox =
+                    new_element(TagType::Input).with_attribute("type", "checkbox".to_string());
+                checkbox.config = checkbox.config.set_attribute("disabled".to_string(), None);
+                if checked {
+                    checkbox.config = checkbox.config.set_attribute("checked".to_string(), None);
+                }
+                self.append(Element::Element(checkbox));
+            }
+        }
+    }
+
+    /// Internal function. Opens the element of a markdown tag.
+    ///
+    /// - `tag` : The opened tag.
+    fn start(&mut self, tag: Tag) {
+        let element = match tag {
+            Tag::Paragraph => new_element(TagType::P),
+            Tag::Heading {
+                level,
+                id,
+                classes,
+                attrs: _,
+            } => {
+                let mut config = HtmlElementConfig::new_empty()
+                    .with_classes(classes.iter().map(|class| escape_html(class)));
+                if let Some(id) = id {
+                    self.ids.insert(id.to_string());
+                    config = config.with_id(escape_html(&id));
+                }
+                self.heading_text = Some(String::new());
+                HtmlElement::new(heading_tag(level), config)
+            }
+            Tag::BlockQuote(_) => new_element(TagType::Blockquote),
+            Tag::CodeBlock(kind) => {
+                self.stack.push(new_element(TagType::Pre));
+                let mut code = new_element(TagType::Code);
+                if let CodeBlockKind::Fenced(info) = kind {
+                    if let Some(language) = info.split_whitespace().next() {
+                        code.config = code
+                            .config
+                            .with_class(format!("language-{}", escape_html(language)));
+                    }
+                }
+                code
+            }
+            Tag::HtmlBlock | Tag::MetadataBlock(_) => return,
+            Tag::List(Some(start)) => {
+                let list = new_element(TagType::Ol);
+                if start == 1 {
+                    list
+                } else {
+                    list.with_attribute("start", start.to_string())
+                }
+            }
+            Tag::List(None) => new_element(TagType::Ul),
+            Tag::Item => new_element(TagType::Li),
+            Tag::FootnoteDefinition(label) => {
+                self.footnote_label = Some(label.to_string());
+                HtmlElement::new(
+                    TagType::Li,
+                    HtmlElementConfig::new_empty().with_id(self.footnote_id(&label)),
+                )
+            }
+            Tag::DefinitionList => new_element(TagType::Dl),
+            Tag::DefinitionListTitle => new_element(TagType::Dt),
+            Tag::DefinitionListDefinition => new_element(TagType::Dd),
+            Tag::Table(alignments) => {
+                self.alignments = alignments;
+                new_element(TagType::Table)
+            }
+            Tag::TableHead => {
+                self.in_table_head = true;
+                self.cell = 0;
+                self.stack.push(new_element(TagType::Thead));
+                new_element(TagType::Tr)
+            }
+            Tag::TableRow => {
+                self.cell = 0;
+                if matches!(self.stack.last(), Some(top) if top.tag == TagType::Table) {
+                    self.stack.push(new_element(TagType::Tbody));
+                }
+                new_element(TagType::Tr)
+            }
+            Tag::TableCell => {
+                let tag = if self.in_table_head {
+                    TagType::Th
+                } else {
+                    TagType::Td
+                };
+                let cell = new_element(tag);
+                let alignment = match self.alignments.get(self.cell) {
+                    Some(Alignment::Left) => Some("left"),
+                    Some(Alignment::Center) => Some("center"),
+                    Some(Alignment::Right) => Some("right"),
+                    Some(Alignment::None) | None => None,
+                };
+                self.cell += 1;
+                match alignment {
+                    Some(alignment) => {
+                        cell.with_attribute("style", format!("text-align: {}", alignment))
+                    }
+                    None => cell,
+                }
+            }
+            Tag::Emphasis => new_element(TagType::Em),
+            Tag::Strong => new_element(TagType::Strong),
+            Tag::Strikethrough => new_element(TagType::Del),
+            Tag::Superscript => new_element(TagType::Sup),
+            Tag::Subscript => new_element(TagType::Sub),
+            Tag::Link {
+                dest_url, title, ..
+            } => {
+                let link =
+                    new_element(TagType::A).with_attribute("href", self.rewrite(&dest_url, false));
+                if title.is_empty() {
+                    link
+                } else {
+                    link.with_attribute("title", escape_html(&title))
+                }
+            }
+            Tag::Image {
+                dest_url, title, ..
+            } => {
+                let mut image =
+                    new_element(TagType::Img).with_attribute("src", self.rewrite(&dest_url, true));
+                if !title.is_empty() {
+                    image = image.with_attribute("title", escape_html(&title));
+                }
+                self.image = Some((image, String::new(), 0));
+                return;
+            }
+        };
+        self.stack.push(element);
+    }
+
+    /// Internal function. Closes the element of a markdown tag.
+    ///
+    /// - `tag` : The closed tag.
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::HtmlBlock | TagEnd::MetadataBlock(_) | TagEnd::Image => return,
+            TagEnd::CodeBlock => self.close(),
+            TagEnd::TableHead => {
+                self.close();
+                self.in_table_head = false;
+            }
+            TagEnd::Table => {
+                if matches!(self.stack.last(), Some(top) if top.tag == TagType::Tbody) {
+                    self.close();
+                }
+            }
+            TagEnd::Heading(_) => {
+                let text = self.heading_text.take().unwrap_or_default();
+                if self.options.heading_ids {
+                    if let Some(heading) = self.stack.last_mut() {
+                        if heading.config.id.is_none() {
+                            let id = unique_id(
+                                &mut self.ids,
+                                format!("{}{}", self.options.id_prefix, slugify(&text)),
+                            );
+                            heading.config.id = Some(escape_html(&id));
+                        }
+                    }
+                }
+            }
+            TagEnd::FootnoteDefinition => {
+                if let Some(definition) = self.stack.pop() {
+                    let label = self.footnote_label.take().unwrap_or_default();
+                    self.footnotes.push((label, definition));
+                }
+                return;
+            }
+            _ => {}
+        }
+        self.close();
+    }
+
+    /// Internal function. Closes the innermost element and appends it to its parent.
+    fn close(&mut self) {
+        if let Some(element) = self.stack.pop() {
+            self.append(Element::Element(element));
+        }
+    }
+
+    /// Internal function. Appends an element to the innermost element, or to the top level elements.
+    ///
+    /// - `element` : The element to append.
+    fn append(&mut self, element: Element) {
+        match self.stack.last_mut() {
+            Some(parent) => parent.children.push(Box::new(element)),
+            None => self.elements.push(element),
+        }
+    }
+
+    /// Internal function. Escapes a text and appends it.
+    ///
+    /// - `text` : The text to append.
+    fn push_text(&mut self, text: &str) {
+        if let Some(heading_text) = self.heading_text.as_mut() {
+            heading_text.push_str(text);
+        }
+        self.append_text(&escape_html(text));
+    }
+
+    /// Internal function. Appends an already escaped text, merged with the previous text if any.
+    ///
+    /// - `text` : The text to append.
+    fn append_text(&mut self, text: &str) {
+        if let Some(parent) = self.stack.last_mut() {
+            if let Some(last) = parent.children.last_mut() {
+                if let Element::Text(previous) = last.as_mut() {
+                    previous.push_str(text);
+                    return;
+                }
+            }
+        }
+        self.append(Element::Text(text.to_string()));
+    }
+
+    /// Internal function. Returns the rewritten and escaped url, or `#` if the url is not safe (see `is_safe_url`).
+    ///
+    /// - `url` : The markdown url.
+    /// - `image` : If true, the url is the source of an image.
+    fn rewrite(&self, url: &str, image: bool) -> String {
+        let url = match &self.options.link_rewriter {
+            Some(rewriter) => rewriter(url),
+            None => url.to_string(),
+        };
+        if is_safe_url(&url, image) {
+            escape_html(&url)
+        } else {
+            "#".to_string()
+        }
+    }
+
+    /// Internal function. Returns the id of a footnote definition.
+    ///
+    /// - `label` : The footnote label.
+    fn footnote_id(&self, label: &str) -> String {
+        escape_html(&format!("{}fn-{}", self.options.id_prefix, slugify(label)))
+    }
+
+    /// Internal function. Returns the id of a footnote reference.
+    ///
+    /// - `label` : The footnote label.
+    fn footnote_reference_id(&self, label: &str) -> String {
+        escape_html(&format!(
+            "{}fnref-{}",
+            self.options.id_prefix,
+            slugify(label)
+        ))
+    }
+
+    /// Internal function. Returns the converted elements, followed by the footnotes section.
+    /// The footnotes are sorted by number, the unreferenced ones are placed last.
+    fn finish(mut self) -> Vec<Element> {
+        while !self.stack.is_empty() {
+            self.close();
+        }
+        if self.footnotes.is_empty() {
+            return self.elements;
+        }
+        let mut footnotes = std::mem::take(&mut self.footnotes);
+        footnotes.sort_by_key(|(label, _)| {
+            self.footnote_numbers
+                .get(label)
+                .copied()
+                .unwrap_or(usize::MAX)
+        });
+        let mut list = new_element(TagType::Ol);
+        for (label, mut definition) in footnotes {
+            let mut back = new_element(TagType::A)
+                .with_attribute("href", format!("#{}", self.footnote_reference_id(&label)));
+            back.config = back.config.with_class("footnote-back".to_string());
+            definition.children.push(Box::new(
+                Element::Element(back) + Element::Text("&#8617;".to_string()),
+            ));
+            list.children.push(Box::new(Element::Element(definition)));
+        }
+        let section = HtmlElement::new(
+            TagType::Section,
+            HtmlElementConfig::new_empty().with_class("footnotes".to_string()),
+        );
+        self.elements
+            .push(Element::Element(section) + Element::Element(list));
+        self.elements
+    }
+}
+
+/// Internal trait. Shortcut to set an attribute while building an element.
+trait WithAttribute {
+    fn with_attribute(self, name: &str, value: String) -> Self;
+}
+
+impl WithAttribute for HtmlElement {
+    fn with_attribute(mut self, name: &str, value: String) -> Self {
+        self.config = self.config.set_attribute(name.to_string(), Some(value));
+        self
+    }
+}
+
+/// Internal function. Returns an element without attributes.
+///
+/// - `tag` : The element's tag.
+fn new_element(tag: TagType) -> HtmlElement {
+    HtmlElement::new(tag, HtmlElementConfig::new_empty())
+}
+
+/// Internal function. Returns the tag of a heading level.
+///
+/// - `level` : The heading level.
+fn heading_tag(level: HeadingLevel) -> TagType {
+    match level {
+        HeadingLevel::H1 => TagType::H1,
+        HeadingLevel::H2 => TagType::H2,
+        HeadingLevel::H3 => TagType::H3,
+        HeadingLevel::H4 => TagType::H4,
+        HeadingLevel::H5 => TagType::H5,
+        HeadingLevel::H6 => TagType::H6,
+    }
+}
+
+/// Converts a text to an id : letters are lowercased, whitespaces become hyphens and punctuation is removed.
+/// `Sales report (2024)` becomes `sales-report-2024`.
+///
+/// - `text` : The text to convert.
+pub fn slugify(text: &str) -> String {
+    text.trim()
+        .chars()
+        .filter_map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                Some(c.to_lowercase().collect::<String>())
+            } else if c.is_whitespace() {
+                Some("-".to_string())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns true if a link or image url can be written in the converted elements :
+/// `http`, `https` and `mailto` urls, relative urls and fragments, and `data:image/...` urls for images.
+/// The other schemes (`javascript:`, `vbscript:`, `data:text/html`...) are replaced with `#` during the conversion.
+///
+/// - `url` : The url.
+/// - `image` : If true, the url is the source of an image.
+pub fn is_safe_url(url: &str, image: bool) -> bool {
+    // The browsers ignore the tabs and line breaks inside a url, and the leading spaces and control characters.
+    let url = url
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect::<String>();
+    let url = url.trim_start_matches(|c: char| c <= ' ');
+    let Some(colon) = url.find(':') else {
+        return true;
+    };
+    if url[..colon].contains(['/', '?', '#']) {
+        return true;
+    }
+    match url[..colon].to_lowercase().as_str() {
+        "http" | "https" | "mailto" => true,
+        "data" => {
+            image
+                && url[colon + 1..]
+                    .trim_start()
+                    .to_lowercase()
+                    .starts_with("image/")
+        }
+        _ => false,
+    }
+}
+
+/// Internal function. Returns the id, with a numeric suffix if it is already used, and marks it as used.
+///
+/// - `ids` : The ids already used.
+/// - `id` : The wanted id.
+fn unique_id(ids: &mut HashSet<String>, id: String) -> String {
+    let mut unique = id.clone();
+    let mut suffix = 1;
+    while ids.contains(&unique) {
+        unique = format!("{}-{}", id, suffix);
+        suffix += 1;
+    }
+    ids.insert(unique.clone());
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the value of an attribute of the first element with the given tag.
+    fn find_attribute(elements: &[Element], tag: TagType, attribute: &str) -> Option<String> {
+        elements.iter().find_map(|element| match element {
+            Element::Element(element) if element.tag == tag => {
+                element.config.attributes.get(attribute).cloned().flatten()
+            }
+            Element::Element(element) => {
+                let children = element
+                    .children
+                    .iter()
+                    .map(|child| *child.clone())
+                    .collect::<Vec<Element>>();
+                find_attribute(&children, tag.clone(), attribute)
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the elements as compact html, with sorted attributes.
+    fn outline(elements: &[Element]) -> String {
+        elements
+            .iter()
+            .map(|element| match element {
+                Element::Text(text) => text.clone(),
+                Element::Slot(name) => format!("{{{}}}", name),
+                Element::Element(element) => {
+                    let tag = element.tag.get_tag_name();
+                    let mut attributes = element
+                        .config
+                        .attributes
+                        .iter()
+                        .map(|(name, value)| match value {
+                            Some(value) => format!(" {}=\"{}\"", name, value),
+                            None => format!(" {}", name),
+                        })
+                        .collect::<Vec<String>>();
+                    if let Some(id) = &element.config.id {
+                        attributes.push(format!(" id=\"{}\"", id));
+                    }
+                    let mut classes = element
+                        .config
+                        .classes
+                        .iter()
+                        .cloned()
+                        .collect::<Vec<String>>();
+                    if !classes.is_empty() {
+                        classes.sort();
+                        attributes.push(format!(" class=\"{}\"", classes.join(" ")));
+                    }
+                    attributes.sort();
+                    let children = element
+                        .children
+                        .iter()
+                        .map(|child| *child.clone())
+                        .collect::<Vec<Element>>();
+                    format!(
+                        "<{}{}>{}</{}>",
+                        tag,
+                        attributes.concat(),
+                        outline(&children),
+                        tag
+                    )
+                }
+            })
+            .collect()
+    }
+
+    fn convert(markdown: &str) -> String {
+        outline(&markdown_to_elements(markdown, &MarkdownOptions::new()))
+    }
+
+    fn link(markdown: &str) -> Option<String> {
+        let elements = markdown_to_elements(markdown, &MarkdownOptions::new());
+        find_attribute(&elements, TagType::A, "href")
+    }
+
+    fn image(markdown: &str) -> Option<String> {
+        let elements = markdown_to_elements(markdown, &MarkdownOptions::new());
+        find_attribute(&elements, TagType::Img, "src")
+    }
+
+    #[test]
+    fn inline_formatting_is_converted() {
+        assert_eq!(
+            convert("Some *em*, **strong**, ~~del~~ and `a < b`."),
+            "<p>Some <em>em</em>, <strong>strong</strong>, <del>del</del> and <code>a &lt; b</code>.</p>"
+        );
+    }
+
+    #[test]
+    fn headings_get_unique_ids() {
+        assert_eq!(
+            convert("# Sales report\n## Sales report\n## Custom {#totals}"),
+            "<h1 id=\"sales-report\">Sales report</h1><h2 id=\"sales-report-1\">Sales report</h2><h2 id=\"totals\">Custom</h2>"
+        );
+        let options = MarkdownOptions::new().with_id_prefix("notes-".to_string());
+        assert_eq!(
+            outline(&markdown_to_elements("# Title", &options)),
+            "<h1 id=\"notes-title\">Title</h1>"
+        );
+        let options = MarkdownOptions::new().without_heading_ids();
+        assert_eq!(
+            outline(&markdown_to_elements("# Title", &options)),
+            "<h1>Title</h1>"
+        );
+    }
+
+    #[test]
+    fn heading_attributes_only_set_the_id_and_classes() {
+        let elements = markdown_to_elements(
+            "## Title {#x onmouseover=alert(1) style=x}",
+            &MarkdownOptions::new(),
+        );
+        assert_eq!(outline(&elements), "<h2 id=\"x\">Title</h2>");
+        assert_eq!(find_attribute(&elements, TagType::H2, "onmouseover"), None);
+        assert_eq!(find_attribute(&elements, TagType::H2, "style"), None);
+        assert_eq!(
+            convert("# Notes {.wide .print data-level=1}"),
+            "<h1 class=\"print wide\" id=\"notes\">Notes</h1>"
+        );
+    }
+
+    #[test]
+    fn raw_html_follows_the_options() {
+        let markdown = "Text <b>bold</b>";
+        assert_eq!(convert(markdown), "<p>Text &lt;b&gt;bold&lt;/b&gt;</p>");
+        let options = MarkdownOptions::new().with_raw_html(RawHtml::Remove);
+        assert_eq!(
+            outline(&markdown_to_elements(markdown, &options)),
+            "<p>Text bold</p>"
+        );
+        let options = MarkdownOptions::new().with_raw_html(RawHtml::Keep);
+        assert_eq!(
+            outline(&markdown_to_elements(markdown, &options)),
+            "<p>Text <b>bold</b></p>"
+        );
+    }
+
+    #[test]
+    fn code_blocks_keep_their_language() {
+        assert_eq!(
+            convert("```rust\nlet a = 1 < 2;\n```"),
+            "<pre><code class=\"language-rust\">let a = 1 &lt; 2;\n</code></pre>"
+        );
+    }
+
+    #[test]
+    fn lists_and_tables_are_converted() {
+        assert_eq!(convert("- a\n- b"), "<ul><li>a</li><li>b</li></ul>");
+        assert_eq!(
+            convert("| Name | Total |\n|:-----|------:|\n| A | 1 |"),
+            "<table><thead><tr><th style=\"text-align: left\">Name</th><th style=\"text-align: right\">Total</th></tr></thead>\
+             <tbody><tr><td style=\"text-align: left\">A</td><td style=\"text-align: right\">1</td></tr></tbody></table>"
+        );
+    }
+
+    #[test]
+    fn footnotes_are_listed_after_the_content() {
+        let html = convert("Sales[^1].\n\n[^1]: In euros.");
+        assert!(html.starts_with("<p>Sales<sup"));
+        assert!(html.contains("<section class=\"footnotes\">"));
+        assert!(html.contains("id=\"fn-1\""));
+        assert!(html.contains("href=\"#fnref-1\""));
+    }
+
+    #[test]
+    fn safe_links_are_kept() {
+        assert_eq!(
+            link("[a](https://example.com/?a=1&b=2)").unwrap(),
+            "https://example.com/?a=1&amp;b=2"
+        );
+        assert_eq!(
+            link("[a](http://example.com)").unwrap(),
+            "http://example.com"
+        );
+        assert_eq!(
+            link("[a](mailto:team@example.com)").unwrap(),
+            "mailto:team@example.com"
+        );
+        assert_eq!(link("[a](notes/report.html)").unwrap(), "notes/report.html");
+        assert_eq!(link("[a](#totals)").unwrap(), "#totals");
+        assert_eq!(link("[a](./a:b)").unwrap(), "./a:b");
+    }
+
+    #[test]
+    fn javascript_links_are_replaced() {
+        assert_eq!(link("[a](javascript:alert(1))").unwrap(), "#");
+        assert_eq!(link("[a](JavaScript:alert(1))").unwrap(), "#");
+        assert_eq!(link("[a](<java\tscript:alert(1)>)").unwrap(), "#");
+        assert_eq!(link("[a](javascript&#58;alert(1))").unwrap(), "#");
+        assert_eq!(link("<javascript:alert(1)>").unwrap(), "#");
+    }
+
+    #[test]
+    fn vbscript_links_are_replaced() {
+        assert_eq!(link("[a](vbscript:msgbox)").unwrap(), "#");
+        assert_eq!(image("![a](VBScript:msgbox)").unwrap(), "#");
+    }
+
+    #[test]
+    fn data_urls_are_only_kept_for_images() {
+        assert_eq!(
+            link("[a](data:text/html;base64,PHNjcmlwdD4=)").unwrap(),
+            "#"
+        );
+        assert_eq!(
+            image("![a](data:text/html;base64,PHNjcmlwdD4=)").unwrap(),
+            "#"
+        );
+        assert_eq!(link("[a](data:image/png;base64,iVBORw0=)").unwrap(), "#");
+        assert_eq!(
+            image("![a](data:image/png;base64,iVBORw0=)").unwrap(),
+            "data:image/png;base64,iVBORw0="
+        );
+    }
+
+    #[test]
+    fn rewritten_links_are_checked() {
+        let options =
+            MarkdownOptions::new().with_link_rewriter(|url| format!("javascript:{}", url));
+        let elements = markdown_to_elements("[a](notes.md)", &options);
+        assert_eq!(find_attribute(&elements, TagType::A, "href").unwrap(), "#");
+    }
+}
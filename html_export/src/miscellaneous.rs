@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 /// Defines the wrap mode behavior.
 #[derive(Clone, Debug)]
 pub enum WrapMode {
@@ -24,24 +26,233 @@ pub enum ScriptLoadMode {
     Async,
 }
 
-impl ToString for BaseUrlTarget {
-    fn to_string(&self) -> String {
-        match self {
+/// Specifies the credentials mode of a cross-origin request (`crossorigin` attribute).
+#[derive(Clone, Debug, PartialEq)]
+pub enum CrossOrigin {
+    Anonymous,
+    UseCredentials,
+}
+
+/// Specifies the referrer sent when fetching a resource (`referrerpolicy` attribute).
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReferrerPolicy {
+    NoReferrer,
+    NoReferrerWhenDowngrade,
+    Origin,
+    OriginWhenCrossOrigin,
+    SameOrigin,
+    StrictOrigin,
+    StrictOriginWhenCrossOrigin,
+    UnsafeUrl,
+}
+
+/// Specifies the resource hint of a `<link>` tag (`rel` attribute).
+///
+/// - `Preload` : Fetches a resource needed by the current page.
+/// - `ModulePreload` : Fetches and compiles a javascript module needed by the current page.
+/// - `Prefetch` : Fetches a resource probably needed by the next page.
+/// - `Preconnect` : Opens a connection to an origin.
+/// - `DnsPrefetch` : Resolves the domain name of an origin.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResourceHint {
+    Preload,
+    ModulePreload,
+    Prefetch,
+    Preconnect,
+    DnsPrefetch,
+}
+
+/// Specifies the type of a preloaded resource (`as` attribute).
+#[derive(Clone, Debug, PartialEq)]
+pub enum PreloadDestination {
+    Audio,
+    Document,
+    Fetch,
+    Font,
+    Image,
+    Script,
+    Style,
+    Track,
+    Video,
+    Worker,
+}
+
+/// Specifies the pragma directive of a `<meta http-equiv>` tag.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HttpEquiv {
+    ContentSecurityPolicy,
+    ContentType,
+    DefaultStyle,
+    Refresh,
+    XUaCompatible,
+}
+
+/// Specifies the format of an alternate feed of the page.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+    Json,
+}
+
+impl Display for BaseUrlTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
             BaseUrlTarget::_Blank => "_blank",
             BaseUrlTarget::_Parent => "_parent",
             BaseUrlTarget::_Self => "_self",
             BaseUrlTarget::_Top => "_top",
-        }
-        .to_string()
+        })
     }
 }
 
-impl ToString for ScriptLoadMode {
-    fn to_string(&self) -> String {
-        match self {
+impl Display for ScriptLoadMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
             ScriptLoadMode::Defer => "defer",
             ScriptLoadMode::Async => "async",
+        })
+    }
+}
+
+impl Display for CrossOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CrossOrigin::Anonymous => "anonymous",
+            CrossOrigin::UseCredentials => "use-credentials",
+        })
+    }
+}
+
+impl Display for ReferrerPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ReferrerPolicy::NoReferrer => "no-referrer",
+            ReferrerPolicy::NoReferrerWhenDowngrade => "no-referrer-when-downgrade",
+            ReferrerPolicy::Origin => "origin",
+            ReferrerPolicy::OriginWhenCrossOrigin => "origin-when-cross-origin",
+            ReferrerPolicy::SameOrigin => "same-origin",
+            ReferrerPolicy::StrictOrigin => "strict-origin",
+            ReferrerPolicy::StrictOriginWhenCrossOrigin => "strict-origin-when-cross-origin",
+            ReferrerPolicy::UnsafeUrl => "unsafe-url",
+        })
+    }
+}
+
+impl Display for ResourceHint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ResourceHint::Preload => "preload",
+            ResourceHint::ModulePreload => "modulepreload",
+            ResourceHint::Prefetch => "prefetch",
+            ResourceHint::Preconnect => "preconnect",
+            ResourceHint::DnsPrefetch => "dns-prefetch",
+        })
+    }
+}
+
+impl Display for PreloadDestination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PreloadDestination::Audio => "audio",
+            PreloadDestination::Document => "document",
+            PreloadDestination::Fetch => "fetch",
+            PreloadDestination::Font => "font",
+            PreloadDestination::Image => "image",
+            PreloadDestination::Script => "script",
+            PreloadDestination::Style => "style",
+            PreloadDestination::Track => "track",
+            PreloadDestination::Video => "video",
+            PreloadDestination::Worker => "worker",
+        })
+    }
+}
+
+impl Display for HttpEquiv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            HttpEquiv::ContentSecurityPolicy => "content-security-policy",
+            HttpEquiv::ContentType => "content-type",
+            HttpEquiv::DefaultStyle => "default-style",
+            HttpEquiv::Refresh => "refresh",
+            HttpEquiv::XUaCompatible => "x-ua-compatible",
+        })
+    }
+}
+
+impl FeedFormat {
+    /// Returns the MIME type of the feed format.
+    pub fn get_mime_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml",
+            FeedFormat::Atom => "application/atom+xml",
+            FeedFormat::Json => "application/feed+json",
+        }
+    }
+}
+
+/// Escapes the Html special characters (`&`, `<`, `>`, `"`, `'`) of a text.
+///
+/// - `text` : The text to escape.
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Replaces the Html character references of a text by their characters.
+/// The named references of the Html special characters and `&nbsp;` are supported,
+/// as well as the numeric references (`&#39;`, `&#x27;`). Unknown references are kept.
+///
+/// - `text` : The text to unescape.
+pub fn unescape_html(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let reference = rest[1..]
+            .find(';')
+            .map(|end| &rest[1..end + 1])
+            .filter(|reference| reference.len() <= 10);
+        let character = reference.and_then(|reference| match reference {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => {
+                let code = match reference.strip_prefix('#') {
+                    Some(hex) if hex.starts_with(['x', 'X']) => {
+                        u32::from_str_radix(&hex[1..], 16).ok()
+                    }
+                    Some(decimal) => decimal.parse().ok(),
+                    None => None,
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+        match (reference, character) {
+            (Some(reference), Some(character)) => {
+                unescaped.push(character);
+                rest = &rest[reference.len() + 2..];
+            }
+            _ => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
         }
-        .to_string()
     }
+    unescaped.push_str(rest);
+    unescaped
 }
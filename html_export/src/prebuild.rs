@@ -243,6 +243,14 @@ macro_rules! text {
     };
 }
 
+#[macro_export]
+/// Creates a named slot, replaced by an element when the template is filled.
+macro_rules! slot {
+    ($name:expr) => {
+        html_export::element::Element::Slot($name.to_string())
+    };
+}
+
 #[macro_export]
 /// Creates a new `P` element. Same behavior as the `elem!` macro.
 macro_rules! p {
@@ -0,0 +1,473 @@
+use std::{collections::HashMap, fs};
+
+use crate::{
+    assets::AssetRegistry,
+    document::Document,
+    element::{Element, HtmlElement, HtmlElementConfig},
+    errors::{HtmlResult, SiteError},
+    head::Head,
+    miscellaneous::escape_html,
+    tags::TagType,
+};
+
+/// The prefix of the `href` attributes linking to another page of the site : `route:reports/march#total`.
+/// The link is replaced by the relative path of the page when the site is exported.
+pub const ROUTE_PREFIX: &str = "route:";
+
+/// The file path of the index page.
+pub const INDEX_PATH: &str = "index.html";
+
+/// Represents a static site made of several pages.
+/// Each page has a route converted to a file path : `reports/march` is written to `reports/march.html`
+/// and the empty route is written to `index.html`.
+/// When the index page is enabled, the empty route links to it and can not be used by a page.
+///
+/// - `title` : The site's title, used by the index page.
+/// - `base_url` : The site's public url, used by the sitemap (`https://example.com`).
+/// - `pages` : The site's pages.
+/// - `index` : If true, an index page listing all the pages is written to `index.html`.
+/// - `assets` : The registry copying the local files referenced by the pages. If the value is None, the references are left unchanged.
+#[derive(Clone, Debug)]
+pub struct Site {
+    pub title: String,
+    pub base_url: String,
+    pub pages: Vec<Page>,
+    pub index: bool,
+    pub assets: Option<AssetRegistry>,
+}
+
+/// Represents a page of a site.
+///
+/// - `route` : The page's route (`reports/march`).
+/// - `title` : The page's title, used by the index page.
+/// - `document` : The page's content.
+#[derive(Clone, Debug)]
+pub struct Page {
+    pub route: String,
+    pub title: String,
+    pub document: Document,
+}
+
+impl Site {
+    /// Creates a site without pages.
+    ///
+    /// - `title` : The site's title.
+    /// - `base_url` : The site's public url.
+    pub fn new(title: String, base_url: String) -> Self {
+        Self {
+            title,
+            base_url,
+            pages: vec![],
+            index: true,
+            assets: None,
+        }
+    }
+
+    /// Adds a page to the site.
+    ///
+    /// - `route` : The page's route.
+    /// - `title` : The page's title.
+    /// - `document` : The page's content.
+    pub fn with_page(mut self, route: String, title: String, document: Document) -> Self {
+        self.pages.push(Page {
+            route,
+            title,
+            document,
+        });
+        self
+    }
+
+    /// Disables the index page.
+    pub fn without_index(mut self) -> Self {
+        self.index = false;
+        self
+    }
+
+    /// Copies the local files referenced by the pages with the given registry.
+    ///
+    /// - `assets` : The asset registry.
+    pub fn with_assets(mut self, assets: AssetRegistry) -> Self {
+        self.assets = Some(assets);
+        self
+    }
+
+    /// Returns the file path of each page, including the index page, by route.
+    /// If a route is invalid or if two pages map to the same path, the Err variant is returned.
+    pub fn paths(&self) -> Result<HashMap<String, String>, SiteError> {
+        let mut paths = HashMap::new();
+        let mut routes = HashMap::new();
+        if self.index {
+            routes.insert(INDEX_PATH.to_string(), String::new());
+            paths.insert(String::new(), INDEX_PATH.to_string());
+        }
+        for page in self.pages.iter() {
+            let path = route_to_path(&page.route)?;
+            if let Some(other) = routes.insert(path.clone(), page.route.clone()) {
+                return Err(SiteError::DuplicatePath(path, other, page.route.clone()));
+            }
+            paths.insert(normalize_route(&page.route), path);
+        }
+        Ok(paths)
+    }
+
+    /// Writes all the pages, the index page and `sitemap.xml` into the destination folder.
+    /// The `route:` links of the pages, including the `<head>` links (canonical, alternate, prev, next...), are replaced by relative paths.
+    /// If the site has an asset registry, the referenced files are copied too.
+    ///
+    /// The routes, the `route:` links and the referenced files are checked before any file is written :
+    /// if a route is invalid, if two pages map to the same path, if a page links to an unknown route
+    /// or if a referenced file is missing, the Err variant is returned and the destination is left unchanged.
+    /// The pages are then rendered one after the other : if a page can not be rendered or written,
+    /// the Err variant is returned and the pages written before it are left in the destination.
+    ///
+    /// - `destination` : The destination folder's path.
+    pub fn export_to_directory(&self, destination: String) -> HtmlResult<()> {
+        let paths = self.paths()?;
+        let mut assets = self.assets.clone();
+        let mut documents = vec![];
+        for page in self.pages.iter() {
+            let path = &paths[&normalize_route(&page.route)];
+            let mut document = page.document.clone();
+            for element in document.head.content.iter_mut() {
+                resolve_links(element, path, &paths)?;
+            }
+            for element in document.body.iter_mut() {
+                resolve_links(element, path, &paths)?;
+            }
+            if let Some(assets) = assets.as_mut() {
+                assets.rewrite_document(&mut document, path)?;
+            }
+            documents.push((path, document));
+        }
+        if self.index {
+            documents.push((&paths[""], self.index_document(&paths)?));
+        }
+
+        for (path, document) in documents {
+            let (folder, filename) = match path.rsplit_once('/') {
+                Some((folder, filename)) => (format!("{}/{}", destination, folder), filename),
+                None => (destination.clone(), path.as_str()),
+            };
+            document.export_to_file(folder, filename.to_string())?;
+        }
+        if let Some(assets) = assets {
+            assets.export_to_directory(destination.clone())?;
+        }
+        fs::write(format!("{}/sitemap.xml", destination), self.sitemap(&paths))?;
+        Ok(())
+    }
+
+    /// Internal function. Returns the index page, listing all the pages sorted by route.
+    /// If an element of the page can not be built, the Err variant is returned.
+    ///
+    /// - `paths` : The file path of each page, by route.
+    fn index_document(&self, paths: &HashMap<String, String>) -> HtmlResult<Document> {
+        let mut list = HtmlElement::new(TagType::Ul, HtmlElementConfig::new_empty());
+        let mut pages = self.pages.iter().collect::<Vec<&Page>>();
+        pages.sort_by(|a, b| a.route.cmp(&b.route));
+        for page in pages {
+            let link = Element::Element(HtmlElement::new(
+                TagType::A,
+                HtmlElementConfig::new_empty().set_attribute(
+                    "href".to_string(),
+                    Some(paths[&normalize_route(&page.route)].clone()),
+                ),
+            ))
+            .checked_add(Element::Text(escape_html(&page.title)))?;
+            list.try_push(
+                Element::Element(HtmlElement::new(
+                    TagType::Li,
+                    HtmlElementConfig::new_empty(),
+                ))
+                .checked_add(link)?,
+            )?;
+        }
+        let heading = Element::Element(HtmlElement::new(
+            TagType::H1,
+            HtmlElementConfig::new_empty(),
+        ))
+        .checked_add(Element::Text(escape_html(&self.title)))?;
+        Ok(Document::new(
+            Head::new().with_title(escape_html(&self.title)),
+            vec![heading, Element::Element(list)],
+        ))
+    }
+
+    /// Internal function. Returns the `sitemap.xml` content, listing all the pages sorted by path.
+    ///
+    /// - `paths` : The file path of each page, by route.
+    fn sitemap(&self, paths: &HashMap<String, String>) -> String {
+        let mut sorted = paths.values().collect::<Vec<&String>>();
+        sorted.sort();
+        let base_url = self.base_url.trim_end_matches('/');
+        let mut sitemap = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+        );
+        for path in sorted {
+            sitemap.push_str(&format!(
+                "\t<url>\n\t\t<loc>{}</loc>\n\t</url>\n",
+                escape_html(&format!("{}/{}", base_url, path))
+            ));
+        }
+        sitemap.push_str("</urlset>\n");
+        sitemap
+    }
+}
+
+/// Returns the relative link from a page to another one.
+///
+/// - `from` : The file path of the page containing the link (`reports/2024/march.html`).
+/// - `to` : The file path of the linked page (`reports/index.html`).
+pub fn relative_link(from: &str, to: &str) -> String {
+    let from_folders = from.split('/').collect::<Vec<&str>>();
+    let from_folders = &from_folders[..from_folders.len() - 1];
+    let to_segments = to.split('/').collect::<Vec<&str>>();
+    let common = from_folders
+        .iter()
+        .zip(to_segments.iter())
+        .take_while(|(a, b)| a == b)
+        .count()
+        .min(to_segments.len() - 1);
+    let mut link = "../".repeat(from_folders.len() - common);
+    link.push_str(&to_segments[common..].join("/"));
+    link
+}
+
+/// Internal function. Returns the route without its leading and trailing slashes.
+///
+/// - `route` : The route to normalize.
+fn normalize_route(route: &str) -> String {
+    route.trim_matches('/').to_string()
+}
+
+/// Internal function. Returns the file path of a route.
+/// If the route contains an empty, `.` or `..` segment, the Err variant is returned.
+///
+/// - `route` : The route to convert.
+fn route_to_path(route: &str) -> Result<String, SiteError> {
+    let route = normalize_route(route);
+    if route.is_empty() {
+        return Ok(INDEX_PATH.to_string());
+    }
+    if route
+        .split('/')
+        .any(|segment| segment.is_empty() || segment == "." || segment == "..")
+    {
+        return Err(SiteError::InvalidRoute(route));
+    }
+    if route.ends_with(".html") {
+        Ok(route)
+    } else {
+        Ok(format!("{}.html", route))
+    }
+}
+
+/// Internal function. Replaces the `route:` links of the element and its sub-elements by relative paths.
+///
+/// - `element` : The element to update.
+/// - `path` : The file path of the page containing the element.
+/// - `paths` : The file path of each page, by route.
+fn resolve_links(
+    element: &mut Element,
+    path: &str,
+    paths: &HashMap<String, String>,
+) -> Result<(), SiteError> {
+    if let Element::Element(html_element) = element {
+        if let Some(Some(href)) = html_element.config.attributes.get_mut("href") {
+            if let Some(target) = href.strip_prefix(ROUTE_PREFIX) {
+                let (route, fragment) = match target.split_once('#') {
+                    Some((route, fragment)) => (route, Some(fragment)),
+                    None => (target, None),
+                };
+                let target_path = paths
+                    .get(&normalize_route(route))
+                    .ok_or_else(|| SiteError::UnknownRoute(path.to_string(), route.to_string()))?;
+                let mut link = relative_link(path, target_path);
+                if let Some(fragment) = fragment {
+                    link = format!("{}#{}", link, fragment);
+                }
+                *href = link;
+            }
+        }
+        for child in html_element.children.iter_mut() {
+            resolve_links(child, path, paths)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::Error;
+
+    fn page() -> Document {
+        Document::new(Head::new(), vec![])
+    }
+
+    fn link(href: &str) -> Element {
+        Element::Element(HtmlElement::new(
+            TagType::A,
+            HtmlElementConfig::new_empty()
+                .set_attribute("href".to_string(), Some(href.to_string())),
+        ))
+    }
+
+    #[test]
+    fn routes_are_converted_to_paths() {
+        let site = Site::new("Reports".to_string(), "https://example.com".to_string())
+            .with_page("/reports/march/".to_string(), "March".to_string(), page())
+            .with_page("about.html".to_string(), "About".to_string(), page());
+        let paths = site.paths().unwrap();
+        assert_eq!(paths[""], "index.html");
+        assert_eq!(paths["reports/march"], "reports/march.html");
+        assert_eq!(paths["about.html"], "about.html");
+    }
+
+    #[test]
+    fn duplicate_paths_are_rejected() {
+        let site = Site::new("Reports".to_string(), String::new())
+            .with_page("reports/march".to_string(), "March".to_string(), page())
+            .with_page(
+                "reports/march.html".to_string(),
+                "March".to_string(),
+                page(),
+            );
+        assert!(matches!(
+            site.paths(),
+            Err(SiteError::DuplicatePath(path, first, second))
+                if path == "reports/march.html" && first == "reports/march" && second == "reports/march.html"
+        ));
+
+        let site = Site::new("Reports".to_string(), String::new()).with_page(
+            String::new(),
+            "Home".to_string(),
+            page(),
+        );
+        assert!(matches!(
+            site.paths(),
+            Err(SiteError::DuplicatePath(_, _, _))
+        ));
+        assert!(site.without_index().paths().is_ok());
+    }
+
+    #[test]
+    fn invalid_routes_are_rejected() {
+        for route in ["reports//march", "reports/../secret", "./march"] {
+            let site = Site::new("Reports".to_string(), String::new()).with_page(
+                route.to_string(),
+                "Page".to_string(),
+                page(),
+            );
+            assert!(
+                matches!(site.paths(), Err(SiteError::InvalidRoute(_))),
+                "{}",
+                route
+            );
+        }
+    }
+
+    #[test]
+    fn relative_links_between_pages() {
+        assert_eq!(
+            relative_link("index.html", "reports/march.html"),
+            "reports/march.html"
+        );
+        assert_eq!(
+            relative_link("reports/march.html", "index.html"),
+            "../index.html"
+        );
+        assert_eq!(
+            relative_link("reports/march.html", "reports/april.html"),
+            "april.html"
+        );
+        assert_eq!(
+            relative_link("reports/2024/march.html", "reports/index.html"),
+            "../index.html"
+        );
+        assert_eq!(
+            relative_link("reports/2024/march.html", "archive/2023/march.html"),
+            "../../archive/2023/march.html"
+        );
+        assert_eq!(relative_link("a/b.html", "a/b.html"), "b.html");
+    }
+
+    #[test]
+    fn route_links_are_resolved() {
+        let site = Site::new("Reports".to_string(), String::new()).with_page(
+            "reports/march".to_string(),
+            "March".to_string(),
+            page(),
+        );
+        let paths = site.paths().unwrap();
+
+        let mut element = link("route:reports/march#total");
+        resolve_links(&mut element, "about/team.html", &paths).unwrap();
+        let Element::Element(html_element) = &element else {
+            panic!("the link is not an html element");
+        };
+        assert_eq!(
+            html_element.config.get_attribute("href".to_string()),
+            Some(Some("../reports/march.html#total".to_string()))
+        );
+
+        let mut element = link("route:reports/april");
+        assert!(matches!(
+            resolve_links(&mut element, "index.html", &paths),
+            Err(SiteError::UnknownRoute(page, route)) if page == "index.html" && route == "reports/april"
+        ));
+    }
+
+    #[test]
+    fn unknown_routes_are_detected_before_writing() {
+        let destination =
+            std::env::temp_dir().join(format!("html_export_site_{}", std::process::id()));
+        let site = Site::new("Reports".to_string(), String::new())
+            .with_page(
+                "a".to_string(),
+                "A".to_string(),
+                Document::new(Head::new(), vec![link("route:b")]),
+            )
+            .with_page("c".to_string(), "C".to_string(), page());
+        assert!(site
+            .export_to_directory(destination.to_string_lossy().to_string())
+            .is_err());
+        assert!(!destination.exists());
+    }
+
+    #[test]
+    fn head_route_links_are_resolved() {
+        let destination =
+            std::env::temp_dir().join(format!("html_export_site_head_{}", std::process::id()));
+        let head = Head::new()
+            .with_canonical_url("route:reports/march".to_string())
+            .with_alternate_language("route:fr/reports/march".to_string(), "fr".to_string());
+        let site = Site::new("Reports".to_string(), String::new())
+            .with_page(
+                "reports/march".to_string(),
+                "March".to_string(),
+                Document::new(head, vec![]),
+            )
+            .with_page("fr/reports/march".to_string(), "Mars".to_string(), page());
+        site.export_to_directory(destination.to_string_lossy().to_string())
+            .unwrap();
+        let html = fs::read_to_string(destination.join("reports/march.html")).unwrap();
+        fs::remove_dir_all(&destination).unwrap();
+        assert!(html.contains("href=\"march.html\""));
+        assert!(html.contains("href=\"../fr/reports/march.html\""));
+        assert!(!html.contains(ROUTE_PREFIX));
+
+        let head = Head::new().with_canonical_url("route:reports/april".to_string());
+        let site = Site::new("Reports".to_string(), String::new()).with_page(
+            "reports/march".to_string(),
+            "March".to_string(),
+            Document::new(head, vec![]),
+        );
+        assert!(matches!(
+            site.export_to_directory(destination.to_string_lossy().to_string()),
+            Err(Error::Site(SiteError::UnknownRoute(page, route)))
+                if page == "reports/march.html" && route == "reports/april"
+        ));
+        assert!(!destination.exists());
+    }
+}
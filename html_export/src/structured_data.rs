@@ -0,0 +1,870 @@
+use crate::{
+    element::{Element, HtmlElement, HtmlElementConfig},
+    errors::HtmlResult,
+    formatting::{Date, Time},
+    tags::TagType,
+};
+
+/// The schema.org vocabulary url, used as the JSON-LD `@context`.
+pub const SCHEMA_CONTEXT: &str = "https://schema.org";
+
+/// Represents a JSON value, the object keys keep their insertion order.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+/// Defines a schema.org entity, rendered as JSON-LD structured data.
+pub trait StructuredData {
+    /// Returns the entity as a JSON object, with its `@type` but without the `@context`.
+    fn to_json_ld(&self) -> JsonValue;
+
+    /// Returns the `<script type="application/ld+json">` element describing the entity.
+    /// If the element can not be built, the Err variant is returned.
+    fn to_script(&self) -> HtmlResult<Element> {
+        let mut entity = vec![(
+            "@context".to_string(),
+            JsonValue::String(SCHEMA_CONTEXT.to_string()),
+        )];
+        match self.to_json_ld() {
+            JsonValue::Object(properties) => entity.extend(properties),
+            value => entity.push(("@graph".to_string(), value)),
+        }
+        Element::Element(HtmlElement::new(
+            TagType::Script,
+            HtmlElementConfig::new_empty()
+                .set_attribute("type".to_string(), Some("application/ld+json".to_string())),
+        ))
+        .checked_add(Element::Text(JsonValue::Object(entity).to_json()))
+        .map_err(Into::into)
+    }
+}
+
+/// A person, author of a creative work.
+///
+/// - `name` : The person's name.
+/// - `url` : The optional url of the person's page.
+#[derive(Clone, Debug)]
+pub struct Person {
+    pub name: String,
+    pub url: Option<String>,
+}
+
+/// An organization, publisher or creator of a work, or organizer of an event.
+///
+/// - `name` : The organization's name.
+/// - `url` : The optional url of the organization's site.
+/// - `logo` : The optional url of the organization's logo.
+/// - `same_as` : The urls of the organization's pages on other sites.
+#[derive(Clone, Debug)]
+pub struct Organization {
+    pub name: String,
+    pub url: Option<String>,
+    pub logo: Option<String>,
+    pub same_as: Vec<String>,
+}
+
+/// An article, such as a report or a blog post.
+///
+/// - `headline` : The article's title.
+/// - `description` : The optional summary.
+/// - `url` : The optional canonical url.
+/// - `images` : The urls of the article's images.
+/// - `authors` : The article's authors.
+/// - `publisher` : The optional publishing organization.
+/// - `date_published` : The optional publication date.
+/// - `date_modified` : The optional date of the last modification.
+#[derive(Clone, Debug)]
+pub struct Article {
+    pub headline: String,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub images: Vec<String>,
+    pub authors: Vec<Person>,
+    pub publisher: Option<Organization>,
+    pub date_published: Option<Date>,
+    pub date_modified: Option<Date>,
+}
+
+/// A downloadable file of a dataset.
+///
+/// - `content_url` : The file's url.
+/// - `encoding_format` : The file's MIME type (`text/csv`...).
+#[derive(Clone, Debug)]
+pub struct DataDownload {
+    pub content_url: String,
+    pub encoding_format: String,
+}
+
+/// A dataset, indexed by dataset search engines.
+///
+/// - `name` : The dataset's name.
+/// - `description` : The dataset's description, required by the search engines.
+/// - `url` : The optional url of the dataset's page.
+/// - `keywords` : The keywords.
+/// - `license` : The optional url of the license.
+/// - `creator` : The optional creating organization.
+/// - `date_modified` : The optional date of the last modification.
+/// - `distribution` : The downloadable files.
+#[derive(Clone, Debug)]
+pub struct Dataset {
+    pub name: String,
+    pub description: String,
+    pub url: Option<String>,
+    pub keywords: Vec<String>,
+    pub license: Option<String>,
+    pub creator: Option<Organization>,
+    pub date_modified: Option<Date>,
+    pub distribution: Vec<DataDownload>,
+}
+
+/// The navigation path to a page, from the site's root.
+///
+/// - `items` : The names and urls of the pages, the root first.
+#[derive(Clone, Debug, Default)]
+pub struct BreadcrumbList {
+    pub items: Vec<(String, String)>,
+}
+
+/// An event happening at a date.
+///
+/// - `name` : The event's name.
+/// - `start_date` : The start date.
+/// - `start_time` : The optional start time.
+/// - `end_date` : The optional end date.
+/// - `description` : The optional description.
+/// - `url` : The optional url of the event's page.
+/// - `location` : The optional name and address of the place.
+/// - `organizer` : The optional organizing organization.
+#[derive(Clone, Debug)]
+pub struct Event {
+    pub name: String,
+    pub start_date: Date,
+    pub start_time: Option<Time>,
+    pub end_date: Option<Date>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub location: Option<(String, String)>,
+    pub organizer: Option<Organization>,
+}
+
+impl JsonValue {
+    /// Serializes the value to compact JSON, safe to embed in a `<script>` tag :
+    /// `<`, `>` and `&` are escaped so that the text can not close the tag or open a comment,
+    /// and the U+2028 and U+2029 separators are escaped for older javascript parsers.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write(&mut json);
+        json
+    }
+
+    /// Internal function. Appends the serialized value.
+    ///
+    /// - `json` : The output.
+    fn write(&self, json: &mut String) {
+        match self {
+            JsonValue::Null => json.push_str("null"),
+            JsonValue::Bool(value) => json.push_str(&value.to_string()),
+            JsonValue::Number(value) if value.is_finite() => json.push_str(&value.to_string()),
+            JsonValue::Number(_) => json.push_str("null"),
+            JsonValue::String(value) => write_string(value, json),
+            JsonValue::Array(values) => {
+                json.push('[');
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        json.push(',');
+                    }
+                    value.write(json);
+                }
+                json.push(']');
+            }
+            JsonValue::Object(properties) => {
+                json.push('{');
+                for (index, (key, value)) in properties.iter().enumerate() {
+                    if index > 0 {
+                        json.push(',');
+                    }
+                    write_string(key, json);
+                    json.push(':');
+                    value.write(json);
+                }
+                json.push('}');
+            }
+        }
+    }
+}
+
+impl Person {
+    /// Creates a person.
+    ///
+    /// - `name` : The person's name.
+    pub fn new(name: String) -> Self {
+        Self { name, url: None }
+    }
+
+    /// Sets the url of the person's page.
+    ///
+    /// - `url` : The url.
+    pub fn with_url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+}
+
+impl Organization {
+    /// Creates an organization.
+    ///
+    /// - `name` : The organization's name.
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            url: None,
+            logo: None,
+            same_as: Vec::new(),
+        }
+    }
+
+    /// Sets the url of the organization's site.
+    ///
+    /// - `url` : The url.
+    pub fn with_url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Sets the url of the organization's logo.
+    ///
+    /// - `logo` : The logo's url.
+    pub fn with_logo(mut self, logo: String) -> Self {
+        self.logo = Some(logo);
+        self
+    }
+
+    /// Adds a page of the organization on another site.
+    ///
+    /// - `url` : The page's url.
+    pub fn with_same_as(mut self, url: String) -> Self {
+        self.same_as.push(url);
+        self
+    }
+}
+
+impl Article {
+    /// Creates an article.
+    ///
+    /// - `headline` : The article's title.
+    pub fn new(headline: String) -> Self {
+        Self {
+            headline,
+            description: None,
+            url: None,
+            images: Vec::new(),
+            authors: Vec::new(),
+            publisher: None,
+            date_published: None,
+            date_modified: None,
+        }
+    }
+
+    /// Sets the article's summary.
+    ///
+    /// - `description` : The summary.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the article's canonical url.
+    ///
+    /// - `url` : The url.
+    pub fn with_url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Adds an image to the article.
+    ///
+    /// - `url` : The image's url.
+    pub fn with_image(mut self, url: String) -> Self {
+        self.images.push(url);
+        self
+    }
+
+    /// Adds an author to the article.
+    ///
+    /// - `author` : The author.
+    pub fn with_author(mut self, author: Person) -> Self {
+        self.authors.push(author);
+        self
+    }
+
+    /// Sets the publishing organization.
+    ///
+    /// - `publisher` : The publisher.
+    pub fn with_publisher(mut self, publisher: Organization) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
+    /// Sets the publication and last modification dates.
+    ///
+    /// - `published` : The publication date.
+    /// - `modified` : The optional date of the last modification.
+    pub fn with_dates(mut self, published: Date, modified: Option<Date>) -> Self {
+        self.date_published = Some(published);
+        self.date_modified = modified;
+        self
+    }
+}
+
+impl DataDownload {
+    /// Creates a downloadable file.
+    ///
+    /// - `content_url` : The file's url.
+    /// - `encoding_format` : The file's MIME type.
+    pub fn new(content_url: String, encoding_format: String) -> Self {
+        Self {
+            content_url,
+            encoding_format,
+        }
+    }
+}
+
+impl Dataset {
+    /// Creates a dataset.
+    ///
+    /// - `name` : The dataset's name.
+    /// - `description` : The dataset's description.
+    pub fn new(name: String, description: String) -> Self {
+        Self {
+            name,
+            description,
+            url: None,
+            keywords: Vec::new(),
+            license: None,
+            creator: None,
+            date_modified: None,
+            distribution: Vec::new(),
+        }
+    }
+
+    /// Sets the url of the dataset's page.
+    ///
+    /// - `url` : The url.
+    pub fn with_url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Adds a keyword to the dataset.
+    ///
+    /// - `keyword` : The keyword.
+    pub fn with_keyword(mut self, keyword: String) -> Self {
+        self.keywords.push(keyword);
+        self
+    }
+
+    /// Sets the dataset's license.
+    ///
+    /// - `license` : The url of the license.
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
+        self
+    }
+
+    /// Sets the creating organization.
+    ///
+    /// - `creator` : The creator.
+    pub fn with_creator(mut self, creator: Organization) -> Self {
+        self.creator = Some(creator);
+        self
+    }
+
+    /// Sets the date of the last modification.
+    ///
+    /// - `date` : The date.
+    pub fn with_date_modified(mut self, date: Date) -> Self {
+        self.date_modified = Some(date);
+        self
+    }
+
+    /// Adds a downloadable file to the dataset.
+    ///
+    /// - `download` : The file.
+    pub fn with_distribution(mut self, download: DataDownload) -> Self {
+        self.distribution.push(download);
+        self
+    }
+}
+
+impl BreadcrumbList {
+    /// Creates an empty navigation path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a page to the navigation path.
+    ///
+    /// - `name` : The page's name.
+    /// - `url` : The page's url.
+    pub fn with_item(mut self, name: String, url: String) -> Self {
+        self.items.push((name, url));
+        self
+    }
+}
+
+impl Event {
+    /// Creates an event.
+    ///
+    /// - `name` : The event's name.
+    /// - `start_date` : The start date.
+    pub fn new(name: String, start_date: Date) -> Self {
+        Self {
+            name,
+            start_date,
+            start_time: None,
+            end_date: None,
+            description: None,
+            url: None,
+            location: None,
+            organizer: None,
+        }
+    }
+
+    /// Sets the start time.
+    ///
+    /// - `time` : The time.
+    pub fn with_start_time(mut self, time: Time) -> Self {
+        self.start_time = Some(time);
+        self
+    }
+
+    /// Sets the end date.
+    ///
+    /// - `date` : The date.
+    pub fn with_end_date(mut self, date: Date) -> Self {
+        self.end_date = Some(date);
+        self
+    }
+
+    /// Sets the event's description.
+    ///
+    /// - `description` : The description.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the url of the event's page.
+    ///
+    /// - `url` : The url.
+    pub fn with_url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Sets the place of the event.
+    ///
+    /// - `name` : The place's name.
+    /// - `address` : The place's address.
+    pub fn with_location(mut self, name: String, address: String) -> Self {
+        self.location = Some((name, address));
+        self
+    }
+
+    /// Sets the organizing organization.
+    ///
+    /// - `organizer` : The organizer.
+    pub fn with_organizer(mut self, organizer: Organization) -> Self {
+        self.organizer = Some(organizer);
+        self
+    }
+}
+
+impl StructuredData for Person {
+    fn to_json_ld(&self) -> JsonValue {
+        let mut entity = EntityBuilder::new("Person");
+        entity.string("name", &self.name);
+        entity.optional_string("url", &self.url);
+        entity.build()
+    }
+}
+
+impl StructuredData for Organization {
+    fn to_json_ld(&self) -> JsonValue {
+        let mut entity = EntityBuilder::new("Organization");
+        entity.string("name", &self.name);
+        entity.optional_string("url", &self.url);
+        entity.optional_string("logo", &self.logo);
+        entity.strings("sameAs", &self.same_as);
+        entity.build()
+    }
+}
+
+impl StructuredData for Article {
+    fn to_json_ld(&self) -> JsonValue {
+        let mut entity = EntityBuilder::new("Article");
+        entity.string("headline", &self.headline);
+        entity.optional_string("description", &self.description);
+        entity.optional_string("url", &self.url);
+        entity.strings("image", &self.images);
+        if !self.authors.is_empty() {
+            entity.value(
+                "author",
+                JsonValue::Array(self.authors.iter().map(|a| a.to_json_ld()).collect()),
+            );
+        }
+        if let Some(publisher) = &self.publisher {
+            entity.value("publisher", publisher.to_json_ld());
+        }
+        if let Some(date) = &self.date_published {
+            entity.string("datePublished", &date.to_iso());
+        }
+        if let Some(date) = &self.date_modified {
+            entity.string("dateModified", &date.to_iso());
+        }
+        entity.build()
+    }
+}
+
+impl StructuredData for DataDownload {
+    fn to_json_ld(&self) -> JsonValue {
+        let mut entity = EntityBuilder::new("DataDownload");
+        entity.string("contentUrl", &self.content_url);
+        entity.string("encodingFormat", &self.encoding_format);
+        entity.build()
+    }
+}
+
+impl StructuredData for Dataset {
+    fn to_json_ld(&self) -> JsonValue {
+        let mut entity = EntityBuilder::new("Dataset");
+        entity.string("name", &self.name);
+        entity.string("description", &self.description);
+        entity.optional_string("url", &self.url);
+        entity.strings("keywords", &self.keywords);
+        entity.optional_string("license", &self.license);
+        if let Some(creator) = &self.creator {
+            entity.value("creator", creator.to_json_ld());
+        }
+        if let Some(date) = &self.date_modified {
+            entity.string("dateModified", &date.to_iso());
+        }
+        if !self.distribution.is_empty() {
+            entity.value(
+                "distribution",
+                JsonValue::Array(self.distribution.iter().map(|d| d.to_json_ld()).collect()),
+            );
+        }
+        entity.build()
+    }
+}
+
+impl StructuredData for BreadcrumbList {
+    fn to_json_ld(&self) -> JsonValue {
+        let items = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, (name, url))| {
+                let mut item = EntityBuilder::new("ListItem");
+                item.value("position", JsonValue::Number((index + 1) as f64));
+                item.string("name", name);
+                item.string("item", url);
+                item.build()
+            })
+            .collect();
+        let mut entity = EntityBuilder::new("BreadcrumbList");
+        entity.value("itemListElement", JsonValue::Array(items));
+        entity.build()
+    }
+}
+
+impl StructuredData for Event {
+    fn to_json_ld(&self) -> JsonValue {
+        let mut entity = EntityBuilder::new("Event");
+        entity.string("name", &self.name);
+        let start = match &self.start_time {
+            Some(time) => format!("{}T{}", self.start_date.to_iso(), time.to_iso()),
+            None => self.start_date.to_iso(),
+        };
+        entity.string("startDate", &start);
+        if let Some(date) = &self.end_date {
+            entity.string("endDate", &date.to_iso());
+        }
+        entity.optional_string("description", &self.description);
+        entity.optional_string("url", &self.url);
+        if let Some((name, address)) = &self.location {
+            let mut place = EntityBuilder::new("Place");
+            place.string("name", name);
+            place.string("address", address);
+            entity.value("location", place.build());
+        }
+        if let Some(organizer) = &self.organizer {
+            entity.value("organizer", organizer.to_json_ld());
+        }
+        entity.build()
+    }
+}
+
+/// Internal struct. Assembles the properties of a schema.org entity, in order.
+struct EntityBuilder {
+    properties: Vec<(String, JsonValue)>,
+}
+
+impl EntityBuilder {
+    /// Internal function. Creates an entity with its `@type`.
+    ///
+    /// - `entity_type` : The schema.org type.
+    fn new(entity_type: &str) -> Self {
+        Self {
+            properties: vec![(
+                "@type".to_string(),
+                JsonValue::String(entity_type.to_string()),
+            )],
+        }
+    }
+
+    /// Internal function. Adds a property.
+    ///
+    /// - `key` : The property's name.
+    /// - `value` : The property's value.
+    fn value(&mut self, key: &str, value: JsonValue) {
+        self.properties.push((key.to_string(), value));
+    }
+
+    /// Internal function. Adds a text property.
+    ///
+    /// - `key` : The property's name.
+    /// - `value` : The text.
+    fn string(&mut self, key: &str, value: &str) {
+        self.value(key, JsonValue::String(value.to_string()));
+    }
+
+    /// Internal function. Adds a text property if the value is defined.
+    ///
+    /// - `key` : The property's name.
+    /// - `value` : The optional text.
+    fn optional_string(&mut self, key: &str, value: &Option<String>) {
+        if let Some(value) = value {
+            self.string(key, value);
+        }
+    }
+
+    /// Internal function. Adds a list of texts if it is not empty.
+    ///
+    /// - `key` : The property's name.
+    /// - `values` : The texts.
+    fn strings(&mut self, key: &str, values: &[String]) {
+        if !values.is_empty() {
+            self.value(
+                key,
+                JsonValue::Array(values.iter().cloned().map(JsonValue::String).collect()),
+            );
+        }
+    }
+
+    /// Internal function. Returns the entity's JSON object.
+    fn build(self) -> JsonValue {
+        JsonValue::Object(self.properties)
+    }
+}
+
+/// Internal function. Appends a JSON string literal, escaped for a script context.
+///
+/// - `value` : The text.
+/// - `json` : The output.
+fn write_string(value: &str, json: &mut String) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            '<' | '>' | '&' | '\u{2028}' | '\u{2029}' => {
+                json.push_str(&format!("\\u{:04x}", c as u32))
+            }
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    fn publisher() -> Organization {
+        Organization::new("Acme".to_string())
+            .with_url("https://acme.example".to_string())
+            .with_logo("https://acme.example/logo.png".to_string())
+            .with_same_as("https://social.example/acme".to_string())
+    }
+
+    #[test]
+    fn strings_are_escaped_for_script_tags() {
+        let value = JsonValue::String("</script><!-- \"a\" & b\u{2028}\u{2029}\n".to_string());
+        assert_eq!(
+            value.to_json(),
+            r#""\u003c/script\u003e\u003c!-- \"a\" \u0026 b\u2028\u2029\n""#
+        );
+        let script = Person::new("</script><script>alert(1)</script>".to_string())
+            .to_script()
+            .unwrap();
+        let Element::Element(script) = script else {
+            panic!("the script is not an html element");
+        };
+        let Element::Text(json) = script.children[0].as_ref() else {
+            panic!("the script does not contain raw text");
+        };
+        assert!(!json.contains("</script") && !json.contains("<!--"));
+    }
+
+    #[test]
+    fn non_finite_numbers_are_null() {
+        let value = JsonValue::Array(vec![
+            JsonValue::Number(f64::NAN),
+            JsonValue::Number(f64::INFINITY),
+            JsonValue::Number(f64::NEG_INFINITY),
+            JsonValue::Number(2.5),
+        ]);
+        assert_eq!(value.to_json(), "[null,null,null,2.5]");
+    }
+
+    #[test]
+    fn scripts_have_the_context_and_type() {
+        let script = Person::new("Ada".to_string()).to_script().unwrap();
+        let Element::Element(script) = script else {
+            panic!("the script is not an html element");
+        };
+        assert_eq!(
+            script.config.get_attribute("type".to_string()),
+            Some(Some("application/ld+json".to_string()))
+        );
+        assert!(matches!(
+            script.children[0].as_ref(),
+            Element::Text(json)
+                if json == r#"{"@context":"https://schema.org","@type":"Person","name":"Ada"}"#
+        ));
+    }
+
+    #[test]
+    fn people_and_organizations_are_serialized() {
+        assert_eq!(
+            Person::new("Ada".to_string())
+                .with_url("https://ada.example".to_string())
+                .to_json_ld()
+                .to_json(),
+            r#"{"@type":"Person","name":"Ada","url":"https://ada.example"}"#
+        );
+        assert_eq!(
+            publisher().to_json_ld().to_json(),
+            concat!(
+                r#"{"@type":"Organization","name":"Acme","url":"https://acme.example","#,
+                r#""logo":"https://acme.example/logo.png","sameAs":["https://social.example/acme"]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn articles_are_serialized() {
+        let article = Article::new("Q1 report".to_string())
+            .with_description("Quarterly sales".to_string())
+            .with_url("https://acme.example/q1".to_string())
+            .with_image("https://acme.example/q1.png".to_string())
+            .with_author(Person::new("Ada".to_string()))
+            .with_publisher(Organization::new("Acme".to_string()))
+            .with_dates(date(2024, 4, 2), Some(date(2024, 4, 10)));
+        assert_eq!(
+            article.to_json_ld().to_json(),
+            concat!(
+                r#"{"@type":"Article","headline":"Q1 report","description":"Quarterly sales","#,
+                r#""url":"https://acme.example/q1","image":["https://acme.example/q1.png"],"#,
+                r#""author":[{"@type":"Person","name":"Ada"}],"#,
+                r#""publisher":{"@type":"Organization","name":"Acme"},"#,
+                r#""datePublished":"2024-04-02","dateModified":"2024-04-10"}"#
+            )
+        );
+    }
+
+    #[test]
+    fn datasets_are_serialized() {
+        let dataset = Dataset::new("Sales".to_string(), "Monthly sales".to_string())
+            .with_url("https://acme.example/sales".to_string())
+            .with_keyword("sales".to_string())
+            .with_license("https://creativecommons.org/licenses/by/4.0/".to_string())
+            .with_creator(Organization::new("Acme".to_string()))
+            .with_date_modified(date(2024, 5, 1))
+            .with_distribution(DataDownload::new(
+                "https://acme.example/sales.csv".to_string(),
+                "text/csv".to_string(),
+            ));
+        assert_eq!(
+            dataset.to_json_ld().to_json(),
+            concat!(
+                r#"{"@type":"Dataset","name":"Sales","description":"Monthly sales","#,
+                r#""url":"https://acme.example/sales","keywords":["sales"],"#,
+                r#""license":"https://creativecommons.org/licenses/by/4.0/","#,
+                r#""creator":{"@type":"Organization","name":"Acme"},"dateModified":"2024-05-01","#,
+                r#""distribution":[{"@type":"DataDownload","contentUrl":"https://acme.example/sales.csv","encodingFormat":"text/csv"}]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn breadcrumbs_are_numbered() {
+        let breadcrumbs = BreadcrumbList::new()
+            .with_item("Home".to_string(), "https://acme.example/".to_string())
+            .with_item(
+                "Reports".to_string(),
+                "https://acme.example/reports".to_string(),
+            );
+        assert_eq!(
+            breadcrumbs.to_json_ld().to_json(),
+            concat!(
+                r#"{"@type":"BreadcrumbList","itemListElement":["#,
+                r#"{"@type":"ListItem","position":1,"name":"Home","item":"https://acme.example/"},"#,
+                r#"{"@type":"ListItem","position":2,"name":"Reports","item":"https://acme.example/reports"}]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn events_are_serialized() {
+        let event = Event::new("Launch".to_string(), date(2024, 6, 3))
+            .with_start_time(Time::new(9, 30, 0).unwrap())
+            .with_end_date(date(2024, 6, 4))
+            .with_description("Product launch".to_string())
+            .with_url("https://acme.example/launch".to_string())
+            .with_location("Hall".to_string(), "1 Main Street".to_string())
+            .with_organizer(Organization::new("Acme".to_string()));
+        assert_eq!(
+            event.to_json_ld().to_json(),
+            concat!(
+                r#"{"@type":"Event","name":"Launch","startDate":"2024-06-03T09:30","#,
+                r#""endDate":"2024-06-04","description":"Product launch","#,
+                r#""url":"https://acme.example/launch","#,
+                r#""location":{"@type":"Place","name":"Hall","address":"1 Main Street"},"#,
+                r#""organizer":{"@type":"Organization","name":"Acme"}}"#
+            )
+        );
+        assert_eq!(
+            Event::new("Launch".to_string(), date(2024, 6, 3))
+                .to_json_ld()
+                .to_json(),
+            r#"{"@type":"Event","name":"Launch","startDate":"2024-06-03"}"#
+        );
+    }
+}
@@ -1,5 +1,13 @@
-#[derive(Clone, Debug)]
+use crate::errors::ElementError;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "String", try_from = "String")
+)]
 /// Defines all Html tags.
+/// With the `serde` feature, a tag is serialized as its Html tag name (`"div"`, `"td"`, ...).
 pub enum TagType {
     /* Text */
     P,
@@ -252,189 +260,263 @@ impl TagType {
         .to_string()
     }
 
+    /// Returns the tag matching the given Html tag name.
+    /// If the name is not a known Html tag, the function returns None.
+    ///
+    /// `name` : The Html tag name.
+    pub fn from_tag_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "p" => TagType::P,
+            "h1" => TagType::H1,
+            "h2" => TagType::H2,
+            "h3" => TagType::H3,
+            "h4" => TagType::H4,
+            "h5" => TagType::H5,
+            "h6" => TagType::H6,
+            "title" => TagType::Title,
+            "abbr" => TagType::Abbr,
+            "b" => TagType::B,
+            "bdi" => TagType::Bdi,
+            "bdo" => TagType::Bdo,
+            "blockquote" => TagType::Blockquote,
+            "cite" => TagType::Cite,
+            "del" => TagType::Del,
+            "dfn" => TagType::Dfn,
+            "em" => TagType::Em,
+            "i" => TagType::I,
+            "ins" => TagType::Ins,
+            "mark" => TagType::Mark,
+            "q" => TagType::Q,
+            "rp" => TagType::Rp,
+            "ruby" => TagType::Ruby,
+            "rt" => TagType::Rt,
+            "s" => TagType::S,
+            "small" => TagType::Small,
+            "strong" => TagType::Strong,
+            "sub" => TagType::Sub,
+            "sup" => TagType::Sup,
+            "u" => TagType::U,
+            "a" => TagType::A,
+            "link" => TagType::Link,
+            "menu" => TagType::Menu,
+            "ul" => TagType::Ul,
+            "ol" => TagType::Ol,
+            "li" => TagType::Li,
+            "dl" => TagType::Dl,
+            "dt" => TagType::Dt,
+            "dd" => TagType::Dd,
+            "table" => TagType::Table,
+            "caption" => TagType::Caption,
+            "th" => TagType::Th,
+            "tr" => TagType::Tr,
+            "td" => TagType::Td,
+            "thead" => TagType::Thead,
+            "tbody" => TagType::Tbody,
+            "tfoot" => TagType::Tfoot,
+            "col" => TagType::Col,
+            "colgroup" => TagType::Colgroup,
+            "template" => TagType::Template,
+            "iframe" => TagType::IFrame,
+            "nav" => TagType::Nav,
+            "div" => TagType::Div,
+            "span" => TagType::Span,
+            "header" => TagType::Header,
+            "hgroup" => TagType::Hgroup,
+            "footer" => TagType::Footer,
+            "main" => TagType::Main,
+            "section" => TagType::Section,
+            "search" => TagType::Search,
+            "article" => TagType::Article,
+            "aside" => TagType::Aside,
+            "details" => TagType::Details,
+            "dialog" => TagType::Dialog,
+            "summary" => TagType::Summary,
+            "data" => TagType::Data,
+            "code" => TagType::Code,
+            "pre" => TagType::Pre,
+            "samp" => TagType::Samp,
+            "form" => TagType::Form,
+            "input" => TagType::Input,
+            "textarea" => TagType::TextArea,
+            "button" => TagType::Button,
+            "select" => TagType::Select,
+            "optgroup" => TagType::OptGroup,
+            "option" => TagType::Option,
+            "label" => TagType::Label,
+            "fieldset" => TagType::FieldSet,
+            "legend" => TagType::Legend,
+            "datalist" => TagType::DataList,
+            "output" => TagType::Output,
+            "img" => TagType::Img,
+            "map" => TagType::Map,
+            "area" => TagType::Area,
+            "canvas" => TagType::Canvas,
+            "figcaption" => TagType::FigCaption,
+            "figure" => TagType::Figure,
+            "picture" => TagType::Picture,
+            "svg" => TagType::Svg,
+            "audio" => TagType::Audio,
+            "source" => TagType::Source,
+            "track" => TagType::Track,
+            "video" => TagType::Video,
+            "wbr" => TagType::Wbr,
+            "br" => TagType::Br,
+            "hr" => TagType::Hr,
+            "comment" => TagType::Comment,
+            "address" => TagType::Address,
+            "kdb" => TagType::Kdb,
+            "meter" => TagType::Meter,
+            "progress" => TagType::Progress,
+            "time" => TagType::Time,
+            "var" => TagType::Var,
+            "script" => TagType::Script,
+            "noscript" => TagType::NoScript,
+            "embed" => TagType::Embed,
+            "object" => TagType::Object,
+            "param" => TagType::Param,
+            "meta" => TagType::Meta,
+            "base" => TagType::Base,
+            "head" => TagType::Head,
+            "style" => TagType::Style,
+            _ => return None,
+        })
+    }
+
     /// Indicates if the Html tag can wrap another element.
     /// Therfore, indicates if the Html tag is a no-child Html element.
     pub fn can_wrap(&self) -> bool {
-        match self {
+        !matches!(
+            self,
             Self::Area
-            | Self::Base
-            | Self::Br
-            | Self::Col
-            | Self::Embed
-            | Self::Hr
-            | Self::Img
-            | Self::Input
-            | Self::Link
-            | Self::Meta
-            | Self::Param
-            | Self::Source
-            | Self::Track
-            | Self::Wbr => false,
-            _ => true,
-        }
+                | Self::Base
+                | Self::Br
+                | Self::Col
+                | Self::Embed
+                | Self::Hr
+                | Self::Img
+                | Self::Input
+                | Self::Link
+                | Self::Meta
+                | Self::Param
+                | Self::Source
+                | Self::Track
+                | Self::Wbr
+        )
     }
 
     /// Indicates if the tag is a single-child element.
     pub fn is_single_child(&self) -> bool {
-        match self {
-            Self::Title | Self::Legend | Self::Label | Self::Caption => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            Self::Title | Self::Legend | Self::Label | Self::Caption
+        )
     }
 
     /// Indicates if the tag can be auto-closed.
     pub fn is_auto_closing(&self) -> bool {
-        match self {
+        matches!(
+            self,
             Self::Map
-            | Self::Base
-            | Self::Br
-            | Self::Col
-            | Self::Embed
-            | Self::Hr
-            | Self::Img
-            | Self::Input
-            | Self::Link
-            | Self::Meta
-            | Self::Param
-            | Self::Source
-            | Self::Track
-            | Self::Wbr => true,
-            _ => false,
-        }
+                | Self::Base
+                | Self::Br
+                | Self::Col
+                | Self::Embed
+                | Self::Hr
+                | Self::Img
+                | Self::Input
+                | Self::Link
+                | Self::Meta
+                | Self::Param
+                | Self::Source
+                | Self::Track
+                | Self::Wbr
+        )
+    }
+
+    /// Indicates if the tag's content is preformatted : its whitespaces and line breaks are significant,
+    /// so it is written without indentation.
+    pub fn preserves_whitespace(&self) -> bool {
+        matches!(self, Self::Pre | Self::TextArea)
+    }
+
+    /// Indicates if the tag is a block element, rendered on its own lines.
+    /// The other tags are inline elements, rendered inside the text flow.
+    pub fn is_block(&self) -> bool {
+        matches!(
+            self,
+            Self::P
+                | Self::H1
+                | Self::H2
+                | Self::H3
+                | Self::H4
+                | Self::H5
+                | Self::H6
+                | Self::Blockquote
+                | Self::Menu
+                | Self::Ul
+                | Self::Ol
+                | Self::Li
+                | Self::Dl
+                | Self::Dt
+                | Self::Dd
+                | Self::Table
+                | Self::Caption
+                | Self::Th
+                | Self::Tr
+                | Self::Td
+                | Self::Thead
+                | Self::Tbody
+                | Self::Tfoot
+                | Self::Nav
+                | Self::Div
+                | Self::Header
+                | Self::Hgroup
+                | Self::Footer
+                | Self::Main
+                | Self::Section
+                | Self::Search
+                | Self::Article
+                | Self::Aside
+                | Self::Details
+                | Self::Dialog
+                | Self::Summary
+                | Self::Pre
+                | Self::Form
+                | Self::FieldSet
+                | Self::Legend
+                | Self::FigCaption
+                | Self::Figure
+                | Self::Hr
+                | Self::Address
+        )
     }
 
     /// Indicates if the tag is allowed in the html document's \<head>.
     pub fn is_allowed_is_head(&self) -> bool {
-        match self {
+        matches!(
+            self,
             Self::Title
-            | Self::Style
-            | Self::Base
-            | Self::Link
-            | Self::Meta
-            | Self::Script
-            | Self::NoScript
-            | Self::Comment => true,
-            _ => false,
-        }
+                | Self::Style
+                | Self::Base
+                | Self::Link
+                | Self::Meta
+                | Self::Script
+                | Self::NoScript
+                | Self::Comment
+        )
     }
 }
 
-impl PartialEq for TagType {
-    fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Self::P, Self::P)
-            | (Self::H1, Self::H1)
-            | (Self::H2, Self::H2)
-            | (Self::H3, Self::H3)
-            | (Self::H4, Self::H4)
-            | (Self::H5, Self::H5)
-            | (Self::H6, Self::H6)
-            | (Self::Title, Self::Title)
-            | (Self::Abbr, Self::Abbr)
-            | (Self::B, Self::B)
-            | (Self::Bdi, Self::Bdi)
-            | (Self::Bdo, Self::Bdo)
-            | (Self::Blockquote, Self::Blockquote)
-            | (Self::Cite, Self::Cite)
-            | (Self::Del, Self::Del)
-            | (Self::Dfn, Self::Dfn)
-            | (Self::Em, Self::Em)
-            | (Self::I, Self::I)
-            | (Self::Ins, Self::Ins)
-            | (Self::Mark, Self::Mark)
-            | (Self::Q, Self::Q)
-            | (Self::Rp, Self::Rp)
-            | (Self::Ruby, Self::Ruby)
-            | (Self::Rt, Self::Rt)
-            | (Self::S, Self::S)
-            | (Self::Small, Self::Small)
-            | (Self::Strong, Self::Strong)
-            | (Self::Sub, Self::Sub)
-            | (Self::Sup, Self::Sup)
-            | (Self::U, Self::U)
-            | (Self::A, Self::A)
-            | (Self::Link, Self::Link)
-            | (Self::Menu, Self::Menu)
-            | (Self::Ul, Self::Ul)
-            | (Self::Ol, Self::Ol)
-            | (Self::Li, Self::Li)
-            | (Self::Dl, Self::Dl)
-            | (Self::Dt, Self::Dt)
-            | (Self::Dd, Self::Dd)
-            | (Self::Table, Self::Table)
-            | (Self::Caption, Self::Caption)
-            | (Self::Th, Self::Th)
-            | (Self::Tr, Self::Tr)
-            | (Self::Td, Self::Td)
-            | (Self::Thead, Self::Thead)
-            | (Self::Tbody, Self::Tbody)
-            | (Self::Tfoot, Self::Tfoot)
-            | (Self::Col, Self::Col)
-            | (Self::Colgroup, Self::Colgroup)
-            | (Self::Template, Self::Template)
-            | (Self::IFrame, Self::IFrame)
-            | (Self::Nav, Self::Nav)
-            | (Self::Div, Self::Div)
-            | (Self::Span, Self::Span)
-            | (Self::Header, Self::Header)
-            | (Self::Hgroup, Self::Hgroup)
-            | (Self::Footer, Self::Footer)
-            | (Self::Main, Self::Main)
-            | (Self::Section, Self::Section)
-            | (Self::Search, Self::Search)
-            | (Self::Article, Self::Article)
-            | (Self::Aside, Self::Aside)
-            | (Self::Details, Self::Details)
-            | (Self::Dialog, Self::Dialog)
-            | (Self::Summary, Self::Summary)
-            | (Self::Data, Self::Data)
-            | (Self::Code, Self::Code)
-            | (Self::Pre, Self::Pre)
-            | (Self::Samp, Self::Samp)
-            | (Self::Form, Self::Form)
-            | (Self::Input, Self::Input)
-            | (Self::TextArea, Self::TextArea)
-            | (Self::Button, Self::Button)
-            | (Self::Select, Self::Select)
-            | (Self::OptGroup, Self::OptGroup)
-            | (Self::Option, Self::Option)
-            | (Self::Label, Self::Label)
-            | (Self::FieldSet, Self::FieldSet)
-            | (Self::Legend, Self::Legend)
-            | (Self::DataList, Self::DataList)
-            | (Self::Output, Self::Output)
-            | (Self::Img, Self::Img)
-            | (Self::Map, Self::Map)
-            | (Self::Area, Self::Area)
-            | (Self::Canvas, Self::Canvas)
-            | (Self::FigCaption, Self::FigCaption)
-            | (Self::Figure, Self::Figure)
-            | (Self::Picture, Self::Picture)
-            | (Self::Svg, Self::Svg)
-            | (Self::Audio, Self::Audio)
-            | (Self::Source, Self::Source)
-            | (Self::Track, Self::Track)
-            | (Self::Video, Self::Video)
-            | (Self::Wbr, Self::Wbr)
-            | (Self::Br, Self::Br)
-            | (Self::Hr, Self::Hr)
-            | (Self::Comment, Self::Comment)
-            | (Self::Address, Self::Address)
-            | (Self::Kdb, Self::Kdb)
-            | (Self::Meter, Self::Meter)
-            | (Self::Progress, Self::Progress)
-            | (Self::Time, Self::Time)
-            | (Self::Var, Self::Var)
-            | (Self::Script, Self::Script)
-            | (Self::NoScript, Self::NoScript)
-            | (Self::Embed, Self::Embed)
-            | (Self::Object, Self::Object)
-            | (Self::Param, Self::Param)
-            | (Self::Meta, Self::Meta)
-            | (Self::Base, Self::Base)
-            | (Self::Head, Self::Head)
-            | (Self::Style, Self::Style) => true,
-            _ => false,
-        }
+impl From<TagType> for String {
+    fn from(value: TagType) -> Self {
+        value.get_tag_name()
+    }
+}
+
+impl TryFrom<String> for TagType {
+    type Error = ElementError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        TagType::from_tag_name(&value).ok_or(ElementError::UnknownTag(value))
     }
 }
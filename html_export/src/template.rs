@@ -0,0 +1,349 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    element::{Element, HtmlElement},
+    errors::TemplateError,
+    miscellaneous::escape_html,
+    tags::TagType,
+};
+
+/// Represents a reusable Html structure.
+/// The structure contains named slots (`Element::Slot`) replaced by elements
+/// and text placeholders (`{name}` inside `Element::Text`) replaced by escaped values.
+/// Like in `format!`, `{{` and `}}` are written as literal braces.
+/// The texts of the `<script>`, `<style>`, `<pre>` and `<code>` elements are kept as is, their braces are not placeholders.
+///
+/// - `content` : The template's elements.
+#[derive(Clone, Debug)]
+pub struct Template {
+    pub content: Vec<Element>,
+}
+
+/// Defines the content used to fill a template.
+///
+/// - `slots` : The elements replacing the slots, by slot name.
+/// - `values` : The values replacing the text placeholders, by placeholder name.
+#[derive(Clone, Debug, Default)]
+pub struct TemplateContent {
+    pub slots: HashMap<String, Element>,
+    pub values: HashMap<String, String>,
+}
+
+impl Template {
+    /// Creates a new template.
+    ///
+    /// - `content` : The template's elements.
+    pub fn new(content: Vec<Element>) -> Self {
+        Self { content }
+    }
+
+    /// Returns the template's elements with their slots and text placeholders replaced.
+    /// The slots elements are inserted as is, their own slots and placeholders are not replaced.
+    /// A slot or a placeholder can be used several times.
+    ///
+    /// If a slot or a placeholder has no content, or if a content is not used by the template,
+    /// the Err variant is returned.
+    ///
+    /// - `content` : The slots and placeholders content.
+    pub fn fill(&self, content: &TemplateContent) -> Result<Vec<Element>, TemplateError> {
+        let mut used_slots = HashSet::new();
+        let mut used_values = HashSet::new();
+        let elements = self
+            .content
+            .iter()
+            .map(|element| fill_element(element, content, false, &mut used_slots, &mut used_values))
+            .collect::<Result<Vec<Element>, TemplateError>>()?;
+        if let Some(name) = first_unused(content.slots.keys(), &used_slots) {
+            return Err(TemplateError::UnknownSlot(name));
+        }
+        if let Some(name) = first_unused(content.values.keys(), &used_values) {
+            return Err(TemplateError::UnknownValue(name));
+        }
+        Ok(elements)
+    }
+}
+
+impl TemplateContent {
+    /// Creates an empty content.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the element replacing a slot.
+    ///
+    /// - `name` : The slot's name.
+    /// - `element` : The slot's element.
+    pub fn with_slot(mut self, name: String, element: Element) -> Self {
+        self.slots.insert(name, element);
+        self
+    }
+
+    /// Sets the value replacing a text placeholder. The value is escaped when inserted.
+    ///
+    /// - `name` : The placeholder's name.
+    /// - `value` : The placeholder's value.
+    pub fn with_value(mut self, name: String, value: String) -> Self {
+        self.values.insert(name, value);
+        self
+    }
+}
+
+/// Internal function. Returns a copy of the element with its slots and text placeholders replaced.
+///
+/// - `element` : The element to fill.
+/// - `content` : The slots and placeholders content.
+/// - `raw` : If true, the element is inside a script, a style or a code and its texts are kept as is.
+/// - `used_slots` : The names of the slots found so far.
+/// - `used_values` : The names of the placeholders found so far.
+fn fill_element(
+    element: &Element,
+    content: &TemplateContent,
+    raw: bool,
+    used_slots: &mut HashSet<String>,
+    used_values: &mut HashSet<String>,
+) -> Result<Element, TemplateError> {
+    match element {
+        Element::Slot(name) => {
+            let slot = content
+                .slots
+                .get(name)
+                .ok_or_else(|| TemplateError::MissingSlot(name.clone()))?;
+            used_slots.insert(name.clone());
+            Ok(slot.clone())
+        }
+        Element::Text(text) if raw => Ok(Element::Text(text.clone())),
+        Element::Text(text) => Ok(Element::Text(interpolate(
+            text,
+            &content.values,
+            used_values,
+        )?)),
+        Element::Element(html_element) => {
+            let mut filled =
+                HtmlElement::new(html_element.tag.clone(), html_element.config.clone());
+            let raw = raw || keeps_raw_text(&html_element.tag);
+            for child in html_element.children.iter() {
+                filled.children.push(Box::new(fill_element(
+                    child,
+                    content,
+                    raw,
+                    used_slots,
+                    used_values,
+                )?));
+            }
+            Ok(Element::Element(filled))
+        }
+    }
+}
+
+/// Internal function. Replaces the `{name}` placeholders of a text by their escaped values.
+///
+/// - `text` : The text to interpolate.
+/// - `values` : The placeholders values.
+/// - `used_values` : The names of the placeholders found so far.
+fn interpolate(
+    text: &str,
+    values: &HashMap<String, String>,
+    used_values: &mut HashSet<String>,
+) -> Result<String, TemplateError> {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some('{') | None => {
+                            return Err(TemplateError::InvalidPlaceholder(text.to_string()))
+                        }
+                        Some(c) => name.push(c),
+                    }
+                }
+                let name = name.trim();
+                if name.is_empty() {
+                    return Err(TemplateError::InvalidPlaceholder(text.to_string()));
+                }
+                let value = values
+                    .get(name)
+                    .ok_or_else(|| TemplateError::MissingValue(name.to_string()))?;
+                used_values.insert(name.to_string());
+                result.push_str(&escape_html(value));
+            }
+            '}' => return Err(TemplateError::InvalidPlaceholder(text.to_string())),
+            c => result.push(c),
+        }
+    }
+    Ok(result)
+}
+
+/// Internal function. Indicates if the texts of an element are kept as is :
+/// the braces of scripts, styles and code are part of their content.
+///
+/// - `tag` : The element's tag.
+fn keeps_raw_text(tag: &TagType) -> bool {
+    matches!(
+        tag,
+        TagType::Script | TagType::Style | TagType::Pre | TagType::Code
+    )
+}
+
+/// Internal function. Returns the first name, in alphabetical order, that was not used.
+///
+/// - `names` : The given names.
+/// - `used` : The used names.
+fn first_unused<'a, T>(names: T, used: &HashSet<String>) -> Option<String>
+where
+    T: Iterator<Item = &'a String>,
+{
+    names.filter(|name| !used.contains(*name)).min().cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::element::HtmlElementConfig;
+
+    fn card() -> Template {
+        let mut article = HtmlElement::new(TagType::Article, HtmlElementConfig::new_empty());
+        article
+            .children
+            .push(Box::new(Element::Text("{title} ({count})".to_string())));
+        article
+            .children
+            .push(Box::new(Element::Slot("body".to_string())));
+        Template::new(vec![Element::Element(article)])
+    }
+
+    fn children(element: &Element) -> Vec<Element> {
+        match element {
+            Element::Element(element) => element
+                .children
+                .iter()
+                .map(|child| *child.clone())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn fill_replaces_slots_and_placeholders() {
+        let content = TemplateContent::new()
+            .with_value("title".to_string(), "Q1 & Q2".to_string())
+            .with_value("count".to_string(), "2".to_string())
+            .with_slot("body".to_string(), Element::Text("Sales".to_string()));
+        let elements = card().fill(&content).unwrap();
+        let children = children(&elements[0]);
+        assert!(matches!(&children[0], Element::Text(text) if text == "Q1 &amp; Q2 (2)"));
+        assert!(matches!(&children[1], Element::Text(text) if text == "Sales"));
+    }
+
+    #[test]
+    fn fill_rejects_missing_and_unknown_content() {
+        let content = TemplateContent::new()
+            .with_value("title".to_string(), "Title".to_string())
+            .with_value("count".to_string(), "2".to_string());
+        assert!(matches!(
+            card().fill(&content),
+            Err(TemplateError::MissingSlot(name)) if name == "body"
+        ));
+
+        let content = TemplateContent::new()
+            .with_value("title".to_string(), "Title".to_string())
+            .with_slot("body".to_string(), Element::Text("Sales".to_string()));
+        assert!(matches!(
+            card().fill(&content),
+            Err(TemplateError::MissingValue(name)) if name == "count"
+        ));
+
+        let content = TemplateContent::new()
+            .with_value("title".to_string(), "Title".to_string())
+            .with_value("count".to_string(), "2".to_string())
+            .with_value("footer".to_string(), "End".to_string())
+            .with_slot("body".to_string(), Element::Text("Sales".to_string()))
+            .with_slot("aside".to_string(), Element::Text("Notes".to_string()));
+        assert!(matches!(
+            card().fill(&content),
+            Err(TemplateError::UnknownSlot(name)) if name == "aside"
+        ));
+    }
+
+    #[test]
+    fn braces_are_escaped_by_doubling() {
+        let template = Template::new(vec![Element::Text("{{literal}} {name}".to_string())]);
+        let content = TemplateContent::new().with_value("name".to_string(), "<b>".to_string());
+        let elements = template.fill(&content).unwrap();
+        assert!(matches!(&elements[0], Element::Text(text) if text == "{literal} &lt;b&gt;"));
+    }
+
+    #[test]
+    fn invalid_placeholders_are_rejected() {
+        for text in ["{unclosed", "closed}", "{}", "{a{b}}"] {
+            let template = Template::new(vec![Element::Text(text.to_string())]);
+            assert!(
+                matches!(
+                    template.fill(&TemplateContent::new()),
+                    Err(TemplateError::InvalidPlaceholder(_))
+                ),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn slots_can_be_used_several_times() {
+        let template = Template::new(vec![
+            Element::Slot("logo".to_string()),
+            Element::Slot("logo".to_string()),
+        ]);
+        let content =
+            TemplateContent::new().with_slot("logo".to_string(), Element::Text("L".to_string()));
+        assert_eq!(template.fill(&content).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn scripts_styles_and_code_keep_their_braces() {
+        let mut style = HtmlElement::new(TagType::Style, HtmlElementConfig::new_empty());
+        style
+            .children
+            .push(Box::new(Element::Text("a { color: red }".to_string())));
+        let mut pre = HtmlElement::new(TagType::Pre, HtmlElementConfig::new_empty());
+        pre.children
+            .push(Box::new(Element::Text("fn main() {}".to_string())));
+        pre.children
+            .push(Box::new(Element::Slot("snippet".to_string())));
+        let mut title = HtmlElement::new(TagType::H1, HtmlElementConfig::new_empty());
+        title
+            .children
+            .push(Box::new(Element::Text("{title}".to_string())));
+        let template = Template::new(vec![
+            Element::Element(style),
+            Element::Element(pre),
+            Element::Element(title),
+        ]);
+        let content = TemplateContent::new()
+            .with_value("title".to_string(), "Report".to_string())
+            .with_slot(
+                "snippet".to_string(),
+                Element::Text("let x = 1;".to_string()),
+            );
+        let elements = template.fill(&content).unwrap();
+        assert!(
+            matches!(&children(&elements[0])[0], Element::Text(text) if text == "a { color: red }")
+        );
+        assert!(
+            matches!(&children(&elements[1])[0], Element::Text(text) if text == "fn main() {}")
+        );
+        assert!(matches!(&children(&elements[1])[1], Element::Text(text) if text == "let x = 1;"));
+        assert!(matches!(&children(&elements[2])[0], Element::Text(text) if text == "Report"));
+    }
+}
@@ -0,0 +1,897 @@
+use crate::{
+    element::{Element, HtmlElement},
+    miscellaneous::unescape_html,
+    tags::TagType,
+};
+
+/// The minimal line width used when wrapping indented plain text.
+const MIN_WIDTH: usize = 20;
+
+/// Renders elements to GitHub-flavoured Markdown.
+/// Tables are rendered as pipe tables, lists keep their nesting and links become numbered references
+/// listed after the content (`[figures][1]` and `[1]: https://...`).
+/// Scripts, styles, comments, embedded media and `aria-hidden` elements are ignored.
+///
+/// - `elements` : The elements to render.
+pub fn to_markdown(elements: &[Element]) -> String {
+    Renderer::new(Format::Markdown, 0).render(elements)
+}
+
+/// Renders elements to plain text, wrapped at the given width.
+/// Tables are rendered as ASCII tables, lists keep their nesting and links become numbered footnotes
+/// listed after the content (`figures [1]` and `[1] https://...`).
+/// Scripts, styles, comments, embedded media and `aria-hidden` elements are ignored.
+///
+/// - `elements` : The elements to render.
+/// - `width` : The maximal line width. Tables, preformatted text and long words are not wrapped.
+pub fn to_plain_text(elements: &[Element], width: usize) -> String {
+    Renderer::new(Format::PlainText, width).render(elements)
+}
+
+/// Internal enum. The output format.
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Markdown,
+    PlainText,
+}
+
+/// Internal struct. Renders the elements while collecting the links.
+///
+/// - `format` : The output format.
+/// - `width` : The maximal line width of the plain text.
+/// - `indent` : The width of the prefixes added to the lines being rendered (list markers, quotes).
+/// - `links` : The links found so far, numbered from 1.
+struct Renderer {
+    format: Format,
+    width: usize,
+    indent: usize,
+    links: Vec<String>,
+}
+
+impl Renderer {
+    fn new(format: Format, width: usize) -> Self {
+        Self {
+            format,
+            width,
+            indent: 0,
+            links: vec![],
+        }
+    }
+
+    /// Internal function. Returns the rendered elements followed by the links list.
+    ///
+    /// - `elements` : The elements to render.
+    fn render(mut self, elements: &[Element]) -> String {
+        let mut output = self.blocks(elements.iter()).join("\n\n");
+        if !self.links.is_empty() {
+            output.push_str("\n\n");
+            for (index, link) in self.links.iter().enumerate() {
+                match self.format {
+                    Format::Markdown => output.push_str(&format!("[{}]: {}\n", index + 1, link)),
+                    Format::PlainText => output.push_str(&format!("[{}] {}\n", index + 1, link)),
+                }
+            }
+        } else if !output.is_empty() {
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Internal function. Renders a sequence of elements to blocks.
+    /// The consecutive inline elements are grouped in a paragraph.
+    ///
+    /// - `elements` : The elements to render.
+    fn blocks<'e>(&mut self, elements: impl Iterator<Item = &'e Element>) -> Vec<String> {
+        let mut blocks = vec![];
+        let mut paragraph = String::new();
+        for element in elements {
+            match element {
+                Element::Element(html_element) if is_hidden(html_element) => {}
+                Element::Element(html_element) if html_element.tag.is_block() => {
+                    self.flush(&mut paragraph, &mut blocks);
+                    blocks.extend(self.block(html_element));
+                }
+                _ => self.inline(element, &mut paragraph),
+            }
+        }
+        self.flush(&mut paragraph, &mut blocks);
+        blocks
+    }
+
+    /// Internal function. Adds the paragraph to the blocks, wrapped if the output is plain text.
+    ///
+    /// - `paragraph` : The inline content, emptied.
+    /// - `blocks` : The rendered blocks.
+    fn flush(&self, paragraph: &mut String, blocks: &mut Vec<String>) {
+        let text = std::mem::take(paragraph);
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        let lines = text.split('\n').map(str::trim);
+        blocks.push(match self.format {
+            Format::Markdown => lines.collect::<Vec<&str>>().join("\\\n"),
+            Format::PlainText => lines
+                .map(|line| wrap(line, self.available_width()))
+                .collect::<Vec<String>>()
+                .join("\n"),
+        });
+    }
+
+    /// Internal function. Renders a block element.
+    ///
+    /// - `element` : The element to render.
+    fn block(&mut self, element: &HtmlElement) -> Vec<String> {
+        let level = match element.tag {
+            TagType::H1 => 1,
+            TagType::H2 => 2,
+            TagType::H3 => 3,
+            TagType::H4 => 4,
+            TagType::H5 => 5,
+            TagType::H6 => 6,
+            _ => 0,
+        };
+        if level > 0 {
+            let text = self.inline_line(element);
+            if text.is_empty() {
+                return vec![];
+            }
+            return match (self.format, level) {
+                (Format::Markdown, _) => vec![format!("{} {}", "#".repeat(level), text)],
+                (Format::PlainText, 1 | 2) => {
+                    let underline = if level == 1 { "=" } else { "-" };
+                    vec![format!(
+                        "{}\n{}",
+                        text,
+                        underline.repeat(text.chars().count())
+                    )]
+                }
+                (Format::PlainText, _) => vec![text],
+            };
+        }
+        match element.tag {
+            TagType::Hr => match self.format {
+                Format::Markdown => vec!["---".to_string()],
+                Format::PlainText => vec!["-".repeat(self.available_width())],
+            },
+            TagType::Pre => vec![self.code_block(element)],
+            TagType::Blockquote => {
+                self.indent += 2;
+                let content = self.blocks(children(element)).join("\n\n");
+                self.indent -= 2;
+                if content.is_empty() {
+                    vec![]
+                } else {
+                    vec![prefix_lines(&content, "> ", "> ", ">")]
+                }
+            }
+            TagType::Ul | TagType::Ol | TagType::Menu => vec![self.list(element)],
+            TagType::Dl => self.definition_list(element),
+            TagType::Table => self.table(element),
+            _ => self.blocks(children(element)),
+        }
+    }
+
+    /// Internal function. Renders an inline element, appended to the paragraph.
+    ///
+    /// - `element` : The element to render.
+    /// - `paragraph` : The inline content.
+    fn inline(&mut self, element: &Element, paragraph: &mut String) {
+        let html_element = match element {
+            Element::Text(text) => {
+                self.push_text(paragraph, &unescape_html(text));
+                return;
+            }
+            Element::Slot(_) => return,
+            Element::Element(html_element) => html_element,
+        };
+        let marker = match self.format {
+            Format::PlainText => "",
+            Format::Markdown => match html_element.tag {
+                TagType::Strong | TagType::B => "**",
+                TagType::Em | TagType::I => "*",
+                TagType::Del | TagType::S => "~~",
+                _ => "",
+            },
+        };
+        match html_element.tag {
+            _ if is_hidden(html_element) => {}
+            TagType::Br => paragraph.push('\n'),
+            TagType::Code | TagType::Kdb | TagType::Samp => {
+                let code = collapse_whitespace(&raw_text(html_element));
+                match self.format {
+                    Format::Markdown => paragraph.push_str(&code_span(&code)),
+                    Format::PlainText => paragraph.push_str(&code),
+                }
+            }
+            TagType::A => {
+                let text = self.inline_line(html_element);
+                match attribute(html_element, "href") {
+                    Some(href) if !href.is_empty() && !href.starts_with('#') => {
+                        let number = self.link(href);
+                        match self.format {
+                            Format::Markdown => {
+                                paragraph.push_str(&format!("[{}][{}]", text, number))
+                            }
+                            Format::PlainText => {
+                                paragraph.push_str(&format!("{} [{}]", text, number))
+                            }
+                        }
+                    }
+                    _ => paragraph.push_str(&text),
+                }
+            }
+            TagType::Img => {
+                let alt = attribute(html_element, "alt").unwrap_or_default();
+                match (self.format, attribute(html_element, "src")) {
+                    (Format::Markdown, Some(src)) => paragraph.push_str(&format!(
+                        "![{}]({})",
+                        escape_markdown(&alt),
+                        src.replace(' ', "%20")
+                    )),
+                    _ => self.push_text(paragraph, &alt),
+                }
+            }
+            TagType::Input => {
+                if attribute(html_element, "type").as_deref() == Some("checkbox") {
+                    if html_element.config.has_attribute("checked".to_string()) {
+                        paragraph.push_str("[x] ");
+                    } else {
+                        paragraph.push_str("[ ] ");
+                    }
+                }
+            }
+            _ => {
+                let mut content = String::new();
+                if html_element.tag.is_block() {
+                    self.push_text(paragraph, " ");
+                }
+                for child in children(html_element) {
+                    self.inline(child, &mut content);
+                }
+                let trimmed = content.trim();
+                if marker.is_empty() || trimmed.is_empty() {
+                    paragraph.push_str(&content);
+                } else {
+                    paragraph.push_str(&format!("{}{}{}", marker, trimmed, marker));
+                    if content.ends_with(char::is_whitespace) {
+                        paragraph.push(' ');
+                    }
+                }
+            }
+        }
+    }
+
+    /// Internal function. Returns the inline content of an element on a single line.
+    ///
+    /// - `element` : The element to render.
+    fn inline_line(&mut self, element: &HtmlElement) -> String {
+        let mut content = String::new();
+        for child in children(element) {
+            self.inline(child, &mut content);
+        }
+        collapse_whitespace(&content)
+    }
+
+    /// Internal function. Appends a text to the paragraph, with its whitespaces collapsed.
+    /// The Markdown special characters are escaped.
+    ///
+    /// - `paragraph` : The inline content.
+    /// - `text` : The text to append.
+    fn push_text(&self, paragraph: &mut String, text: &str) {
+        for c in text.chars() {
+            if c.is_whitespace() && c != '\u{a0}' {
+                if !paragraph.is_empty() && !paragraph.ends_with([' ', '\n']) {
+                    paragraph.push(' ');
+                }
+            } else if self.format == Format::Markdown {
+                paragraph.push_str(&escape_markdown(&c.to_string()));
+            } else {
+                paragraph.push(c);
+            }
+        }
+    }
+
+    /// Internal function. Returns the number of a link, registering it if needed.
+    ///
+    /// - `href` : The link's url.
+    fn link(&mut self, href: String) -> usize {
+        match self.links.iter().position(|link| *link == href) {
+            Some(index) => index + 1,
+            None => {
+                self.links.push(href);
+                self.links.len()
+            }
+        }
+    }
+
+    /// Internal function. Returns the width available for the plain text lines.
+    fn available_width(&self) -> usize {
+        self.width.saturating_sub(self.indent).max(MIN_WIDTH)
+    }
+
+    /// Internal function. Renders a `<pre>` element as a fenced code block, or as an indented block in plain text.
+    /// The language is read from the `language-*` class of the `<pre>` element or of its `<code>` child.
+    ///
+    /// - `element` : The element to render.
+    fn code_block(&self, element: &HtmlElement) -> String {
+        let code = raw_text(element);
+        let code = code.trim_start_matches('\n').trim_end();
+        match self.format {
+            Format::Markdown => {
+                let mut classes = element.config.classes.iter().collect::<Vec<&String>>();
+                for child in children(element) {
+                    if let Element::Element(child) = child {
+                        classes.extend(child.config.classes.iter());
+                    }
+                }
+                let language = classes
+                    .iter()
+                    .find_map(|class| class.strip_prefix("language-"))
+                    .unwrap_or_default();
+                let mut fence = "```".to_string();
+                while code.contains(&fence) {
+                    fence.push('`');
+                }
+                format!("{}{}\n{}\n{}", fence, language, code, fence)
+            }
+            Format::PlainText => prefix_lines(code, "    ", "    ", ""),
+        }
+    }
+
+    /// Internal function. Renders a list, each item starting with a marker and its nested content indented.
+    ///
+    /// - `element` : The `<ul>`, `<ol>` or `<menu>` element.
+    fn list(&mut self, element: &HtmlElement) -> String {
+        let mut number = attribute(element, "start")
+            .and_then(|start| start.parse::<i64>().ok())
+            .unwrap_or(1);
+        let mut items = vec![];
+        for child in children(element) {
+            match child {
+                Element::Element(item) if item.tag == TagType::Li => {
+                    let marker = if element.tag == TagType::Ol {
+                        number += 1;
+                        format!("{}. ", number - 1)
+                    } else {
+                        "- ".to_string()
+                    };
+                    let width = marker.chars().count();
+                    self.indent += width;
+                    let content = self.blocks(children(item)).join("\n");
+                    self.indent -= width;
+                    items.push(prefix_lines(&content, &marker, &" ".repeat(width), ""));
+                }
+                _ => items.extend(self.blocks(std::iter::once(child))),
+            }
+        }
+        items.join("\n")
+    }
+
+    /// Internal function. Renders a definition list, the definitions following their term.
+    ///
+    /// - `element` : The `<dl>` element.
+    fn definition_list(&mut self, element: &HtmlElement) -> Vec<String> {
+        let mut blocks = vec![];
+        for child in children(element) {
+            match child {
+                Element::Element(term) if term.tag == TagType::Dt => {
+                    let text = self.inline_line(term);
+                    match self.format {
+                        Format::Markdown => blocks.push(format!("**{}**", text)),
+                        Format::PlainText => blocks.push(text),
+                    }
+                }
+                Element::Element(definition) if definition.tag == TagType::Dd => {
+                    match self.format {
+                        Format::Markdown => blocks.extend(self.blocks(children(definition))),
+                        Format::PlainText => {
+                            self.indent += 4;
+                            let content = self.blocks(children(definition)).join("\n");
+                            self.indent -= 4;
+                            let content = prefix_lines(&content, "    ", "    ", "");
+                            match blocks.last_mut() {
+                                Some(last) => {
+                                    last.push('\n');
+                                    last.push_str(&content);
+                                }
+                                None => blocks.push(content),
+                            }
+                        }
+                    }
+                }
+                _ => blocks.extend(self.blocks(std::iter::once(child))),
+            }
+        }
+        blocks
+    }
+
+    /// Internal function. Renders a table as a pipe table, or as an ASCII table in plain text.
+    /// The first row is used as header, the columns alignment is read from the cells `text-align` style.
+    ///
+    /// - `element` : The `<table>` element.
+    fn table(&mut self, element: &HtmlElement) -> Vec<String> {
+        let mut caption = None;
+        let mut rows = vec![];
+        let mut alignments = vec![];
+        self.table_rows(element, &mut caption, &mut rows, &mut alignments);
+        let mut blocks = caption.into_iter().collect::<Vec<String>>();
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return blocks;
+        }
+        for row in rows.iter_mut() {
+            row.resize(columns, String::new());
+        }
+        alignments.resize(columns, Alignment::None);
+        let widths = (0..columns)
+            .map(|column| {
+                rows.iter()
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .unwrap_or(0)
+                    .max(3)
+            })
+            .collect::<Vec<usize>>();
+
+        let format_row = |row: &Vec<String>| {
+            let cells = row
+                .iter()
+                .zip(widths.iter().zip(alignments.iter()))
+                .map(|(cell, (width, alignment))| alignment.pad(cell, *width))
+                .collect::<Vec<String>>();
+            format!("| {} |", cells.join(" | "))
+        };
+        let mut lines = vec![];
+        match self.format {
+            Format::Markdown => {
+                lines.push(format_row(&rows[0]));
+                let separators = widths
+                    .iter()
+                    .zip(alignments.iter())
+                    .map(|(width, alignment)| alignment.separator(*width))
+                    .collect::<Vec<String>>();
+                lines.push(format!("| {} |", separators.join(" | ")));
+                lines.extend(rows[1..].iter().map(format_row));
+            }
+            Format::PlainText => {
+                let border = |c: &str| {
+                    let segments = widths
+                        .iter()
+                        .map(|width| c.repeat(width + 2))
+                        .collect::<Vec<String>>();
+                    format!("+{}+", segments.join("+"))
+                };
+                lines.push(border("-"));
+                lines.push(format_row(&rows[0]));
+                if rows.len() > 1 {
+                    lines.push(border("="));
+                    lines.extend(rows[1..].iter().map(format_row));
+                }
+                lines.push(border("-"));
+            }
+        }
+        blocks.push(lines.join("\n"));
+        blocks
+    }
+
+    /// Internal function. Collects the caption, the rows and the columns alignment of a table.
+    ///
+    /// - `element` : The table or one of its row groups.
+    /// - `caption` : The table's caption.
+    /// - `rows` : The cells text of each row.
+    /// - `alignments` : The columns alignment.
+    fn table_rows(
+        &mut self,
+        element: &HtmlElement,
+        caption: &mut Option<String>,
+        rows: &mut Vec<Vec<String>>,
+        alignments: &mut Vec<Alignment>,
+    ) {
+        for child in children(element) {
+            let Element::Element(child) = child else {
+                continue;
+            };
+            match child.tag {
+                TagType::Caption => *caption = Some(self.inline_line(child)),
+                TagType::Thead | TagType::Tbody | TagType::Tfoot => {
+                    self.table_rows(child, caption, rows, alignments)
+                }
+                TagType::Tr => {
+                    let mut row = vec![];
+                    for cell in children(child) {
+                        let Element::Element(cell) = cell else {
+                            continue;
+                        };
+                        if cell.tag != TagType::Th && cell.tag != TagType::Td {
+                            continue;
+                        }
+                        if alignments.len() <= row.len() {
+                            alignments.push(Alignment::from_element(cell));
+                        } else if alignments[row.len()] == Alignment::None {
+                            alignments[row.len()] = Alignment::from_element(cell);
+                        }
+                        let text = self.inline_line(cell);
+                        row.push(match self.format {
+                            Format::Markdown => text.replace('|', "\\|"),
+                            Format::PlainText => text,
+                        });
+                    }
+                    rows.push(row);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Internal enum. The alignment of a table column.
+#[derive(Clone, Copy, PartialEq)]
+enum Alignment {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+impl Alignment {
+    /// Internal function. Reads the alignment of a cell from its `text-align` style or its `align` attribute.
+    ///
+    /// - `cell` : The `<th>` or `<td>` element.
+    fn from_element(cell: &HtmlElement) -> Self {
+        let style = attribute(cell, "style")
+            .unwrap_or_default()
+            .replace(' ', "");
+        let align = attribute(cell, "align").unwrap_or_default();
+        if style.contains("text-align:left") || align == "left" {
+            Self::Left
+        } else if style.contains("text-align:center") || align == "center" {
+            Self::Center
+        } else if style.contains("text-align:right") || align == "right" {
+            Self::Right
+        } else {
+            Self::None
+        }
+    }
+
+    /// Internal function. Returns the cell's text padded to the column's width.
+    ///
+    /// - `text` : The cell's text.
+    /// - `width` : The column's width.
+    fn pad(&self, text: &str, width: usize) -> String {
+        let padding = width.saturating_sub(text.chars().count());
+        match self {
+            Self::None | Self::Left => format!("{}{}", text, " ".repeat(padding)),
+            Self::Right => format!("{}{}", " ".repeat(padding), text),
+            Self::Center => format!(
+                "{}{}{}",
+                " ".repeat(padding / 2),
+                text,
+                " ".repeat(padding - padding / 2)
+            ),
+        }
+    }
+
+    /// Internal function. Returns the Markdown separator of the column, below the header.
+    ///
+    /// - `width` : The column's width.
+    fn separator(&self, width: usize) -> String {
+        match self {
+            Self::None => "-".repeat(width),
+            Self::Left => format!(":{}", "-".repeat(width - 1)),
+            Self::Right => format!("{}:", "-".repeat(width - 1)),
+            Self::Center => format!(":{}:", "-".repeat(width - 2)),
+        }
+    }
+}
+
+/// Internal function. Returns the children of an element.
+///
+/// - `element` : The parent element.
+fn children(element: &HtmlElement) -> impl Iterator<Item = &Element> {
+    element.children.iter().map(|child| child.as_ref())
+}
+
+/// Internal function. Returns the unescaped value of an element's attribute.
+///
+/// - `element` : The element.
+/// - `name` : The attribute's name.
+fn attribute(element: &HtmlElement, name: &str) -> Option<String> {
+    element
+        .config
+        .attributes
+        .get(name)
+        .and_then(|value| value.as_deref())
+        .map(unescape_html)
+}
+
+/// Internal function. Indicates if the element's content is not rendered as text :
+/// scripts, styles, embedded media and the elements marked with `aria-hidden="true"`.
+///
+/// - `element` : The element to check.
+fn is_hidden(element: &HtmlElement) -> bool {
+    if let Some(Some(hidden)) = element.config.attributes.get("aria-hidden") {
+        if hidden == "true" {
+            return true;
+        }
+    }
+    matches!(
+        element.tag,
+        TagType::Head
+            | TagType::Title
+            | TagType::Meta
+            | TagType::Link
+            | TagType::Base
+            | TagType::Script
+            | TagType::NoScript
+            | TagType::Style
+            | TagType::Template
+            | TagType::Comment
+            | TagType::Col
+            | TagType::Colgroup
+            | TagType::DataList
+            | TagType::Select
+            | TagType::TextArea
+            | TagType::IFrame
+            | TagType::Svg
+            | TagType::Canvas
+            | TagType::Audio
+            | TagType::Video
+            | TagType::Embed
+            | TagType::Object
+            | TagType::Param
+            | TagType::Source
+            | TagType::Track
+            | TagType::Map
+            | TagType::Area
+    )
+}
+
+/// Internal function. Returns the unescaped text of an element and its sub-elements, whitespaces included.
+///
+/// - `element` : The element.
+fn raw_text(element: &HtmlElement) -> String {
+    let mut text = String::new();
+    for child in children(element) {
+        match child {
+            Element::Text(content) => text.push_str(&unescape_html(content)),
+            Element::Element(child) if child.tag == TagType::Br => text.push('\n'),
+            Element::Element(child) if !is_hidden(child) => text.push_str(&raw_text(child)),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Internal function. Returns the text with its whitespaces runs replaced by a single space, and trimmed.
+///
+/// - `text` : The text to collapse.
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Internal function. Escapes the Markdown special characters of a text.
+///
+/// - `text` : The text to escape.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '~') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Internal function. Returns a Markdown code span, delimited by enough backticks.
+///
+/// - `code` : The code.
+fn code_span(code: &str) -> String {
+    let mut delimiter = "`".to_string();
+    while code.contains(&delimiter) {
+        delimiter.push('`');
+    }
+    if code.starts_with('`') || code.ends_with('`') {
+        format!("{} {} {}", delimiter, code, delimiter)
+    } else {
+        format!("{}{}{}", delimiter, code, delimiter)
+    }
+}
+
+/// Internal function. Adds a prefix to each line of a text.
+///
+/// - `text` : The text to prefix.
+/// - `first` : The prefix of the first line.
+/// - `other` : The prefix of the other lines.
+/// - `empty` : The prefix of the empty lines.
+fn prefix_lines(text: &str, first: &str, other: &str, empty: &str) -> String {
+    text.split('\n')
+        .enumerate()
+        .map(|(index, line)| match (index, line.is_empty()) {
+            (0, true) => first.trim_end().to_string(),
+            (0, false) => format!("{}{}", first, line),
+            (_, true) => empty.to_string(),
+            (_, false) => format!("{}{}", other, line),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Internal function. Wraps a line at the given width, breaking on spaces.
+///
+/// - `line` : The line to wrap.
+/// - `width` : The maximal line width.
+fn wrap(line: &str, width: usize) -> String {
+    let mut lines = vec![];
+    let mut current = String::new();
+    for word in line.split(' ').filter(|word| !word.is_empty()) {
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    lines.push(current);
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{element::HtmlElementConfig, tags::TagType};
+
+    fn node(tag: TagType, children: Vec<Element>) -> Element {
+        node_with(tag, &[], children)
+    }
+
+    fn node_with(tag: TagType, attributes: &[(&str, &str)], children: Vec<Element>) -> Element {
+        let mut config = HtmlElementConfig::new_empty();
+        for (name, value) in attributes {
+            config = config.set_attribute(name.to_string(), Some(value.to_string()));
+        }
+        let mut element = HtmlElement::new(tag, config);
+        element.children = children.into_iter().map(Box::new).collect();
+        Element::Element(element)
+    }
+
+    fn text(text: &str) -> Element {
+        Element::Text(text.to_string())
+    }
+
+    fn report() -> Vec<Element> {
+        vec![
+            node(TagType::H1, vec![text("Sales")]),
+            node(
+                TagType::P,
+                vec![
+                    text("See the "),
+                    node_with(
+                        TagType::A,
+                        &[("href", "https://example.com/q1")],
+                        vec![text("figures")],
+                    ),
+                    text(" for *Q1* &amp; Q2."),
+                ],
+            ),
+            node(
+                TagType::Ul,
+                vec![
+                    node(TagType::Li, vec![text("North")]),
+                    node(
+                        TagType::Li,
+                        vec![
+                            text("South"),
+                            node(TagType::Ul, vec![node(TagType::Li, vec![text("Coast")])]),
+                        ],
+                    ),
+                ],
+            ),
+            node(
+                TagType::Table,
+                vec![
+                    node(
+                        TagType::Thead,
+                        vec![node(
+                            TagType::Tr,
+                            vec![
+                                node(TagType::Th, vec![text("Region")]),
+                                node(TagType::Th, vec![text("Total")]),
+                            ],
+                        )],
+                    ),
+                    node(
+                        TagType::Tbody,
+                        vec![node(
+                            TagType::Tr,
+                            vec![
+                                node(TagType::Td, vec![text("North")]),
+                                node(TagType::Td, vec![text("12")]),
+                            ],
+                        )],
+                    ),
+                ],
+            ),
+            node(TagType::Script, vec![text("alert(1)")]),
+            node_with(
+                TagType::Div,
+                &[("aria-hidden", "true")],
+                vec![text("hidden")],
+            ),
+        ]
+    }
+
+    #[test]
+    fn markdown_rendering() {
+        assert_eq!(
+            to_markdown(&report()),
+            "# Sales\n\n\
+             See the [figures][1] for \\*Q1\\* & Q2.\n\n\
+             - North\n- South\n  - Coast\n\n\
+             | Region | Total |\n| ------ | ----- |\n| North  | 12    |\n\n\
+             [1]: https://example.com/q1\n"
+        );
+    }
+
+    #[test]
+    fn plain_text_rendering() {
+        assert_eq!(
+            to_plain_text(&report(), 20),
+            "Sales\n=====\n\n\
+             See the figures [1]\nfor *Q1* & Q2.\n\n\
+             - North\n- South\n  - Coast\n\n\
+             +--------+-------+\n| Region | Total |\n+========+=======+\n| North  | 12    |\n+--------+-------+\n\n\
+             [1] https://example.com/q1\n"
+        );
+    }
+
+    #[test]
+    fn plain_text_is_wrapped_on_spaces() {
+        let paragraph = [node(
+            TagType::P,
+            vec![text("one two three four five six seven eight nine ten")],
+        )];
+        let rendered = to_plain_text(&paragraph, 24);
+        assert_eq!(
+            rendered,
+            "one two three four five\nsix seven eight nine ten\n"
+        );
+        assert!(rendered.lines().all(|line| line.len() <= 24));
+    }
+
+    #[test]
+    fn code_and_quotes_in_markdown() {
+        let elements = [
+            node(
+                TagType::P,
+                vec![text("Use "), node(TagType::Code, vec![text("a`b")])],
+            ),
+            node(TagType::Pre, vec![text("  keep\n   this")]),
+            node(
+                TagType::Blockquote,
+                vec![node(TagType::P, vec![text("quoted")])],
+            ),
+        ];
+        assert_eq!(
+            to_markdown(&elements),
+            "Use ``a`b``\n\n```\n  keep\n   this\n```\n\n> quoted\n"
+        );
+    }
+
+    #[test]
+    fn hidden_content_is_ignored() {
+        let elements = [
+            node(TagType::Script, vec![text("alert(1)")]),
+            node(TagType::Style, vec![text("p {}")]),
+            node_with(
+                TagType::Div,
+                &[("aria-hidden", "true")],
+                vec![text("hidden")],
+            ),
+        ];
+        assert_eq!(to_markdown(&elements).trim(), "");
+        assert_eq!(to_plain_text(&elements, 80).trim(), "");
+    }
+}
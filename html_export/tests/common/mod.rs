@@ -0,0 +1,41 @@
+use html_export::element::Element;
+
+/// Returns the element as compact html, with sorted attributes and classes.
+pub fn outline(element: &Element) -> String {
+    match element {
+        Element::Text(text) => text.clone(),
+        Element::Slot(name) => format!("{{{}}}", name),
+        Element::Element(element) => {
+            let tag = element.tag.get_tag_name();
+            let mut attributes = element
+                .config
+                .attributes
+                .iter()
+                .map(|(name, value)| match value {
+                    Some(value) => format!(" {}=\"{}\"", name, value),
+                    None => format!(" {}", name),
+                })
+                .collect::<Vec<String>>();
+            if let Some(id) = &element.config.id {
+                attributes.push(format!(" id=\"{}\"", id));
+            }
+            let mut classes = element
+                .config
+                .classes
+                .iter()
+                .cloned()
+                .collect::<Vec<String>>();
+            if !classes.is_empty() {
+                classes.sort();
+                attributes.push(format!(" class=\"{}\"", classes.join(" ")));
+            }
+            attributes.sort();
+            let children = element
+                .children
+                .iter()
+                .map(|child| outline(child))
+                .collect::<String>();
+            format!("<{}{}>{}</{}>", tag, attributes.concat(), children, tag)
+        }
+    }
+}
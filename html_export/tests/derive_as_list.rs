@@ -0,0 +1,136 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::outline;
+use html_export::composed::list::{from_iterator, term_definition_list_from_map, AsList, ListType};
+use html_export::element::HtmlElementConfig;
+
+#[derive(AsList)]
+#[list(class = "language")]
+struct Language {
+    #[list(term, tag = "strong")]
+    name: String,
+    #[list(definition, label = "Published in")]
+    year: u32,
+    #[list(skip)]
+    _creator: String,
+}
+
+#[derive(AsList)]
+enum Task {
+    #[list(class = "done")]
+    Done(String),
+    #[list(class = "pending")]
+    Pending {
+        #[list(term)]
+        name: String,
+        #[list(definition, format = "format_progress")]
+        progress: f64,
+    },
+    #[list(label = "Nothing to do")]
+    Idle,
+    #[list(skip)]
+    Hidden,
+}
+
+#[derive(AsList, Eq, Hash, PartialEq)]
+#[list(term)]
+struct Country(String);
+
+#[derive(AsList)]
+#[list(definition)]
+struct Population(#[list(tag = "em")] u64);
+
+fn format_progress(progress: &f64) -> String {
+    format!("{:.0} %", progress * 100.0)
+}
+
+fn languages() -> Vec<Language> {
+    vec![
+        Language {
+            name: "Rust".to_string(),
+            year: 2015,
+            _creator: "Graydon Hoare".to_string(),
+        },
+        Language {
+            name: "C".to_string(),
+            year: 1972,
+            _creator: "Dennis Ritchie".to_string(),
+        },
+    ]
+}
+
+fn list(items: &Vec<impl AsList>, mode: ListType) -> String {
+    outline(
+        &from_iterator(items, &mode, HtmlElementConfig::new_empty())
+            .unwrap()
+            .unwrap(),
+    )
+}
+
+#[test]
+fn unordered_and_ordered_lists_contain_the_fields() {
+    let items = concat!(
+        r#"<li class="language"><strong>Rust</strong><span><strong>Published in</strong>2015</span></li>"#,
+        r#"<li class="language"><strong>C</strong><span><strong>Published in</strong>1972</span></li>"#,
+    );
+    assert_eq!(
+        list(&languages(), ListType::Unordered),
+        format!("<ul>{}</ul>", items)
+    );
+    assert_eq!(
+        list(&languages(), ListType::Ordered),
+        format!("<ol>{}</ol>", items)
+    );
+}
+
+#[test]
+fn definition_lists_use_the_term_and_definition_fields() {
+    assert_eq!(
+        list(&languages(), ListType::TermDefinition),
+        concat!(
+            "<dl><dt><strong>Rust</strong></dt>",
+            "<dd><span><strong>Published in</strong>2015</span></dd>",
+            "<dt><strong>C</strong></dt>",
+            "<dd><span><strong>Published in</strong>1972</span></dd></dl>"
+        )
+    );
+}
+
+#[test]
+fn each_variant_is_rendered() {
+    let tasks = vec![
+        Task::Done("Write the parser".to_string()),
+        Task::Pending {
+            name: "Write the docs".to_string(),
+            progress: 0.4,
+        },
+        Task::Idle,
+        Task::Hidden,
+    ];
+    assert_eq!(
+        list(&tasks, ListType::Unordered),
+        concat!(
+            r#"<ul><li class="done"><span>Write the parser</span></li>"#,
+            r#"<li class="pending"><span>Write the docs</span><span>40 %</span></li>"#,
+            "<li>Nothing to do</li></ul>"
+        )
+    );
+    assert_eq!(
+        list(&tasks, ListType::TermDefinition),
+        "<dl><dt><span>Write the docs</span></dt><dd><span>40 %</span></dd></dl>"
+    );
+}
+
+#[test]
+fn maps_are_converted_to_definition_lists() {
+    let populations = HashMap::from([(Country("France".to_string()), Population(68_000_000))]);
+    let list = term_definition_list_from_map(&populations, HtmlElementConfig::new_empty())
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        outline(&list),
+        "<dl><dt><span>France</span></dt><dd><em>68000000</em></dd></dl>"
+    );
+}
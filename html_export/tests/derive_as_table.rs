@@ -0,0 +1,100 @@
+mod common;
+
+use common::outline;
+use html_export::composed::table::AsTable;
+
+#[derive(AsTable)]
+#[table(foot_label = "Total")]
+struct Item {
+    #[table(header = "Unit price", format = "format_price", min, class = "price")]
+    unit_price: f64,
+    #[table(order = 1)]
+    name: String,
+    #[table(avg)]
+    quantity: u64,
+    #[table(format = "format_price", sum)]
+    total: f64,
+    #[table(skip)]
+    _internal_id: u64,
+}
+
+#[derive(AsTable)]
+struct Line(
+    #[table(header = "Label", count)] String,
+    #[table(header = "Amount", max)] u32,
+);
+
+impl Item {
+    fn new(name: &str, unit_price: f64, quantity: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            unit_price,
+            quantity,
+            total: unit_price * quantity as f64,
+            _internal_id: 7,
+        }
+    }
+}
+
+fn format_price(price: &f64) -> String {
+    format!("{:.2} €", price)
+}
+
+fn items() -> Vec<Item> {
+    vec![
+        Item::new("Item A", 10.0, 3),
+        Item::new("Item B", 5.5, 2),
+        Item::new("Item C", 20.0, 1),
+    ]
+}
+
+#[test]
+fn header_follows_the_order_and_skips_columns() {
+    let items = items();
+    assert_eq!(
+        outline(&items[0].as_table_head().unwrap()),
+        concat!(
+            "<thead><tr><th>Name</th>",
+            r#"<th class="price">Unit price</th>"#,
+            "<th>Quantity</th><th>Total</th></tr></thead>"
+        )
+    );
+}
+
+#[test]
+fn rows_use_the_format_functions() {
+    let items = items();
+    assert_eq!(
+        outline(&items[1].as_table_row().unwrap()),
+        concat!(
+            "<tr><td>Item B</td>",
+            r#"<td class="price">5.50 €</td>"#,
+            "<td>2</td><td>11.00 €</td></tr>"
+        )
+    );
+}
+
+#[test]
+fn footer_holds_the_aggregates() {
+    assert_eq!(
+        outline(&Item::as_table_foot_of(&items()).unwrap()),
+        concat!(
+            "<tfoot><tr><td>Total</td>",
+            r#"<td class="price">5.50 €</td>"#,
+            "<td>2</td><td>61.00 €</td></tr></tfoot>"
+        )
+    );
+}
+
+#[test]
+fn tuple_structs_use_the_given_headers() {
+    let lines = vec![Line("a".to_string(), 4), Line("b".to_string(), 9)];
+    assert_eq!(
+        outline(&lines[0].as_table_head().unwrap()),
+        "<thead><tr><th>Label</th><th>Amount</th></tr></thead>"
+    );
+    assert_eq!(
+        outline(&Line::as_table_foot_of(&lines).unwrap()),
+        "<tfoot><tr><td>2</td><td>9</td></tr></tfoot>"
+    );
+}
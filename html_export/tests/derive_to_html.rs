@@ -0,0 +1,56 @@
+mod common;
+
+use common::outline;
+use html_export::html::ToHtml;
+
+#[derive(ToHtml)]
+#[html(tag = "article", class = "comment")]
+struct Comment {
+    #[html(tag = "h2", label = "By")]
+    author: String,
+    #[html(tag = "p", format = "quote")]
+    text: String,
+    #[html(skip)]
+    _token: String,
+}
+
+#[derive(ToHtml)]
+enum Status {
+    #[html(label = "<Active>")]
+    Active,
+    Away(String),
+}
+
+fn quote(text: &String) -> String {
+    format!("\"{}\"", text)
+}
+
+#[test]
+fn field_values_are_escaped() {
+    let comment = Comment {
+        author: "<script>alert(1)</script>".to_string(),
+        text: "Tom & Jerry".to_string(),
+        _token: "secret".to_string(),
+    };
+    assert_eq!(
+        outline(&comment.to_html()),
+        concat!(
+            r#"<article class="comment">"#,
+            "<h2><strong>By</strong>&lt;script&gt;alert(1)&lt;/script&gt;</h2>",
+            "<p>&quot;Tom &amp; Jerry&quot;</p>",
+            "</article>"
+        )
+    );
+}
+
+#[test]
+fn variant_labels_and_values_are_escaped() {
+    assert_eq!(
+        outline(&Status::Active.to_html()),
+        "<div>&lt;Active&gt;</div>"
+    );
+    assert_eq!(
+        outline(&Status::Away("<b>".to_string()).to_html()),
+        "<div><span>&lt;b&gt;</span></div>"
+    );
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::outline;
+use html_export::{element::Element, html};
+
+#[test]
+fn texts_expressions_and_attributes_are_escaped() {
+    let name = "<b>Tom & Jerry</b>";
+    let title = "\"quoted\"";
+    let element = html! {
+        <p title={title} data-kind="a<b">"1 < 2 " {name}</p>
+    };
+    assert_eq!(
+        outline(&element),
+        concat!(
+            r#"<p data-kind="a&lt;b" title="&quot;quoted&quot;">"#,
+            "1 &lt; 2 &lt;b&gt;Tom &amp; Jerry&lt;/b&gt;</p>"
+        )
+    );
+}
+
+#[test]
+fn elements_are_inserted_as_is() {
+    let inner = html! { <em>"kept"</em> };
+    assert_eq!(outline(&html! { <p>{inner}</p> }), "<p><em>kept</em></p>");
+}
+
+#[test]
+fn loops_and_iterators_repeat_their_nodes() {
+    let tags = ["rust", "html"];
+    let element = html! {
+        <div>
+            <ul>
+                for (index, tag) in tags.iter().enumerate() {
+                    <li id={format!("tag-{}", index)}>{tag}</li>
+                }
+            </ul>
+            <p>{for tags.iter().map(|tag| tag.to_uppercase())}</p>
+        </div>
+    };
+    assert_eq!(
+        outline(&element),
+        concat!(
+            "<div><ul>",
+            r#"<li id="tag-0">rust</li><li id="tag-1">html</li>"#,
+            "</ul><p>RUSTHTML</p></div>"
+        )
+    );
+}
+
+#[test]
+fn conditionals_select_a_branch() {
+    let render = |count: Option<u32>| {
+        html! {
+            <label class="count">
+                if let Some(count) = count {
+                    <strong>{count}</strong>
+                } else {
+                    "none"
+                }
+            </label>
+        }
+    };
+    assert_eq!(
+        outline(&render(Some(3))),
+        r#"<label class="count"><strong>3</strong></label>"#
+    );
+    assert_eq!(
+        outline(&render(None)),
+        r#"<label class="count">none</label>"#
+    );
+}
+
+#[test]
+fn boolean_attributes_have_no_value() {
+    let Element::Element(input) = html!(<input type="checkbox" checked />) else {
+        panic!("html! did not return an html element");
+    };
+    assert_eq!(
+        input.config.get_attribute("checked".to_string()),
+        Some(None)
+    );
+}
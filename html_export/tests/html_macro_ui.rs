@@ -0,0 +1,5 @@
+#[test]
+fn invalid_html_is_rejected_at_compile_time() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
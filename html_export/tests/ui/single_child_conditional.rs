@@ -0,0 +1,12 @@
+use html_export::html;
+
+fn main() {
+    let long = true;
+    let _ = html! {
+        <legend>
+            if long {
+                "Long" <em>"title"</em>
+            }
+        </legend>
+    };
+}
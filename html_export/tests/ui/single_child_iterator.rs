@@ -0,0 +1,6 @@
+use html_export::html;
+
+fn main() {
+    let words = ["a", "b"];
+    let _ = html! { <title>{for words}</title> };
+}
@@ -0,0 +1,12 @@
+use html_export::html;
+
+fn main() {
+    let words = ["a", "b"];
+    let _ = html! {
+        <label>
+            for word in words {
+                <span>{word}</span>
+            }
+        </label>
+    };
+}
@@ -0,0 +1,5 @@
+use html_export::html;
+
+fn main() {
+    let _ = html! { <br>"text"</br> };
+}
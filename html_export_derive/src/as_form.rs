@@ -0,0 +1,518 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    spanned::Spanned, Data, DataEnum, DeriveInput, Fields, GenericArgument, LitStr, Member,
+    PathArguments, Type,
+};
+
+use crate::{
+    attributes::{FormOptions, InputKind},
+    common::humanize,
+};
+
+/// Expands `#[derive(AsForm)]`.
+///
+/// - `input` : The derived struct or enum.
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    match &input.data {
+        Data::Struct(data) => expand_struct(&input, &data.fields),
+        Data::Enum(data) => expand_enum(&input, data),
+        Data::Union(_) => Err(syn::Error::new(
+            input.ident.span(),
+            "AsForm can not be derived for unions",
+        )),
+    }
+}
+
+/// Internal function. Expands `#[derive(AsForm)]` for a struct : each field becomes an input.
+///
+/// - `input` : The derived struct.
+/// - `fields` : The struct's fields.
+fn expand_struct(input: &DeriveInput, fields: &Fields) -> syn::Result<TokenStream> {
+    let container = FormOptions::parse(&input.attrs, &["legend", "method", "action", "submit"])?;
+
+    let mut inputs = vec![];
+    for (index, field) in fields.iter().enumerate() {
+        let options = FormOptions::parse(
+            &field.attrs,
+            &[
+                "label",
+                "name",
+                "id",
+                "placeholder",
+                "pattern",
+                "min_length",
+                "max_length",
+                "min",
+                "max",
+                "step",
+                "skip",
+                "text",
+                "select",
+                "radio",
+                "nested",
+            ],
+        )?;
+        if options.skip {
+            continue;
+        }
+        let name = match (&options.name, &field.ident) {
+            (Some(name), _) => name.clone(),
+            (None, Some(ident)) => {
+                LitStr::new(ident.to_string().trim_start_matches("r#"), ident.span())
+            }
+            (None, None) => {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "tuple struct fields need a `#[form(name = \"...\")]` attribute",
+                ))
+            }
+        };
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(syn::Index::from(index)),
+        };
+        inputs.push(field_input(&options, name, member, &field.ty)?);
+    }
+
+    let fields = quote! {
+        #[allow(unused_mut)]
+        let mut fields: ::std::vec::Vec<::html_export::element::Element> = ::std::vec::Vec::new();
+        #(#inputs)*
+    };
+    let content = match &container.legend {
+        Some(legend) => quote! {
+            #[allow(unused_mut)]
+            let mut content = ::std::vec![
+                ::html_export::composed::forms::form::wrap_fields_in_fieldset(
+                    fields,
+                    ::std::string::String::from(#legend),
+                    ::html_export::element::HtmlElementConfig::new_empty(),
+                    ::html_export::element::HtmlElementConfig::new_empty(),
+                )?
+            ];
+        },
+        None => quote! {
+            #[allow(unused_mut)]
+            let mut content = fields;
+        },
+    };
+    let submit = match &container.submit {
+        Some(submit) => quote! {
+            content.push(::html_export::element::Element::Element(
+                ::html_export::element::HtmlElement::new(
+                    ::html_export::tags::TagType::Input,
+                    ::html_export::element::HtmlElementConfig::new_empty()
+                        .set_attribute(
+                            ::std::string::String::from("type"),
+                            ::std::option::Option::Some(::std::string::String::from("submit")),
+                        )
+                        .set_attribute(
+                            ::std::string::String::from("value"),
+                            ::std::option::Option::Some(::std::string::String::from(#submit)),
+                        ),
+                ),
+            ));
+        },
+        None => quote!(),
+    };
+    let name = &input.ident;
+    let missing = quote! {
+        || ::html_export::errors::Error::from(
+            ::html_export::errors::FieldCreationError::MissingField(
+                ::std::string::String::from(::std::stringify!(#name)),
+            ),
+        )
+    };
+    let result = if container.method.is_some() || container.action.is_some() {
+        let method = container.method.iter();
+        let action = container.action.iter();
+        quote! {
+            ::html_export::composed::forms::form::create_form(
+                content,
+                ::html_export::composed::forms::form::FormConfig::new()
+                    #(.with_method(::std::string::String::from(#method)))*
+                    #(.with_action(::std::string::String::from(#action)))*,
+                ::html_export::element::HtmlElementConfig::new_empty(),
+            )?
+            .ok_or_else(#missing)
+        }
+    } else if container.legend.is_some() && container.submit.is_none() {
+        quote!(content.pop().ok_or_else(#missing))
+    } else {
+        quote! {
+            let mut div = ::html_export::element::Element::Element(
+                ::html_export::element::HtmlElement::new(
+                    ::html_export::tags::TagType::Div,
+                    ::html_export::element::HtmlElementConfig::new_empty(),
+                ),
+            );
+            div.try_extend(content)?;
+            ::std::result::Result::Ok(div)
+        }
+    };
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        #[allow(clippy::unnecessary_cast)]
+        impl #impl_generics ::html_export::composed::forms::form::AsForm for #name #type_generics #where_clause {
+            fn as_form_field(&self) -> ::std::option::Option<::html_export::element::Element> {
+                ::html_export::composed::forms::form::AsForm::try_as_form_field(self).ok()
+            }
+
+            fn try_as_form_field(&self) -> ::html_export::errors::HtmlResult<::html_export::element::Element> {
+                #fields
+                #content
+                #submit
+                #result
+            }
+        }
+    })
+}
+
+/// Internal function. Expands `#[derive(AsForm)]` for a fieldless enum :
+/// the variants become the choices of a `<select>` or of `<input type="radio">`s.
+///
+/// - `input` : The derived enum.
+/// - `data` : The enum's variants.
+fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
+    let container = FormOptions::parse(&input.attrs, &["label", "name", "id", "radio"])?;
+
+    let mut choices = vec![];
+    let mut arms = vec![];
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(
+                variant.fields.span(),
+                "AsForm can only be derived for enums without fields",
+            ));
+        }
+        let options = FormOptions::parse(&variant.attrs, &["label", "value"])?;
+        let ident = &variant.ident;
+        let value = options
+            .value
+            .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+        let label = options
+            .label
+            .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+        choices.push(quote! {
+            (::std::string::String::from(#value), ::std::string::String::from(#label))
+        });
+        arms.push(quote!(Self::#ident => ::std::string::String::from(#value)));
+    }
+    let choice_value = if arms.is_empty() {
+        quote!(match *self {})
+    } else {
+        quote!(match self { #(#arms,)* })
+    };
+
+    let ident = &input.ident;
+    let name = container
+        .name
+        .unwrap_or_else(|| LitStr::new(&ident.to_string().to_lowercase(), ident.span()));
+    let id = container.id.unwrap_or_else(|| name.clone());
+    let label = container
+        .label
+        .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+    let config = input_field_config(&name, &id, &label);
+    let field = if container.kind == Some(InputKind::Radio) {
+        quote! {
+            ::html_export::composed::forms::fields::choice_input::create_labeled_choice_radios(
+                #config,
+                ::std::option::Option::Some(self),
+            )
+        }
+    } else {
+        quote! {
+            ::html_export::composed::forms::fields::choice_input::create_labeled_choice_select(
+                #config,
+                ::html_export::composed::forms::fields::select_input::SelectInputConfigs::new()
+                    .with_required(true),
+                ::std::option::Option::Some(self),
+            )
+        }
+    };
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::html_export::composed::forms::fields::choice_input::FormChoice for #ident #type_generics #where_clause {
+            fn choices() -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+                ::std::vec![#(#choices),*]
+            }
+
+            fn choice_value(&self) -> ::std::string::String {
+                #choice_value
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::html_export::composed::forms::form::AsForm for #ident #type_generics #where_clause {
+            fn as_form_field(&self) -> ::std::option::Option<::html_export::element::Element> {
+                #field.ok()
+            }
+
+            fn try_as_form_field(&self) -> ::html_export::errors::HtmlResult<::html_export::element::Element> {
+                ::std::result::Result::Ok(#field?)
+            }
+        }
+    })
+}
+
+/// Internal function. Returns the statement pushing the field's input into `fields`.
+/// If an option does not apply to the field's input, a compile error is returned.
+///
+/// - `options` : The field's options.
+/// - `name` : The input's name.
+/// - `member` : The field's name or index.
+/// - `ty` : The field's type.
+fn field_input(
+    options: &FormOptions,
+    name: LitStr,
+    member: Member,
+    ty: &Type,
+) -> syn::Result<TokenStream> {
+    let (ty, optional) = unwrap_option(ty);
+    let kind = options.kind.unwrap_or_else(|| infer_kind(ty));
+    check_option(
+        options.pattern.as_ref(),
+        "pattern",
+        kind,
+        &[InputKind::Text],
+    )?;
+    check_option(
+        options.min_length.as_ref(),
+        "min_length",
+        kind,
+        &[InputKind::Text],
+    )?;
+    check_option(
+        options.max_length.as_ref(),
+        "max_length",
+        kind,
+        &[InputKind::Text],
+    )?;
+    check_option(options.min.as_ref(), "min", kind, &[InputKind::Number])?;
+    check_option(options.max.as_ref(), "max", kind, &[InputKind::Number])?;
+    check_option(options.step.as_ref(), "step", kind, &[InputKind::Number])?;
+    check_option(
+        options.placeholder.as_ref(),
+        "placeholder",
+        kind,
+        &[InputKind::Text, InputKind::Number],
+    )?;
+    if kind == InputKind::Nested {
+        for (option, span) in [
+            ("name", options.name.as_ref().map(|name| name.span())),
+            ("id", options.id.as_ref().map(|id| id.span())),
+            ("label", options.label.as_ref().map(|label| label.span())),
+        ] {
+            if let Some(span) = span {
+                return Err(syn::Error::new(
+                    span,
+                    format!("`{}` does not apply to nested fields", option),
+                ));
+            }
+        }
+    }
+
+    let id = options.id.clone().unwrap_or_else(|| name.clone());
+    let label = options
+        .label
+        .clone()
+        .unwrap_or_else(|| LitStr::new(&humanize(&name.value()), name.span()));
+    let config = input_field_config(&name, &id, &label);
+    let span = ty.span();
+    let place_holder = options.placeholder.iter();
+    let fields = quote!(::html_export::composed::forms::fields);
+    let invalid = quote! {
+        map_err(|error| {
+            ::html_export::errors::FieldCreationError::InvalidField(
+                ::std::string::String::from(#name),
+                ::std::boxed::Box::new(::html_export::errors::Error::from(error)),
+            )
+        })
+    };
+    let element = match kind {
+        InputKind::Text => {
+            let value = if optional {
+                quote!(self.#member.as_ref().map(::std::string::ToString::to_string))
+            } else {
+                quote!(::std::option::Option::Some(::std::string::ToString::to_string(&self.#member)))
+            };
+            let required = (!optional).then(|| quote!(.set_required()));
+            let pattern = options.pattern.iter();
+            let min_length = options.min_length.iter();
+            let max_length = options.max_length.iter();
+            quote_spanned! {span=>
+                #fields::text_input::create_label_text_field(
+                    #config,
+                    #fields::text_input::TextInputConfig::new()
+                        #required
+                        #(.with_place_holder(::std::string::String::from(#place_holder)))*
+                        #(.with_pattern(::std::string::String::from(#pattern)))*
+                        #(.with_min_length(#min_length))*
+                        #(.with_max_length(#max_length))*,
+                    #value,
+                )
+                .#invalid?
+            }
+        }
+        InputKind::Number => {
+            let value = if optional {
+                quote_spanned!(span=> self.#member.map(|value| value as f64))
+            } else {
+                quote_spanned!(span=> ::std::option::Option::Some(self.#member as f64))
+            };
+            let required = (!optional).then(|| quote!(.with_required(true)));
+            let min = options.min.iter();
+            let max = options.max.iter();
+            let step = options.step.iter();
+            quote! {
+                #fields::number_input::create_labeled_number_input(
+                    #config,
+                    #fields::number_input::NumberInputConfigs::new()
+                        #required
+                        #(.with_place_holder(::std::string::String::from(#place_holder)))*
+                        #(.with_min((#min) as f64))*
+                        #(.with_max((#max) as f64))*
+                        #(.with_step((#step) as f64))*,
+                    #value,
+                )
+                .#invalid?
+            }
+        }
+        InputKind::Checkbox => {
+            let checked = if optional {
+                quote_spanned!(span=> self.#member.unwrap_or(false))
+            } else {
+                quote_spanned!(span=> self.#member)
+            };
+            quote! {
+                #fields::checkbox_input::create_labeled_checkbox(
+                    &#config,
+                    ::std::string::String::from("true"),
+                    #checked,
+                )
+                .#invalid?
+            }
+        }
+        InputKind::Select | InputKind::Radio => {
+            let value = if optional {
+                quote_spanned!(span=> self.#member.as_ref())
+            } else {
+                quote_spanned!(span=> ::std::option::Option::Some(&self.#member))
+            };
+            if kind == InputKind::Radio {
+                quote! {
+                    #fields::choice_input::create_labeled_choice_radios(#config, #value).#invalid?
+                }
+            } else {
+                let required = (!optional).then(|| quote!(.with_required(true)));
+                quote! {
+                    #fields::choice_input::create_labeled_choice_select(
+                        #config,
+                        #fields::select_input::SelectInputConfigs::new() #required,
+                        #value,
+                    )
+                    .#invalid?
+                }
+            }
+        }
+        InputKind::Nested => {
+            let value = if optional {
+                quote_spanned!(span=> self.#member.as_ref())
+            } else {
+                quote_spanned!(span=> ::std::option::Option::Some(&self.#member))
+            };
+            return Ok(quote! {
+                if let ::std::option::Option::Some(value) = #value {
+                    fields.push(
+                        ::html_export::composed::forms::form::AsForm::try_as_form_field(value)
+                            .#invalid?,
+                    );
+                }
+            });
+        }
+    };
+    Ok(quote!(fields.push(#element);))
+}
+
+/// Internal function. Returns the expression creating an `InputFieldConfig`.
+///
+/// - `name` : The input's name.
+/// - `id` : The input's id.
+/// - `label` : The label's content.
+fn input_field_config(name: &LitStr, id: &LitStr, label: &LitStr) -> TokenStream {
+    quote! {
+        ::html_export::composed::forms::fields::field::InputFieldConfig::new(
+            ::std::string::String::from(#name),
+            ::std::string::String::from(#id),
+            ::std::string::String::from(#label),
+        )
+    }
+}
+
+/// Internal function. Returns the inner type of an `Option<T>` and `true`, or the type itself and `false`.
+///
+/// - `ty` : The field's type.
+fn unwrap_option(ty: &Type) -> (&Type, bool) {
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            if segment.ident == "Option" {
+                if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                    if let Some(GenericArgument::Type(inner)) = arguments.args.first() {
+                        return (inner, true);
+                    }
+                }
+            }
+        }
+    }
+    (ty, false)
+}
+
+/// Internal function. Returns the input matching the field's type.
+/// Types that are not strings, numbers or booleans are expected to implement `FormChoice`.
+///
+/// - `ty` : The field's type, without its `Option`.
+fn infer_kind(ty: &Type) -> InputKind {
+    match ty {
+        Type::Reference(reference) => infer_kind(&reference.elem),
+        Type::Path(path) => match path.path.segments.last() {
+            Some(segment) => match segment.ident.to_string().as_str() {
+                "String" | "str" | "char" => InputKind::Text,
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+                | "u128" | "usize" | "f32" | "f64" => InputKind::Number,
+                "bool" => InputKind::Checkbox,
+                _ => InputKind::Select,
+            },
+            None => InputKind::Select,
+        },
+        _ => InputKind::Select,
+    }
+}
+
+/// Internal function. Checks that an option applies to the field's input.
+///
+/// - `option` : The option's value, if given.
+/// - `name` : The option's name.
+/// - `kind` : The field's input.
+/// - `allowed` : The inputs the option applies to.
+fn check_option<T>(
+    option: Option<&T>,
+    name: &str,
+    kind: InputKind,
+    allowed: &[InputKind],
+) -> syn::Result<()>
+where
+    T: Spanned,
+{
+    match option {
+        Some(option) if !allowed.contains(&kind) => Err(syn::Error::new(
+            option.span(),
+            format!("`{}` does not apply to this field's input", name),
+        )),
+        _ => Ok(()),
+    }
+}
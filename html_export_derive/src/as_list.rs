@@ -0,0 +1,259 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, LitStr};
+
+use crate::{
+    attributes::ListOptions,
+    common::{
+        destructure, element_with_children, field_element, self_places, tag_or_default, text,
+    },
+    tags::Tag,
+};
+
+/// Represents the list conversions of a struct or of an enum variant.
+///
+/// - `item` : The expression converting the value to an ordered or unordered list item.
+/// - `role_item` : The expression converting the value to a definition list item (`<dt>` or `<dd>`), if any.
+/// - `pair` : The expression converting the value to a term and a definition, if any.
+struct Conversion {
+    item: TokenStream,
+    role_item: Option<TokenStream>,
+    pair: Option<TokenStream>,
+}
+
+/// Expands `#[derive(AsList)]`.
+///
+/// - `input` : The derived struct or enum.
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let container = ListOptions::parse(&input.attrs, &["tag", "class", "term", "definition"])?;
+    let (item_tag, item_span) = tag_or_default(&container.tag, "li", input.ident.span())?;
+    let (to_list_item, to_definition_list_item) = match &input.data {
+        Data::Struct(data) => {
+            let conversion = convert(
+                &container,
+                &item_tag,
+                item_span,
+                &container.classes,
+                &data.fields,
+                &self_places(&data.fields),
+                None,
+            )?;
+            (list_item(&conversion), definition_list_item(&conversion))
+        }
+        Data::Enum(data) => {
+            let mut item_arms = vec![];
+            let mut pair_arms = vec![];
+            for variant in data.variants.iter() {
+                let options =
+                    ListOptions::parse(&variant.attrs, &["tag", "class", "label", "skip"])?;
+                let ident = &variant.ident;
+                if options.skip {
+                    item_arms.push(quote!(Self::#ident { .. } => ::std::option::Option::None));
+                    pair_arms.push(quote!(Self::#ident { .. } => ::std::option::Option::None));
+                    continue;
+                }
+                let (tag, tag_span) = match &options.tag {
+                    Some(tag) => (Tag::from_literal(tag)?, tag.span()),
+                    None => (Tag::from_name(&item_tag.name, item_span)?, ident.span()),
+                };
+                let classes = container
+                    .classes
+                    .iter()
+                    .chain(options.classes.iter())
+                    .cloned()
+                    .collect::<Vec<LitStr>>();
+                let label = options
+                    .label
+                    .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+                let (pattern, places) = destructure(&variant.fields);
+                let conversion = convert(
+                    &container,
+                    &tag,
+                    tag_span,
+                    &classes,
+                    &variant.fields,
+                    &places,
+                    Some(&label),
+                )?;
+                let item = list_item(&conversion);
+                let pair = definition_list_item(&conversion);
+                item_arms.push(quote!(Self::#ident #pattern => #item));
+                pair_arms.push(quote!(Self::#ident #pattern => #pair));
+            }
+            if item_arms.is_empty() {
+                (quote!(match *self {}), quote!(match *self {}))
+            } else {
+                (
+                    quote!(match self { #(#item_arms,)* }),
+                    quote!(match self { #(#pair_arms,)* }),
+                )
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "AsList can not be derived for unions",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        #[allow(unused_variables)]
+        impl #impl_generics ::html_export::composed::list::AsList for #name #type_generics #where_clause {
+            fn to_list_item(
+                &self,
+                mode: &::html_export::composed::list::ListType,
+            ) -> ::std::option::Option<::html_export::element::Element> {
+                #to_list_item
+            }
+
+            fn to_definition_list_item(
+                &self,
+            ) -> ::std::option::Option<(::html_export::element::Element, ::html_export::element::Element)> {
+                #to_definition_list_item
+            }
+        }
+    })
+}
+
+/// Internal function. Returns the list conversions of a struct or of an enum variant.
+/// Unit variants are converted to their label.
+///
+/// - `container` : The item's options.
+/// - `tag` : The list item's tag.
+/// - `tag_span` : The location of the list item's tag, used for errors.
+/// - `classes` : The list item's css classes.
+/// - `fields` : The fields to convert.
+/// - `places` : The place expressions of each field.
+/// - `label` : The unit variant's text.
+fn convert(
+    container: &ListOptions,
+    tag: &Tag,
+    tag_span: Span,
+    classes: &[LitStr],
+    fields: &Fields,
+    places: &[TokenStream],
+    label: Option<&LitStr>,
+) -> syn::Result<Conversion> {
+    let mut children = vec![];
+    let mut term = None;
+    let mut definition = None;
+    if let (Fields::Unit, Some(label)) = (fields, label) {
+        children.push(text(quote!(::std::string::String::from(#label))));
+    }
+    for (field, place) in fields.iter().zip(places) {
+        let options = ListOptions::parse(
+            &field.attrs,
+            &[
+                "tag",
+                "class",
+                "label",
+                "skip",
+                "display",
+                "format",
+                "nested",
+                "term",
+                "definition",
+            ],
+        )?;
+        if options.skip {
+            continue;
+        }
+        let element = field_element(
+            &options.tag,
+            &options.classes,
+            &options.label,
+            &options.format,
+            place,
+            field.span(),
+        )?;
+        for (is_role, role, name) in [
+            (options.term, &mut term, "term"),
+            (options.definition, &mut definition, "definition"),
+        ] {
+            if !is_role {
+                continue;
+            }
+            if role.is_some() {
+                return Err(syn::Error::new(
+                    field.span(),
+                    format!("a list item can have at most 1 {} field", name),
+                ));
+            }
+            *role = Some((element.clone(), field.span()));
+        }
+        children.push(element);
+    }
+
+    let pair = match (term, definition) {
+        (Some((term, _)), Some((definition, _))) => Some(quote!((#term, #definition))),
+        (None, None) => None,
+        (Some((_, span)), None) => {
+            return Err(syn::Error::new(
+                span,
+                "a `term` field needs a `definition` field",
+            ))
+        }
+        (None, Some((_, span))) => {
+            return Err(syn::Error::new(
+                span,
+                "a `definition` field needs a `term` field",
+            ))
+        }
+    };
+    tag.check_children(children.len(), tag_span)?;
+    let item = element_with_children(tag, classes, None, &children);
+    let role_item = match (container.term, container.definition) {
+        (true, _) => Some(element_with_children(
+            &Tag::from_name("dt", tag_span)?,
+            classes,
+            None,
+            &children,
+        )),
+        (_, true) => Some(element_with_children(
+            &Tag::from_name("dd", tag_span)?,
+            classes,
+            None,
+            &children,
+        )),
+        _ => None,
+    };
+    Ok(Conversion {
+        item,
+        role_item,
+        pair,
+    })
+}
+
+/// Internal function. Returns the `to_list_item` expression of a conversion.
+///
+/// - `conversion` : The struct's or the variant's conversion.
+fn list_item(conversion: &Conversion) -> TokenStream {
+    let item = &conversion.item;
+    let role_item = match &conversion.role_item {
+        Some(role_item) => quote!(::std::option::Option::Some(#role_item)),
+        None => quote!(::std::option::Option::None),
+    };
+    quote! {
+        match mode {
+            ::html_export::composed::list::ListType::Ordered
+            | ::html_export::composed::list::ListType::Unordered => {
+                ::std::option::Option::Some(#item)
+            }
+            ::html_export::composed::list::ListType::TermDefinition => #role_item,
+        }
+    }
+}
+
+/// Internal function. Returns the `to_definition_list_item` expression of a conversion.
+///
+/// - `conversion` : The struct's or the variant's conversion.
+fn definition_list_item(conversion: &Conversion) -> TokenStream {
+    match &conversion.pair {
+        Some(pair) => quote!(::std::option::Option::Some(#pair)),
+        None => quote!(::std::option::Option::None),
+    }
+}
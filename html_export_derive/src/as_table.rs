@@ -0,0 +1,232 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, LitStr, Member, Type};
+
+use crate::{
+    attributes::{Aggregate, Format, TableOptions},
+    common::{element_with_children, humanize, text},
+    tags::Tag,
+};
+
+/// Represents a table column generated from a struct field.
+///
+/// - `header` : The column's header text.
+/// - `classes` : The cells css classes.
+/// - `format` : Defines how the field's value is converted to text.
+/// - `aggregate` : The aggregate displayed in the table's footer.
+/// - `member` : The field's name or index.
+/// - `ty` : The field's type.
+struct Column<'a> {
+    header: LitStr,
+    classes: Vec<LitStr>,
+    format: Format,
+    aggregate: Option<(Aggregate, Span)>,
+    member: Member,
+    ty: &'a Type,
+}
+
+/// Expands `#[derive(AsTable)]`.
+///
+/// - `input` : The derived struct.
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let container = TableOptions::parse(&input.attrs, &["foot_label"])?;
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "AsTable can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut columns = vec![];
+    for (index, field) in fields.iter().enumerate() {
+        let options = TableOptions::parse(
+            &field.attrs,
+            &[
+                "header", "class", "order", "skip", "format", "sum", "avg", "min", "max", "count",
+            ],
+        )?;
+        if options.skip {
+            continue;
+        }
+        let header = match (&options.header, &field.ident) {
+            (Some(header), _) => header.clone(),
+            (None, Some(ident)) => LitStr::new(&humanize(&ident.to_string()), ident.span()),
+            (None, None) => {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "tuple struct fields need a `#[table(header = \"...\")]` attribute",
+                ))
+            }
+        };
+        let order = match &options.order {
+            Some(order) => order.base10_parse::<usize>()?,
+            None => usize::MAX,
+        };
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(syn::Index::from(index)),
+        };
+        columns.push((
+            (order, index),
+            Column {
+                header,
+                classes: options.classes,
+                format: options.format,
+                aggregate: options.aggregate,
+                member,
+                ty: &field.ty,
+            },
+        ));
+    }
+    columns.sort_by_key(|(key, _)| *key);
+    let columns = columns
+        .into_iter()
+        .map(|(_, column)| column)
+        .collect::<Vec<Column>>();
+
+    let span = input.ident.span();
+    let th = Tag::from_name("th", span)?;
+    let td = Tag::from_name("td", span)?;
+    let tr = Tag::from_name("tr", span)?;
+
+    let header_cells = columns
+        .iter()
+        .map(|column| {
+            let header = &column.header;
+            element_with_children(
+                &th,
+                &column.classes,
+                None,
+                &[text(quote!(::std::string::String::from(#header)))],
+            )
+        })
+        .collect::<Vec<TokenStream>>();
+    let head = element_with_children(
+        &Tag::from_name("thead", span)?,
+        &[],
+        None,
+        &[element_with_children(&tr, &[], None, &header_cells)],
+    );
+
+    let row_cells = columns
+        .iter()
+        .map(|column| {
+            let member = &column.member;
+            let value = format_value(&column.format, quote!(&self.#member));
+            element_with_children(&td, &column.classes, None, &[text(value)])
+        })
+        .collect::<Vec<TokenStream>>();
+    let row = element_with_children(&tr, &[], None, &row_cells);
+
+    let foot = if columns.iter().any(|column| column.aggregate.is_some()) {
+        let mut foot_cells = vec![];
+        for (index, column) in columns.iter().enumerate() {
+            let content = match (&column.aggregate, &container.foot_label) {
+                (Some((aggregate, span)), _) => {
+                    vec![text(aggregate_value(column, *aggregate, *span))]
+                }
+                (None, Some(label)) if index == 0 => {
+                    vec![text(quote!(::std::string::String::from(#label)))]
+                }
+                _ => vec![],
+            };
+            foot_cells.push(element_with_children(&td, &column.classes, None, &content));
+        }
+        let foot = element_with_children(
+            &Tag::from_name("tfoot", span)?,
+            &[],
+            None,
+            &[element_with_children(&tr, &[], None, &foot_cells)],
+        );
+        quote!(::std::option::Option::Some(#foot))
+    } else {
+        quote!(::std::option::Option::None)
+    };
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::html_export::composed::table::AsTable for #name #type_generics #where_clause {
+            fn as_table_head(&self) -> ::std::option::Option<::html_export::element::Element> {
+                ::std::option::Option::Some(#head)
+            }
+
+            fn as_table_row(&self) -> ::std::option::Option<::html_export::element::Element> {
+                ::std::option::Option::Some(#row)
+            }
+
+            fn as_table_foot(&self) -> ::std::option::Option<::html_export::element::Element> {
+                <Self as ::html_export::composed::table::AsTable>::as_table_foot_of(
+                    ::std::slice::from_ref(self),
+                )
+            }
+
+            fn as_table_foot_of(items: &[Self]) -> ::std::option::Option<::html_export::element::Element> {
+                #foot
+            }
+        }
+    })
+}
+
+/// Internal function. Returns the expression converting a value reference to a `String`.
+///
+/// - `format` : Defines how the value is converted.
+/// - `value` : The value reference expression.
+fn format_value(format: &Format, value: TokenStream) -> TokenStream {
+    match format {
+        Format::Function(function) => quote!(#function(#value)),
+        _ => quote!(::std::string::ToString::to_string(#value)),
+    }
+}
+
+/// Internal function. Returns the expression computing the column's aggregate over `items` as a `String`.
+///
+/// - `column` : The aggregated column.
+/// - `aggregate` : The aggregate to compute.
+/// - `span` : The location of the aggregate option, used for type errors.
+fn aggregate_value(column: &Column, aggregate: Aggregate, span: Span) -> TokenStream {
+    let member = &column.member;
+    let ty = column.ty;
+    match aggregate {
+        Aggregate::Sum => {
+            let value = format_value(&column.format, quote!(&sum));
+            quote_spanned! {span=>
+                {
+                    let sum = items.iter().map(|item| &item.#member).sum::<#ty>();
+                    #value
+                }
+            }
+        }
+        Aggregate::Average => quote_spanned! {span=>
+            if items.is_empty() {
+                ::std::string::String::new()
+            } else {
+                let sum = items.iter().map(|item| item.#member as f64).sum::<f64>();
+                ::std::string::ToString::to_string(&(sum / items.len() as f64))
+            }
+        },
+        Aggregate::Min | Aggregate::Max => {
+            let keep_current = match aggregate {
+                Aggregate::Min => quote!(current <= value),
+                _ => quote!(current >= value),
+            };
+            let value = format_value(&column.format, quote!(value));
+            quote_spanned! {span=>
+                items
+                    .iter()
+                    .map(|item| &item.#member)
+                    .fold(::std::option::Option::None, |result: ::std::option::Option<&#ty>, value| match result {
+                        ::std::option::Option::Some(current) if #keep_current => ::std::option::Option::Some(current),
+                        _ => ::std::option::Option::Some(value),
+                    })
+                    .map(|value| #value)
+                    .unwrap_or_default()
+            }
+        }
+        Aggregate::Count => quote!(::std::string::ToString::to_string(&items.len())),
+    }
+}
@@ -0,0 +1,375 @@
+use syn::{meta::ParseNestedMeta, Attribute, Expr, LitInt, LitStr, Path};
+
+/// Defines how a field's value is converted to Html.
+///
+/// - `Display` : The value is converted to raw text using its `Display` implementation.
+/// - `Function` : The value is converted to raw text using the given `fn(&T) -> String` function.
+/// - `Nested` : The value is converted using its `ToHtml` implementation.
+pub enum Format {
+    Display,
+    Function(Path),
+    Nested,
+}
+
+/// Represents the options of an `#[html(...)]` attribute.
+///
+/// - `tag` : The element's Html tag name.
+/// - `classes` : The element's css classes.
+/// - `id` : The element's id.
+/// - `label` : The field's label or the unit variant's text.
+/// - `skip` : Indicates if the field is ignored.
+/// - `format` : Defines how the field's value is converted to Html.
+pub struct HtmlOptions {
+    pub tag: Option<LitStr>,
+    pub classes: Vec<LitStr>,
+    pub id: Option<LitStr>,
+    pub label: Option<LitStr>,
+    pub skip: bool,
+    pub format: Format,
+}
+
+impl HtmlOptions {
+    /// Parses the `#[html(...)]` attributes.
+    /// If an option is not part of the `allowed` options, a compile error is returned.
+    ///
+    /// - `attributes` : The item's attributes.
+    /// - `allowed` : The options allowed for this item.
+    pub fn parse(attributes: &[Attribute], allowed: &[&str]) -> syn::Result<Self> {
+        let mut options = Self {
+            tag: None,
+            classes: vec![],
+            id: None,
+            label: None,
+            skip: false,
+            format: Format::Display,
+        };
+        parse_options(attributes, "html", allowed, |name, meta| {
+            match name {
+                "tag" => options.tag = Some(meta.value()?.parse()?),
+                "class" => options.classes.push(meta.value()?.parse()?),
+                "id" => options.id = Some(meta.value()?.parse()?),
+                "label" => options.label = Some(meta.value()?.parse()?),
+                "skip" => options.skip = true,
+                "display" => options.format = Format::Display,
+                "nested" => options.format = Format::Nested,
+                "format" => options.format = parse_function(&meta)?,
+                _ => unreachable!(),
+            }
+            Ok(())
+        })?;
+        Ok(options)
+    }
+}
+
+/// The aggregates computed in a table's footer.
+///
+/// - `Sum` : The sum of the column's values.
+/// - `Average` : The average of the column's values, as `f64`.
+/// - `Min` : The column's minimum value.
+/// - `Max` : The column's maximum value.
+/// - `Count` : The number of rows.
+#[derive(Clone, Copy)]
+pub enum Aggregate {
+    Sum,
+    Average,
+    Min,
+    Max,
+    Count,
+}
+
+/// Represents the options of a `#[table(...)]` attribute.
+///
+/// - `header` : The column's header text.
+/// - `classes` : The cells css classes.
+/// - `order` : The column's position.
+/// - `skip` : Indicates if the field is ignored.
+/// - `format` : Defines how the field's value is converted to text.
+/// - `aggregate` : The aggregate displayed in the table's footer.
+/// - `foot_label` : The text of the footer's first cell.
+pub struct TableOptions {
+    pub header: Option<LitStr>,
+    pub classes: Vec<LitStr>,
+    pub order: Option<LitInt>,
+    pub skip: bool,
+    pub format: Format,
+    pub aggregate: Option<(Aggregate, proc_macro2::Span)>,
+    pub foot_label: Option<LitStr>,
+}
+
+impl TableOptions {
+    /// Parses the `#[table(...)]` attributes.
+    /// If an option is not part of the `allowed` options, a compile error is returned.
+    ///
+    /// - `attributes` : The item's attributes.
+    /// - `allowed` : The options allowed for this item.
+    pub fn parse(attributes: &[Attribute], allowed: &[&str]) -> syn::Result<Self> {
+        let mut options = Self {
+            header: None,
+            classes: vec![],
+            order: None,
+            skip: false,
+            format: Format::Display,
+            aggregate: None,
+            foot_label: None,
+        };
+        parse_options(attributes, "table", allowed, |name, meta| {
+            let aggregate = match name {
+                "header" => {
+                    options.header = Some(meta.value()?.parse()?);
+                    None
+                }
+                "class" => {
+                    options.classes.push(meta.value()?.parse()?);
+                    None
+                }
+                "order" => {
+                    options.order = Some(meta.value()?.parse()?);
+                    None
+                }
+                "skip" => {
+                    options.skip = true;
+                    None
+                }
+                "format" => {
+                    options.format = parse_function(&meta)?;
+                    None
+                }
+                "foot_label" => {
+                    options.foot_label = Some(meta.value()?.parse()?);
+                    None
+                }
+                "sum" => Some(Aggregate::Sum),
+                "avg" => Some(Aggregate::Average),
+                "min" => Some(Aggregate::Min),
+                "max" => Some(Aggregate::Max),
+                "count" => Some(Aggregate::Count),
+                _ => unreachable!(),
+            };
+            if let Some(aggregate) = aggregate {
+                if options.aggregate.is_some() {
+                    return Err(meta.error("a column can have at most 1 aggregate"));
+                }
+                options.aggregate = Some((aggregate, meta.path.get_ident().unwrap().span()));
+            }
+            Ok(())
+        })?;
+        Ok(options)
+    }
+}
+
+/// Represents the options of a `#[list(...)]` attribute.
+///
+/// - `tag` : The element's Html tag name.
+/// - `classes` : The element's css classes.
+/// - `label` : The field's label or the unit variant's text.
+/// - `skip` : Indicates if the field or the variant is ignored.
+/// - `format` : Defines how the field's value is converted to Html.
+/// - `term` : Indicates if the field is the definition list's term, or if the item is converted to a `<dt>`.
+/// - `definition` : Indicates if the field is the definition list's definition, or if the item is converted to a `<dd>`.
+pub struct ListOptions {
+    pub tag: Option<LitStr>,
+    pub classes: Vec<LitStr>,
+    pub label: Option<LitStr>,
+    pub skip: bool,
+    pub format: Format,
+    pub term: bool,
+    pub definition: bool,
+}
+
+impl ListOptions {
+    /// Parses the `#[list(...)]` attributes.
+    /// If an option is not part of the `allowed` options, a compile error is returned.
+    ///
+    /// - `attributes` : The item's attributes.
+    /// - `allowed` : The options allowed for this item.
+    pub fn parse(attributes: &[Attribute], allowed: &[&str]) -> syn::Result<Self> {
+        let mut options = Self {
+            tag: None,
+            classes: vec![],
+            label: None,
+            skip: false,
+            format: Format::Display,
+            term: false,
+            definition: false,
+        };
+        parse_options(attributes, "list", allowed, |name, meta| {
+            match name {
+                "tag" => options.tag = Some(meta.value()?.parse()?),
+                "class" => options.classes.push(meta.value()?.parse()?),
+                "label" => options.label = Some(meta.value()?.parse()?),
+                "skip" => options.skip = true,
+                "display" => options.format = Format::Display,
+                "nested" => options.format = Format::Nested,
+                "format" => options.format = parse_function(&meta)?,
+                "term" => options.term = true,
+                "definition" => options.definition = true,
+                _ => unreachable!(),
+            }
+            if options.term && options.definition {
+                return Err(meta.error("`term` and `definition` can not be combined"));
+            }
+            Ok(())
+        })?;
+        Ok(options)
+    }
+}
+
+/// The inputs generated for a form field.
+///
+/// - `Text` : An `<input type="text">` filled using the value's `Display` implementation.
+/// - `Number` : An `<input type="number">`. The value must be a numeric primitive.
+/// - `Checkbox` : An `<input type="checkbox">`. The value must be a `bool`.
+/// - `Select` : A `<select>`. The value must implement `FormChoice`.
+/// - `Radio` : `<input type="radio">`s. The value must implement `FormChoice`.
+/// - `Nested` : The value is converted using its `AsForm` implementation.
+#[derive(Clone, Copy, PartialEq)]
+pub enum InputKind {
+    Text,
+    Number,
+    Checkbox,
+    Select,
+    Radio,
+    Nested,
+}
+
+/// Represents the options of a `#[form(...)]` attribute.
+///
+/// - `label` : The field's label, or the choice's label for enum variants.
+/// - `name` : The input's name.
+/// - `id` : The input's id.
+/// - `placeholder` : The input's place holder text.
+/// - `pattern` : The text input's validation pattern.
+/// - `min_length` / `max_length` : The text input's length bounds.
+/// - `min` / `max` / `step` : The number input's bounds and step.
+/// - `kind` : The input explicitly requested for the field.
+/// - `skip` : Indicates if the field is ignored.
+/// - `value` : The choice's value for enum variants.
+/// - `legend` : The `<fieldset>`'s legend wrapping the fields.
+/// - `method` / `action` : The `<form>`'s method and action.
+/// - `submit` : The submit button's text.
+pub struct FormOptions {
+    pub label: Option<LitStr>,
+    pub name: Option<LitStr>,
+    pub id: Option<LitStr>,
+    pub placeholder: Option<LitStr>,
+    pub pattern: Option<LitStr>,
+    pub min_length: Option<LitInt>,
+    pub max_length: Option<LitInt>,
+    pub min: Option<Expr>,
+    pub max: Option<Expr>,
+    pub step: Option<Expr>,
+    pub kind: Option<InputKind>,
+    pub skip: bool,
+    pub value: Option<LitStr>,
+    pub legend: Option<LitStr>,
+    pub method: Option<LitStr>,
+    pub action: Option<LitStr>,
+    pub submit: Option<LitStr>,
+}
+
+impl FormOptions {
+    /// Parses the `#[form(...)]` attributes.
+    /// If an option is not part of the `allowed` options, a compile error is returned.
+    ///
+    /// - `attributes` : The item's attributes.
+    /// - `allowed` : The options allowed for this item.
+    pub fn parse(attributes: &[Attribute], allowed: &[&str]) -> syn::Result<Self> {
+        let mut options = Self {
+            label: None,
+            name: None,
+            id: None,
+            placeholder: None,
+            pattern: None,
+            min_length: None,
+            max_length: None,
+            min: None,
+            max: None,
+            step: None,
+            kind: None,
+            skip: false,
+            value: None,
+            legend: None,
+            method: None,
+            action: None,
+            submit: None,
+        };
+        parse_options(attributes, "form", allowed, |name, meta| {
+            match name {
+                "label" => options.label = Some(meta.value()?.parse()?),
+                "name" => options.name = Some(meta.value()?.parse()?),
+                "id" => options.id = Some(meta.value()?.parse()?),
+                "placeholder" => options.placeholder = Some(meta.value()?.parse()?),
+                "pattern" => options.pattern = Some(meta.value()?.parse()?),
+                "min_length" => options.min_length = Some(meta.value()?.parse()?),
+                "max_length" => options.max_length = Some(meta.value()?.parse()?),
+                "min" => options.min = Some(meta.value()?.parse()?),
+                "max" => options.max = Some(meta.value()?.parse()?),
+                "step" => options.step = Some(meta.value()?.parse()?),
+                "skip" => options.skip = true,
+                "value" => options.value = Some(meta.value()?.parse()?),
+                "legend" => options.legend = Some(meta.value()?.parse()?),
+                "method" => options.method = Some(meta.value()?.parse()?),
+                "action" => options.action = Some(meta.value()?.parse()?),
+                "submit" => options.submit = Some(meta.value()?.parse()?),
+                "text" | "select" | "radio" | "nested" => {
+                    if options.kind.is_some() {
+                        return Err(meta.error("a field can have at most 1 input kind"));
+                    }
+                    options.kind = Some(match name {
+                        "text" => InputKind::Text,
+                        "select" => InputKind::Select,
+                        "radio" => InputKind::Radio,
+                        _ => InputKind::Nested,
+                    });
+                }
+                _ => unreachable!(),
+            }
+            Ok(())
+        })?;
+        Ok(options)
+    }
+}
+
+/// Internal function. Parses the `#[namespace(...)]` attributes, calling `parse` for each option.
+/// If an option is not part of the `allowed` options, a compile error is returned.
+///
+/// - `attributes` : The item's attributes.
+/// - `namespace` : The attribute's name.
+/// - `allowed` : The options allowed for this item.
+/// - `parse` : Parses an option given its name.
+fn parse_options<F>(
+    attributes: &[Attribute],
+    namespace: &str,
+    allowed: &[&str],
+    mut parse: F,
+) -> syn::Result<()>
+where
+    F: FnMut(&str, ParseNestedMeta) -> syn::Result<()>,
+{
+    for attribute in attributes.iter().filter(|a| a.path().is_ident(namespace)) {
+        attribute.parse_nested_meta(|meta| {
+            let name = meta
+                .path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+            if !allowed.contains(&name.as_str()) {
+                return Err(meta.error(format!(
+                    "unsupported {} option, expected one of: {}",
+                    namespace,
+                    allowed.join(", ")
+                )));
+            }
+            parse(&name, meta)
+        })?;
+    }
+    Ok(())
+}
+
+/// Internal function. Parses a `format = "path::to::function"` option.
+///
+/// - `meta` : The option.
+fn parse_function(meta: &ParseNestedMeta) -> syn::Result<Format> {
+    let path: LitStr = meta.value()?.parse()?;
+    Ok(Format::Function(path.parse()?))
+}
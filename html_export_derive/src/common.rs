@@ -0,0 +1,164 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Fields, Ident, LitStr};
+
+use crate::{attributes::Format, tags::Tag};
+
+/// Returns the expression creating an empty `HtmlElement` with the given tag, classes and id.
+///
+/// - `tag` : The element's tag.
+/// - `classes` : The element's css classes.
+/// - `id` : The element's optional id.
+pub fn new_element(tag: &Tag, classes: &[LitStr], id: Option<&LitStr>) -> TokenStream {
+    let tag = tag.to_tokens();
+    let id = match id {
+        Some(id) => quote!(::std::option::Option::Some(::std::string::String::from(#id))),
+        None => quote!(::std::option::Option::None),
+    };
+    quote! {
+        ::html_export::element::HtmlElement::new(
+            #tag,
+            ::html_export::element::HtmlElementConfig::new_class_id(
+                <::std::vec::Vec<::std::string::String>>::from([
+                    #(::std::string::String::from(#classes)),*
+                ]),
+                #id,
+            ),
+        )
+    }
+}
+
+/// Returns the expression creating an `Element` with the given tag, classes, id and sub-elements.
+/// The sub-elements must have been checked against the tag's restrictions with `Tag::check_children`.
+///
+/// - `tag` : The element's tag.
+/// - `classes` : The element's css classes.
+/// - `id` : The element's optional id.
+/// - `children` : The sub-elements expressions.
+pub fn element_with_children(
+    tag: &Tag,
+    classes: &[LitStr],
+    id: Option<&LitStr>,
+    children: &[TokenStream],
+) -> TokenStream {
+    let element = new_element(tag, classes, id);
+    quote! {
+        {
+            let mut element = #element;
+            #(element.children.push(::std::boxed::Box::new(#children));)*
+            ::html_export::element::Element::Element(element)
+        }
+    }
+}
+
+/// Returns the given tag, or the default tag if None.
+///
+/// - `tag` : The optional tag name.
+/// - `default` : The default tag name.
+/// - `span` : The location used for errors if the tag is not given.
+pub fn tag_or_default(tag: &Option<LitStr>, default: &str, span: Span) -> syn::Result<(Tag, Span)> {
+    match tag {
+        Some(tag) => Ok((Tag::from_literal(tag)?, tag.span())),
+        None => Ok((Tag::from_name(default, span)?, span)),
+    }
+}
+
+/// Returns the expression converting a field to an `Element`.
+/// The value is wrapped in a `<span>` unless another tag is given,
+/// nested values without tag, class or label are inserted as is.
+///
+/// - `tag` : The field's optional tag name.
+/// - `classes` : The field's css classes.
+/// - `label` : The field's optional label, inserted in a `<strong>` before the value.
+/// - `format` : Defines how the field's value is converted.
+/// - `place` : The field's place expression.
+/// - `span` : The field's location, used for errors.
+pub fn field_element(
+    tag: &Option<LitStr>,
+    classes: &[LitStr],
+    label: &Option<LitStr>,
+    format: &Format,
+    place: &TokenStream,
+    span: Span,
+) -> syn::Result<TokenStream> {
+    let nested_as_is = tag.is_none() && label.is_none() && classes.is_empty();
+    if let (Format::Nested, true) = (format, nested_as_is) {
+        return Ok(quote!(::html_export::html::ToHtml::to_html(&#place)));
+    }
+    let (tag, tag_span) = tag_or_default(tag, "span", span)?;
+    let value = match format {
+        Format::Display => text(quote!(::std::string::ToString::to_string(&#place))),
+        Format::Function(function) => text(quote!(#function(&#place))),
+        Format::Nested => quote!(::html_export::html::ToHtml::to_html(&#place)),
+    };
+    let mut children = vec![];
+    if let Some(label) = label {
+        children.push(element_with_children(
+            &Tag::from_name("strong", label.span())?,
+            &[],
+            None,
+            &[text(quote!(::std::string::String::from(#label)))],
+        ));
+    }
+    children.push(value);
+    tag.check_children(children.len(), tag_span)?;
+    Ok(element_with_children(&tag, classes, None, &children))
+}
+
+/// Returns the expression creating an escaped raw text `Element`.
+///
+/// - `text` : The text expression, of type `String`.
+pub fn text(text: TokenStream) -> TokenStream {
+    quote!(::html_export::element::Element::Text(
+        ::html_export::miscellaneous::escape_html(&(#text))
+    ))
+}
+
+/// Returns the pattern destructuring the given fields and the place expressions of each binding.
+/// Bindings are named `field_0`, `field_1`...
+///
+/// - `fields` : The variant's fields.
+pub fn destructure(fields: &Fields) -> (TokenStream, Vec<TokenStream>) {
+    let bindings = (0..fields.len())
+        .map(|index| Ident::new(&format!("field_{}", index), proc_macro2::Span::call_site()))
+        .collect::<Vec<Ident>>();
+    let places = bindings.iter().map(|binding| quote!((*#binding))).collect();
+    let pattern = match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|field| &field.ident);
+            quote!({ #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(( #(#bindings),* )),
+        Fields::Unit => quote!(),
+    };
+    (pattern, places)
+}
+
+/// Returns the place expressions of the struct's fields (`self.name`, `self.0`...).
+///
+/// - `fields` : The struct's fields.
+pub fn self_places(fields: &Fields) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => quote!(self.#ident),
+            None => {
+                let index = syn::Index::from(index);
+                quote!(self.#index)
+            }
+        })
+        .collect()
+}
+
+/// Converts a field name to a human readable text : `unit_price` becomes `Unit price`.
+///
+/// - `ident` : The field's name.
+pub fn humanize(ident: &str) -> String {
+    let words = ident.trim_start_matches("r#").replace('_', " ");
+    let mut chars = words.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
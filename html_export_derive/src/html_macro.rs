@@ -0,0 +1,439 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{
+    braced,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    token, Expr, Ident, LitStr, Pat, Token,
+};
+
+use crate::tags::{check_attribute, Tag};
+
+/// Represents the content of the `html!` macro.
+///
+/// - `root` : The single root node.
+pub struct Html {
+    root: Node,
+}
+
+/// Represents a node of the `html!` macro.
+///
+/// - `Element` : An Html element, `<div class="x">...</div>`.
+/// - `Text` : A string literal, converted to escaped raw text.
+/// - `Expr` : A Rust expression `{value}`, converted with `IntoElement`.
+/// - `Iter` : A Rust iterator `{for values}`, each item is converted with `IntoElement`.
+/// - `For` : A loop `for pattern in values { nodes }`.
+/// - `If` : A conditional `if condition { nodes } else { nodes }`, `if let` is supported.
+enum Node {
+    Element(ElementNode),
+    Text(LitStr),
+    Expr(Expr),
+    Iter(Expr),
+    For(Box<Pat>, Expr, Vec<Node>),
+    If(TokenStream, Vec<Node>, Option<Vec<Node>>),
+}
+
+/// Represents an Html element node.
+///
+/// - `tag` : The element's tag.
+/// - `span` : The location of the tag name.
+/// - `attributes` : The element's attributes.
+/// - `children` : The element's sub-nodes.
+struct ElementNode {
+    tag: Tag,
+    span: Span,
+    attributes: Vec<Attribute>,
+    children: Vec<Node>,
+}
+
+/// Represents an Html attribute.
+///
+/// - `name` : The attribute's name.
+/// - `span` : The location of the attribute's name.
+/// - `value` : The attribute's value, None for boolean attributes.
+struct Attribute {
+    name: String,
+    span: Span,
+    value: Option<AttributeValue>,
+}
+
+/// Represents an Html attribute value.
+///
+/// - `Literal` : A string literal, `class="x"`.
+/// - `Expr` : A Rust expression converted with its `Display` implementation, `id={id}`.
+enum AttributeValue {
+    Literal(LitStr),
+    Expr(Expr),
+}
+
+impl Parse for Html {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let root = input.parse()?;
+        if !input.is_empty() {
+            return Err(
+                input.error("html! expects a single root node, wrap the nodes in a parent element")
+            );
+        }
+        Ok(Self { root })
+    }
+}
+
+impl Parse for Node {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![<]) {
+            Ok(Node::Element(input.parse()?))
+        } else if input.peek(LitStr) {
+            Ok(Node::Text(input.parse()?))
+        } else if input.peek(token::Brace) {
+            let content;
+            braced!(content in input);
+            if content.peek(Token![for]) {
+                content.parse::<Token![for]>()?;
+                Ok(Node::Iter(content.parse()?))
+            } else {
+                Ok(Node::Expr(content.parse()?))
+            }
+        } else if input.peek(Token![for]) {
+            input.parse::<Token![for]>()?;
+            let pattern = Pat::parse_multi_with_leading_vert(input)?;
+            input.parse::<Token![in]>()?;
+            let values = Expr::parse_without_eager_brace(input)?;
+            Ok(Node::For(Box::new(pattern), values, parse_block(input)?))
+        } else if input.peek(Token![if]) {
+            parse_if(input)
+        } else {
+            Err(input.error(
+                "expected an element `<tag>`, a string literal, `{expression}`, `for` or `if`",
+            ))
+        }
+    }
+}
+
+impl Parse for ElementNode {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![<]>()?;
+        let (name, span) = parse_name(input)?;
+        let tag = Tag::from_name(&name, span)?;
+        let mut attributes = vec![];
+        while !input.peek(Token![>]) && !input.peek(Token![/]) {
+            attributes.push(input.parse()?);
+        }
+        let mut children = vec![];
+        if input.peek(Token![/]) {
+            input.parse::<Token![/]>()?;
+            input.parse::<Token![>]>()?;
+        } else {
+            input.parse::<Token![>]>()?;
+            while !(input.peek(Token![<]) && input.peek2(Token![/])) {
+                if input.is_empty() {
+                    return Err(syn::Error::new(span, format!("<{}> is not closed", name)));
+                }
+                children.push(input.parse()?);
+            }
+            input.parse::<Token![<]>()?;
+            input.parse::<Token![/]>()?;
+            let (closing, closing_span) = parse_name(input)?;
+            if closing != name {
+                return Err(syn::Error::new(
+                    closing_span,
+                    format!("expected `</{}>`", name),
+                ));
+            }
+            input.parse::<Token![>]>()?;
+        }
+        Ok(Self {
+            tag,
+            span,
+            attributes,
+            children,
+        })
+    }
+}
+
+impl Parse for Attribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (name, span) = parse_name(input)?;
+        check_attribute(&name, span)?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            if input.peek(LitStr) {
+                Some(AttributeValue::Literal(input.parse()?))
+            } else {
+                let content;
+                braced!(content in input);
+                Some(AttributeValue::Expr(content.parse()?))
+            }
+        } else {
+            None
+        };
+        Ok(Self { name, span, value })
+    }
+}
+
+/// Internal function. Parses a tag or attribute name, including keywords and dashes (`http-equiv`).
+///
+/// - `input` : The macro's input.
+fn parse_name(input: ParseStream) -> syn::Result<(String, Span)> {
+    let first = Ident::parse_any(input)?;
+    let span = first.span();
+    let mut name = first.to_string();
+    while input.peek(Token![-]) {
+        input.parse::<Token![-]>()?;
+        name.push('-');
+        name.push_str(&Ident::parse_any(input)?.to_string());
+    }
+    Ok((name, span))
+}
+
+/// Internal function. Parses the nodes of a `{ ... }` block.
+///
+/// - `input` : The macro's input.
+fn parse_block(input: ParseStream) -> syn::Result<Vec<Node>> {
+    let content;
+    braced!(content in input);
+    let mut nodes = vec![];
+    while !content.is_empty() {
+        nodes.push(content.parse()?);
+    }
+    Ok(nodes)
+}
+
+/// Internal function. Parses an `if` node and its `else` branches.
+///
+/// - `input` : The macro's input.
+fn parse_if(input: ParseStream) -> syn::Result<Node> {
+    input.parse::<Token![if]>()?;
+    let condition = if input.peek(Token![let]) {
+        input.parse::<Token![let]>()?;
+        let pattern = Pat::parse_multi_with_leading_vert(input)?;
+        input.parse::<Token![=]>()?;
+        let value = Expr::parse_without_eager_brace(input)?;
+        quote!(let #pattern = #value)
+    } else {
+        let condition = Expr::parse_without_eager_brace(input)?;
+        quote!(#condition)
+    };
+    let then = parse_block(input)?;
+    let otherwise = if input.peek(Token![else]) {
+        input.parse::<Token![else]>()?;
+        if input.peek(Token![if]) {
+            Some(vec![parse_if(input)?])
+        } else {
+            Some(parse_block(input)?)
+        }
+    } else {
+        None
+    };
+    Ok(Node::If(condition, then, otherwise))
+}
+
+/// Expands the `html!` macro.
+///
+/// - `html` : The parsed macro content.
+pub fn expand(html: Html) -> syn::Result<TokenStream> {
+    match &html.root {
+        Node::Element(element) => element_expr(element),
+        Node::Text(text) => Ok(text_expr(text)),
+        Node::Expr(expr) => Ok(into_element(expr)),
+        node => Err(syn::Error::new(
+            node_span(node),
+            "the root node of html! must be an element, a string literal or an expression",
+        )),
+    }
+}
+
+/// Internal function. Returns the expression creating the element.
+/// The tag's restrictions are checked : a conditional counts as its largest branch,
+/// `for` loops and iterators are rejected in single-child tags since they can produce any number of sub-elements.
+///
+/// - `element` : The element node.
+fn element_expr(element: &ElementNode) -> syn::Result<TokenStream> {
+    let count = if element.tag.is_single_child {
+        max_children(&element.children)?
+    } else {
+        element.children.len()
+    };
+    element.tag.check_children(count, element.span)?;
+
+    let tag = element.tag.to_tokens();
+    let mut config = quote!(::html_export::element::HtmlElementConfig::new_empty());
+    for attribute in &element.attributes {
+        let name = &attribute.name;
+        config = match (name.as_str(), &attribute.value) {
+            ("class", Some(AttributeValue::Literal(classes))) => {
+                let classes = classes.value();
+                let classes = classes.split_whitespace();
+                quote!(#config #(.with_class(::std::string::String::from(#classes)))*)
+            }
+            ("class", Some(AttributeValue::Expr(classes))) => quote! {
+                #config.with_classes(
+                    ::std::string::ToString::to_string(&(#classes))
+                        .split_whitespace()
+                        .map(::std::string::String::from),
+                )
+            },
+            ("id", Some(value)) => {
+                let value = attribute_value(value);
+                quote!(#config.with_id(#value))
+            }
+            (_, Some(value)) => {
+                let value = attribute_value(value);
+                quote! {
+                    #config.set_attribute(
+                        ::std::string::String::from(#name),
+                        ::std::option::Option::Some(#value),
+                    )
+                }
+            }
+            ("class" | "id", None) => {
+                return Err(syn::Error::new(
+                    attribute.span,
+                    format!("`{}` needs a value", name),
+                ))
+            }
+            (_, None) => quote! {
+                #config.set_attribute(::std::string::String::from(#name), ::std::option::Option::None)
+            },
+        };
+    }
+
+    let children = children_statements(&element.children)?;
+    Ok(quote! {
+        {
+            let mut element = ::html_export::element::HtmlElement::new(#tag, #config);
+            {
+                let children = &mut element.children;
+                #children
+            }
+            ::html_export::element::Element::Element(element)
+        }
+    })
+}
+
+/// Internal function. Returns the maximum number of sub-elements produced by nodes.
+/// If a node can produce any number of sub-elements (`for` loop or iterator), the Err variant is returned.
+///
+/// - `nodes` : The sub-nodes.
+fn max_children(nodes: &[Node]) -> syn::Result<usize> {
+    let mut count = 0;
+    for node in nodes {
+        count += match node {
+            Node::Element(_) | Node::Text(_) | Node::Expr(_) => 1,
+            Node::Iter(_) | Node::For(..) => {
+                return Err(syn::Error::new(
+                    node_span(node),
+                    "`for` loops and iterators can not be used in a single-child tag, they can produce several sub-elements",
+                ))
+            }
+            Node::If(_, then, otherwise) => {
+                let otherwise = match otherwise {
+                    Some(otherwise) => max_children(otherwise)?,
+                    None => 0,
+                };
+                max_children(then)?.max(otherwise)
+            }
+        };
+    }
+    Ok(count)
+}
+
+/// Internal function. Returns the statements pushing the nodes into `children`.
+///
+/// - `nodes` : The sub-nodes.
+fn children_statements(nodes: &[Node]) -> syn::Result<TokenStream> {
+    let mut statements = vec![];
+    for node in nodes {
+        statements.push(match node {
+            Node::Element(element) => {
+                let element = element_expr(element)?;
+                quote!(children.push(::std::boxed::Box::new(#element));)
+            }
+            Node::Text(text) => {
+                let text = text_expr(text);
+                quote!(children.push(::std::boxed::Box::new(#text));)
+            }
+            Node::Expr(expr) => {
+                let element = into_element(expr);
+                quote!(children.push(::std::boxed::Box::new(#element));)
+            }
+            Node::Iter(values) => {
+                let item = quote_spanned!(values.span()=> item);
+                let element = into_element(&item);
+                quote! {
+                    for #item in #values {
+                        children.push(::std::boxed::Box::new(#element));
+                    }
+                }
+            }
+            Node::For(pattern, values, body) => {
+                let body = children_statements(body)?;
+                quote! {
+                    for #pattern in #values {
+                        #body
+                    }
+                }
+            }
+            Node::If(condition, then, otherwise) => {
+                let then = children_statements(then)?;
+                let otherwise = match otherwise {
+                    Some(otherwise) => {
+                        let otherwise = children_statements(otherwise)?;
+                        quote!(else { #otherwise })
+                    }
+                    None => quote!(),
+                };
+                quote! {
+                    if #condition {
+                        #then
+                    } #otherwise
+                }
+            }
+        });
+    }
+    Ok(quote!(#(#statements)*))
+}
+
+/// Internal function. Returns the expression converting a value to an element with `IntoElement`.
+///
+/// - `value` : The value expression.
+fn into_element<T>(value: &T) -> TokenStream
+where
+    T: quote::ToTokens + Spanned,
+{
+    quote_spanned!(value.span()=> ::html_export::html::IntoElement::into_element(#value))
+}
+
+/// Internal function. Returns the expression creating the escaped raw text of a string literal.
+///
+/// - `text` : The string literal.
+fn text_expr(text: &LitStr) -> TokenStream {
+    quote!(::html_export::element::Element::Text(::html_export::miscellaneous::escape_html(#text)))
+}
+
+/// Internal function. Returns the escaped `String` expression of an attribute value.
+///
+/// - `value` : The attribute value.
+fn attribute_value(value: &AttributeValue) -> TokenStream {
+    match value {
+        AttributeValue::Literal(literal) => {
+            quote!(::html_export::miscellaneous::escape_html(#literal))
+        }
+        AttributeValue::Expr(expr) => quote! {
+            ::html_export::miscellaneous::escape_html(&::std::string::ToString::to_string(&(#expr)))
+        },
+    }
+}
+
+/// Internal function. Returns the location of a node, used for errors.
+///
+/// - `node` : The node.
+fn node_span(node: &Node) -> Span {
+    match node {
+        Node::Element(element) => element.span,
+        Node::Text(text) => text.span(),
+        Node::Expr(expr) | Node::Iter(expr) => expr.span(),
+        Node::For(pattern, ..) => pattern.span(),
+        Node::If(condition, ..) => condition.span(),
+    }
+}
@@ -0,0 +1,194 @@
+//! Derive macros for the `html_export` crate.
+//! These macros are re-exported by `html_export` when its `derive` feature is enabled.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod as_form;
+mod as_list;
+mod as_table;
+mod attributes;
+mod common;
+mod html_macro;
+mod tags;
+mod to_html;
+
+/// Builds an `Element` with a JSX-like syntax.
+///
+/// ```ignore
+/// html! {
+///     <div class="card" id={id}>
+///         <h2>{title}</h2>
+///         if rows.is_empty() {
+///             <p>"No data"</p>
+///         } else {
+///             <ul>
+///                 for row in &rows {
+///                     <li class={row.kind}>{&row.name}</li>
+///                 }
+///             </ul>
+///         }
+///         {for footer}
+///     </div>
+/// }
+/// ```
+///
+/// The macro expects a single root node. The nodes are :
+/// - `<tag attribute="value" other={expression} boolean>...</tag>` or `<tag />` : An Html element.
+///   Tag and attribute names are checked at compile time, `data-*`, `aria-*` and `on*` attributes are accepted.
+///   Attribute values are converted with their `Display` implementation and escaped.
+///   The `class` attribute's value is split into css classes.
+/// - `"text"` : An escaped raw text.
+/// - `{expression}` : A value converted with `html_export::html::IntoElement` :
+///   elements are inserted as is, other values are converted to escaped raw text.
+/// - `{for values}` : Each item of the iterator, converted with `IntoElement`.
+/// - `for pattern in values { ... }` : Repeats the nodes for each value.
+/// - `if condition { ... } else { ... }` : Conditional nodes, `if let` and `else if` are supported.
+///
+/// The number of sub-elements of the tags that allow at most one is checked at compile time :
+/// an `if` node counts as its largest branch, `{for values}` and `for` nodes are rejected.
+#[proc_macro]
+pub fn html(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as html_macro::Html);
+    html_macro::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `html_export::html::ToHtml` for structs and enums.
+///
+/// The item is wrapped in a `<div>` and each field is converted to a `<span>` containing its escaped value.
+/// Enum variants are converted the same way, unit variants contain their name.
+///
+/// Item attributes `#[html(...)]` :
+/// - `tag = "section"` : The wrapper's Html tag.
+/// - `class = "person"` : Adds a css class to the wrapper. Can be repeated.
+/// - `id = "main"` : The wrapper's id.
+///
+/// Variant attributes `#[html(...)]` :
+/// - `tag = "p"` : The variant's wrapper Html tag, overrides the item's tag.
+/// - `class = "error"` : Adds a css class to the variant's wrapper. Can be repeated.
+/// - `label = "Unknown"` : The unit variant's text.
+///
+/// Field attributes `#[html(...)]` :
+/// - `tag = "p"` : The field's Html tag.
+/// - `class = "name"` : Adds a css class to the field's element. Can be repeated.
+/// - `label = "Name"` : Adds a `<strong>` label before the field's value.
+/// - `skip` : Ignores the field.
+/// - `display` : Converts the value using its `Display` implementation (default).
+/// - `format = "path::to::function"` : Converts the value using a `fn(&T) -> String` function.
+/// - `nested` : Converts the value using its `ToHtml` implementation.
+///   The converted value is inserted as is unless a tag, a class or a label is given.
+///
+/// Tag names are checked at compile time.
+#[proc_macro_derive(ToHtml, attributes(html))]
+pub fn derive_to_html(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_html::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `html_export::composed::forms::form::AsForm` for structs and fieldless enums.
+///
+/// Each struct field is converted to a labeled input pre-filled with the field's value :
+/// `String` and `char` to a text input, numeric primitives to a number input, `bool` to a checkbox
+/// and other types to a `<select>` of their `FormChoice` values.
+/// `Option<T>` fields are converted like `T` but are not required.
+/// The inputs are wrapped in a `<div>`, a `<fieldset>` or a `<form>` depending on the item attributes.
+/// `try_as_form_field` returns the Err variant naming the field whose input can not be built,
+/// `as_form_field` returns None in that case.
+///
+/// Fieldless enums implement `FormChoice` with one choice per variant
+/// and are converted to a `<select>` or to radio inputs.
+///
+/// Struct attributes `#[form(...)]` :
+/// - `legend = "Address"` : Wraps the inputs in a `<fieldset>` with the given legend.
+/// - `method = "POST"` / `action = "/save"` : Wraps the inputs in a `<form>`.
+/// - `submit = "Save"` : Adds a submit button with the given text.
+///
+/// Struct field attributes `#[form(...)]` :
+/// - `label = "Name"` : The label's text. Defaults to the field's name (`first_name` becomes `First name`).
+/// - `name = "first"` : The input's name. Defaults to the field's name, required for tuple structs.
+/// - `id = "first"` : The input's id. Defaults to the input's name.
+/// - `placeholder = "John"` : The text or number input's place holder.
+/// - `pattern = "[a-z]+"`, `min_length = 2`, `max_length = 20` : The text input's constraints.
+/// - `min = 0`, `max = 10`, `step = 0.5` : The number input's constraints.
+/// - `text` : Converts the value to a text input using its `Display` implementation.
+/// - `select` / `radio` : Converts the value to a `<select>` / radio inputs. The field's type must implement `FormChoice`.
+/// - `nested` : Converts the value using its `AsForm` implementation.
+/// - `skip` : Ignores the field.
+///
+/// Enum attributes `#[form(...)]` :
+/// - `label`, `name`, `id` : The input's label, name and id. Default to the enum's name.
+/// - `radio` : Converts the value to radio inputs instead of a `<select>`.
+///
+/// Variant attributes `#[form(...)]` :
+/// - `label = "Dark blue"` : The choice's text. Defaults to the variant's name.
+/// - `value = "dark-blue"` : The choice's value. Defaults to the variant's name.
+#[proc_macro_derive(AsForm, attributes(form))]
+pub fn derive_as_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    as_form::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `html_export::composed::list::AsList` for structs and enums.
+///
+/// In ordered and unordered lists, the item is converted to an `<li>` and each field to a `<span>` containing its escaped value.
+/// In definition lists, the `term` field is converted to the `<dt>` content and the `definition` field to the `<dd>` content.
+/// Enum variants are converted the same way, unit variants contain their name.
+///
+/// Item attributes `#[list(...)]` :
+/// - `tag = "li"` : The list item's Html tag.
+/// - `class = "language"` : Adds a css class to the list item. Can be repeated.
+/// - `term` / `definition` : Converts the item to a `<dt>` / `<dd>` when `to_list_item` is called
+///   with `ListType::TermDefinition`, as done by `term_definition_list_from_map`.
+///
+/// Variant attributes `#[list(...)]` :
+/// - `tag = "li"` : The variant's list item Html tag, overrides the item's tag.
+/// - `class = "error"` : Adds a css class to the variant's list item. Can be repeated.
+/// - `label = "Unknown"` : The unit variant's text.
+/// - `skip` : Ignores the variant's values.
+///
+/// Field attributes `#[list(...)]` :
+/// - `tag`, `class`, `label`, `skip`, `display`, `format`, `nested` : Same as the `ToHtml` field attributes.
+/// - `term` : The field is the definition list's term.
+/// - `definition` : The field is the definition list's definition.
+#[proc_macro_derive(AsList, attributes(list))]
+pub fn derive_as_list(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    as_list::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `html_export::composed::table::AsTable` for structs.
+///
+/// The table's header contains the fields names (`unit_price` becomes `Unit price`),
+/// each row contains the fields values converted with their `Display` implementation and escaped.
+/// A footer is generated if at least one column has an aggregate.
+///
+/// Item attributes `#[table(...)]` :
+/// - `foot_label = "Total"` : The text of the footer's first cell, if the first column has no aggregate.
+///
+/// Field attributes `#[table(...)]` :
+/// - `header = "Price"` : The column's header text. Required for tuple structs.
+/// - `class = "price"` : Adds a css class to the column's cells. Can be repeated.
+/// - `order = 1` : The column's position. Ordered columns are placed first, the others keep the declaration order.
+/// - `skip` : Ignores the field.
+/// - `format = "path::to::function"` : Converts the value using a `fn(&T) -> String` function.
+/// - `sum` : Displays the column's sum in the footer. The field's type must implement `Sum<&T>`.
+/// - `avg` : Displays the column's average in the footer. The field's type must be a numeric primitive.
+/// - `min` / `max` : Displays the column's minimum / maximum value in the footer. The field's type must implement `PartialOrd`.
+/// - `count` : Displays the number of rows in the footer.
+///
+/// The `format` function is also used to display the `sum`, `min` and `max` aggregates.
+#[proc_macro_derive(AsTable, attributes(table))]
+pub fn derive_as_table(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    as_table::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,343 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Ident, LitStr};
+
+/// The Html tags known at compile time : name, `TagType` variant, can wrap, is single-child.
+const TAGS: &[(&str, &str, bool, bool)] = &[
+    ("p", "P", true, false),
+    ("h1", "H1", true, false),
+    ("h2", "H2", true, false),
+    ("h3", "H3", true, false),
+    ("h4", "H4", true, false),
+    ("h5", "H5", true, false),
+    ("h6", "H6", true, false),
+    ("title", "Title", true, true),
+    ("abbr", "Abbr", true, false),
+    ("b", "B", true, false),
+    ("bdi", "Bdi", true, false),
+    ("bdo", "Bdo", true, false),
+    ("blockquote", "Blockquote", true, false),
+    ("cite", "Cite", true, false),
+    ("del", "Del", true, false),
+    ("dfn", "Dfn", true, false),
+    ("em", "Em", true, false),
+    ("i", "I", true, false),
+    ("ins", "Ins", true, false),
+    ("mark", "Mark", true, false),
+    ("q", "Q", true, false),
+    ("rp", "Rp", true, false),
+    ("ruby", "Ruby", true, false),
+    ("rt", "Rt", true, false),
+    ("s", "S", true, false),
+    ("small", "Small", true, false),
+    ("strong", "Strong", true, false),
+    ("sub", "Sub", true, false),
+    ("sup", "Sup", true, false),
+    ("u", "U", true, false),
+    ("a", "A", true, false),
+    ("link", "Link", false, false),
+    ("menu", "Menu", true, false),
+    ("ul", "Ul", true, false),
+    ("ol", "Ol", true, false),
+    ("li", "Li", true, false),
+    ("dl", "Dl", true, false),
+    ("dt", "Dt", true, false),
+    ("dd", "Dd", true, false),
+    ("table", "Table", true, false),
+    ("caption", "Caption", true, true),
+    ("th", "Th", true, false),
+    ("tr", "Tr", true, false),
+    ("td", "Td", true, false),
+    ("thead", "Thead", true, false),
+    ("tbody", "Tbody", true, false),
+    ("tfoot", "Tfoot", true, false),
+    ("col", "Col", false, false),
+    ("colgroup", "Colgroup", true, false),
+    ("template", "Template", true, false),
+    ("iframe", "IFrame", true, false),
+    ("nav", "Nav", true, false),
+    ("div", "Div", true, false),
+    ("span", "Span", true, false),
+    ("header", "Header", true, false),
+    ("hgroup", "Hgroup", true, false),
+    ("footer", "Footer", true, false),
+    ("main", "Main", true, false),
+    ("section", "Section", true, false),
+    ("search", "Search", true, false),
+    ("article", "Article", true, false),
+    ("aside", "Aside", true, false),
+    ("details", "Details", true, false),
+    ("dialog", "Dialog", true, false),
+    ("summary", "Summary", true, false),
+    ("data", "Data", true, false),
+    ("code", "Code", true, false),
+    ("pre", "Pre", true, false),
+    ("samp", "Samp", true, false),
+    ("form", "Form", true, false),
+    ("input", "Input", false, false),
+    ("textarea", "TextArea", true, false),
+    ("button", "Button", true, false),
+    ("select", "Select", true, false),
+    ("optgroup", "OptGroup", true, false),
+    ("option", "Option", true, false),
+    ("label", "Label", true, true),
+    ("fieldset", "FieldSet", true, false),
+    ("legend", "Legend", true, true),
+    ("datalist", "DataList", true, false),
+    ("output", "Output", true, false),
+    ("img", "Img", false, false),
+    ("map", "Map", true, false),
+    ("area", "Area", false, false),
+    ("canvas", "Canvas", true, false),
+    ("figcaption", "FigCaption", true, false),
+    ("figure", "Figure", true, false),
+    ("picture", "Picture", true, false),
+    ("svg", "Svg", true, false),
+    ("audio", "Audio", true, false),
+    ("source", "Source", false, false),
+    ("track", "Track", false, false),
+    ("video", "Video", true, false),
+    ("wbr", "Wbr", false, false),
+    ("br", "Br", false, false),
+    ("hr", "Hr", false, false),
+    ("comment", "Comment", true, false),
+    ("address", "Address", true, false),
+    ("kdb", "Kdb", true, false),
+    ("meter", "Meter", true, false),
+    ("progress", "Progress", true, false),
+    ("time", "Time", true, false),
+    ("var", "Var", true, false),
+    ("script", "Script", true, false),
+    ("noscript", "NoScript", true, false),
+    ("embed", "Embed", false, false),
+    ("object", "Object", true, false),
+    ("param", "Param", false, false),
+    ("meta", "Meta", false, false),
+    ("base", "Base", false, false),
+    ("head", "Head", true, false),
+    ("style", "Style", true, false),
+];
+
+/// The Html attributes known at compile time.
+/// `data-*` and `aria-*` attributes and `on*` event handlers are also accepted.
+const ATTRIBUTES: &[&str] = &[
+    "abbr",
+    "accept",
+    "accept-charset",
+    "accesskey",
+    "action",
+    "allow",
+    "allowfullscreen",
+    "alt",
+    "as",
+    "async",
+    "autocapitalize",
+    "autocomplete",
+    "autofocus",
+    "autoplay",
+    "blocking",
+    "charset",
+    "checked",
+    "cite",
+    "class",
+    "color",
+    "cols",
+    "colspan",
+    "content",
+    "contenteditable",
+    "controls",
+    "coords",
+    "crossorigin",
+    "data",
+    "datetime",
+    "decoding",
+    "default",
+    "defer",
+    "dir",
+    "dirname",
+    "disabled",
+    "download",
+    "draggable",
+    "enctype",
+    "enterkeyhint",
+    "fetchpriority",
+    "for",
+    "form",
+    "formaction",
+    "formenctype",
+    "formmethod",
+    "formnovalidate",
+    "formtarget",
+    "headers",
+    "height",
+    "hidden",
+    "high",
+    "href",
+    "hreflang",
+    "http-equiv",
+    "id",
+    "inert",
+    "inputmode",
+    "integrity",
+    "is",
+    "ismap",
+    "itemid",
+    "itemprop",
+    "itemref",
+    "itemscope",
+    "itemtype",
+    "kind",
+    "label",
+    "lang",
+    "list",
+    "loading",
+    "loop",
+    "low",
+    "max",
+    "maxlength",
+    "media",
+    "method",
+    "min",
+    "minlength",
+    "multiple",
+    "muted",
+    "name",
+    "nomodule",
+    "nonce",
+    "novalidate",
+    "open",
+    "optimum",
+    "pattern",
+    "ping",
+    "placeholder",
+    "playsinline",
+    "popover",
+    "popovertarget",
+    "poster",
+    "preload",
+    "property",
+    "readonly",
+    "referrerpolicy",
+    "rel",
+    "required",
+    "reversed",
+    "role",
+    "rows",
+    "rowspan",
+    "sandbox",
+    "scope",
+    "selected",
+    "shape",
+    "size",
+    "sizes",
+    "slot",
+    "span",
+    "spellcheck",
+    "src",
+    "srcdoc",
+    "srclang",
+    "srcset",
+    "start",
+    "step",
+    "style",
+    "tabindex",
+    "target",
+    "title",
+    "translate",
+    "type",
+    "usemap",
+    "value",
+    "width",
+    "wrap",
+    "xmlns",
+];
+
+/// Checks that the attribute name is a known Html attribute.
+/// If it is not, a compile error pointing at `span` is returned.
+///
+/// - `name` : The attribute's name.
+/// - `span` : The location of the attribute name in the source code.
+pub fn check_attribute(name: &str, span: Span) -> syn::Result<()> {
+    let is_custom = ["data-", "aria-"]
+        .iter()
+        .any(|prefix| name.len() > prefix.len() && name.starts_with(prefix));
+    let is_event =
+        name.len() > 2 && name.starts_with("on") && name.chars().all(|c| c.is_ascii_lowercase());
+    if is_custom || is_event || ATTRIBUTES.contains(&name) {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            span,
+            format!("`{}` is not a known Html attribute", name),
+        ))
+    }
+}
+
+/// Represents an Html tag checked at compile time.
+///
+/// - `name` : The Html tag name.
+/// - `variant` : The matching `TagType` variant.
+/// - `can_wrap` : Indicates if the tag can wrap another element.
+/// - `is_single_child` : Indicates if the tag is a single-child element.
+pub struct Tag {
+    pub name: String,
+    pub variant: Ident,
+    pub can_wrap: bool,
+    pub is_single_child: bool,
+}
+
+impl Tag {
+    /// Returns the tag matching the given Html tag name.
+    /// If the name is not a known Html tag, a compile error pointing at `span` is returned.
+    ///
+    /// - `name` : The Html tag name.
+    /// - `span` : The location of the tag name in the source code.
+    pub fn from_name(name: &str, span: Span) -> syn::Result<Self> {
+        match TAGS.iter().find(|(tag_name, ..)| *tag_name == name) {
+            Some((tag_name, variant, can_wrap, is_single_child)) => Ok(Self {
+                name: tag_name.to_string(),
+                variant: Ident::new(variant, Span::call_site()),
+                can_wrap: *can_wrap,
+                is_single_child: *is_single_child,
+            }),
+            None => Err(syn::Error::new(
+                span,
+                format!("<{}> is not a known Html tag", name),
+            )),
+        }
+    }
+
+    /// Returns the tag matching the given string literal.
+    ///
+    /// - `literal` : The Html tag name literal.
+    pub fn from_literal(literal: &LitStr) -> syn::Result<Self> {
+        Self::from_name(&literal.value(), literal.span())
+    }
+
+    /// Checks that the tag can contain `count` sub-elements.
+    /// If it can not, a compile error pointing at `span` is returned.
+    ///
+    /// - `count` : The number of sub-elements.
+    /// - `span` : The location of the tag in the source code.
+    pub fn check_children(&self, count: usize, span: Span) -> syn::Result<()> {
+        if count > 0 && !self.can_wrap {
+            return Err(syn::Error::new(
+                span,
+                format!("<{}> can not have sub-elements", self.name),
+            ));
+        }
+        if count > 1 && self.is_single_child {
+            return Err(syn::Error::new(
+                span,
+                format!("<{}> can have at most 1 sub-element", self.name),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the `TagType` expression of the tag.
+    pub fn to_tokens(&self) -> TokenStream {
+        let variant = &self.variant;
+        quote!(::html_export::tags::TagType::#variant)
+    }
+}
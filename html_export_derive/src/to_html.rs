@@ -0,0 +1,129 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Field, Fields, LitStr};
+
+use crate::{
+    attributes::HtmlOptions,
+    common::{
+        destructure, element_with_children, field_element, self_places, tag_or_default, text,
+    },
+    tags::Tag,
+};
+
+/// Expands `#[derive(ToHtml)]`.
+///
+/// - `input` : The derived struct or enum.
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let container = HtmlOptions::parse(&input.attrs, &["tag", "class", "id"])?;
+    let (wrapper, wrapper_span) = tag_or_default(&container.tag, "div", input.ident.span())?;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let children = fields_to_html(&data.fields, &self_places(&data.fields))?;
+            wrapper.check_children(children.len(), wrapper_span)?;
+            element_with_children(
+                &wrapper,
+                &container.classes,
+                container.id.as_ref(),
+                &children,
+            )
+        }
+        Data::Enum(data) => {
+            let mut arms = vec![];
+            for variant in data.variants.iter() {
+                let options = HtmlOptions::parse(&variant.attrs, &["tag", "class", "label"])?;
+                let (tag, tag_span) = match &options.tag {
+                    Some(tag) => (Tag::from_literal(tag)?, tag.span()),
+                    None => (
+                        Tag::from_name(&wrapper.name, wrapper_span)?,
+                        variant.ident.span(),
+                    ),
+                };
+                let classes = container
+                    .classes
+                    .iter()
+                    .chain(options.classes.iter())
+                    .cloned()
+                    .collect::<Vec<LitStr>>();
+                let (pattern, places) = destructure(&variant.fields);
+                let children = match &variant.fields {
+                    Fields::Unit => {
+                        let label = options.label.unwrap_or_else(|| {
+                            LitStr::new(&variant.ident.to_string(), variant.ident.span())
+                        });
+                        vec![text(quote!(::std::string::String::from(#label)))]
+                    }
+                    fields => fields_to_html(fields, &places)?,
+                };
+                tag.check_children(children.len(), tag_span)?;
+                let element =
+                    element_with_children(&tag, &classes, container.id.as_ref(), &children);
+                let ident = &variant.ident;
+                arms.push(quote!(Self::#ident #pattern => #element));
+            }
+            if arms.is_empty() {
+                quote!(match *self {})
+            } else {
+                quote! {
+                    match self {
+                        #(#arms,)*
+                    }
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "ToHtml can not be derived for unions",
+            ))
+        }
+    };
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::html_export::html::ToHtml for #name #type_generics #where_clause {
+            fn to_html(&self) -> ::html_export::element::Element {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns the expressions converting each non-skipped field to an `Element`.
+///
+/// - `fields` : The fields to convert.
+/// - `places` : The place expressions of each field.
+fn fields_to_html(fields: &Fields, places: &[TokenStream]) -> syn::Result<Vec<TokenStream>> {
+    let mut elements = vec![];
+    for (field, place) in fields.iter().zip(places) {
+        if let Some(element) = field_to_html(field, place)? {
+            elements.push(element);
+        }
+    }
+    Ok(elements)
+}
+
+/// Returns the expression converting the field to an `Element`.
+/// If the field is skipped, the function returns None.
+///
+/// - `field` : The field to convert.
+/// - `place` : The field's place expression.
+fn field_to_html(field: &Field, place: &TokenStream) -> syn::Result<Option<TokenStream>> {
+    let options = HtmlOptions::parse(
+        &field.attrs,
+        &[
+            "tag", "class", "label", "skip", "display", "format", "nested",
+        ],
+    )?;
+    if options.skip {
+        return Ok(None);
+    }
+    Ok(Some(field_element(
+        &options.tag,
+        &options.classes,
+        &options.label,
+        &options.format,
+        place,
+        field.span(),
+    )?))
+}